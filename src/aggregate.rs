@@ -0,0 +1,230 @@
+// src/aggregate.rs
+
+use crate::client::Parse;
+use crate::error::ParseError;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+/// Builds an aggregation pipeline for [`Parse::execute_aggregate_pipeline`] one stage at a time.
+///
+/// Each method appends one stage, in call order, so the stage names cannot be misspelled.
+/// The stage bodies are still raw JSON, as in MongoDB's aggregation documentation. Use
+/// [`Parse::execute_aggregate`] directly for stages without a method here.
+///
+/// # Example
+///
+/// ```rust
+/// use parse_rs::AggregatePipeline;
+/// use serde_json::json;
+///
+/// let pipeline = AggregatePipeline::new()
+///     .match_(json!({ "score": { "$gt": 100 } }))
+///     .group(json!({ "_id": "$playerName", "total": { "$sum": "$score" } }))
+///     .sort(json!({ "total": -1 }))
+///     .limit(10);
+/// assert_eq!(pipeline.stages().len(), 4);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AggregatePipeline {
+    stages: Vec<Value>,
+}
+
+impl AggregatePipeline {
+    /// Creates an empty pipeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a `$match` stage, which filters documents like a query's `where` clause.
+    pub fn match_(self, filter: Value) -> Self {
+        self.stage("$match", filter)
+    }
+
+    /// Appends a `$group` stage. `spec` must contain the `_id` to group by; Parse Server returns
+    /// it as `objectId`.
+    pub fn group(self, spec: Value) -> Self {
+        self.stage("$group", spec)
+    }
+
+    /// Appends a `$sort` stage, e.g. `{"total": -1}`.
+    pub fn sort(self, spec: Value) -> Self {
+        self.stage("$sort", spec)
+    }
+
+    /// Appends a `$project` stage, which reshapes each document.
+    pub fn project(self, spec: Value) -> Self {
+        self.stage("$project", spec)
+    }
+
+    /// Appends a `$limit` stage.
+    pub fn limit(self, count: usize) -> Self {
+        self.stage("$limit", json!(count))
+    }
+
+    /// Appends a `$skip` stage.
+    pub fn skip(self, count: usize) -> Self {
+        self.stage("$skip", json!(count))
+    }
+
+    fn stage(mut self, name: &str, body: Value) -> Self {
+        self.stages.push(json!({ name: body }));
+        self
+    }
+
+    /// Returns the stages added so far.
+    pub fn stages(&self) -> &[Value] {
+        &self.stages
+    }
+
+    /// Returns the pipeline as the JSON array sent to the server.
+    pub fn to_value(&self) -> Value {
+        Value::Array(self.stages.clone())
+    }
+}
+
+impl From<AggregatePipeline> for Value {
+    fn from(pipeline: AggregatePipeline) -> Self {
+        Value::Array(pipeline.stages)
+    }
+}
+
+/// One page of aggregation results, returned by [`Parse::execute_aggregate_paged`].
+#[derive(Debug, Clone)]
+pub struct AggregatePage<T> {
+    /// The results on this page.
+    pub results: Vec<T>,
+    /// Whether the pipeline produced more results after this page.
+    pub has_more: bool,
+    /// The number of results skipped before this page.
+    pub skip: usize,
+    /// The page size requested.
+    pub limit: usize,
+}
+
+impl<T> AggregatePage<T> {
+    /// Returns the `skip` of the next page, or `None` if this is the last one.
+    pub fn next_skip(&self) -> Option<usize> {
+        self.has_more.then_some(self.skip + self.limit)
+    }
+}
+
+impl Parse {
+    /// Runs an aggregation built with [`AggregatePipeline`] on `class_name`.
+    ///
+    /// This is [`execute_aggregate`](Self::execute_aggregate) with a typed pipeline, and
+    /// likewise uses the Master Key.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use parse_rs::{AggregatePipeline, Parse, ParseError};
+    /// use serde_json::{json, Value};
+    ///
+    /// # async fn run(client: &Parse) -> Result<(), ParseError> {
+    /// let pipeline = AggregatePipeline::new()
+    ///     .group(json!({ "_id": "$playerName", "total": { "$sum": "$score" } }))
+    ///     .sort(json!({ "total": -1 }));
+    /// let totals: Vec<Value> = client.execute_aggregate_pipeline("GameScore", &pipeline).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_aggregate_pipeline<T: DeserializeOwned + Send + 'static>(
+        &self,
+        class_name: &str,
+        pipeline: &AggregatePipeline,
+    ) -> Result<Vec<T>, ParseError> {
+        self.execute_aggregate(class_name, pipeline.to_value())
+            .await
+    }
+
+    /// Runs one page of an aggregation: `$skip` and `$limit` stages are appended to `pipeline`,
+    /// so the page is taken from the pipeline's final output (e.g. after a `$group` and `$sort`).
+    ///
+    /// One extra result is requested to tell whether more exist; it is not returned. Sort the
+    /// pipeline's output for the pages to be stable. `pipeline` is a JSON array of stages, such
+    /// as an [`AggregatePipeline`] converted with `.into()`. Fails with
+    /// `ParseError::InvalidInput` if it is not an array or if `limit` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use parse_rs::{AggregatePipeline, Parse, ParseError};
+    /// use serde_json::{json, Value};
+    ///
+    /// # async fn run(client: &Parse) -> Result<(), ParseError> {
+    /// let leaderboard = AggregatePipeline::new()
+    ///     .group(json!({ "_id": "$playerName", "total": { "$sum": "$score" } }))
+    ///     .sort(json!({ "total": -1 }));
+    /// let mut skip = Some(0);
+    /// while let Some(current) = skip {
+    ///     let page = client
+    ///         .execute_aggregate_paged::<Value>("GameScore", leaderboard.to_value(), current, 20)
+    ///         .await?;
+    ///     println!("{:?}", page.results);
+    ///     skip = page.next_skip();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_aggregate_paged<T: DeserializeOwned + Send + 'static>(
+        &self,
+        class_name: &str,
+        pipeline: Value,
+        skip: usize,
+        limit: usize,
+    ) -> Result<AggregatePage<T>, ParseError> {
+        let Value::Array(stages) = pipeline else {
+            return Err(ParseError::InvalidInput(
+                "An aggregation pipeline must be a JSON array of stages".to_string(),
+            ));
+        };
+        if limit == 0 {
+            return Err(ParseError::InvalidInput(
+                "The page size of an aggregation must be at least 1".to_string(),
+            ));
+        }
+
+        let mut pipeline = AggregatePipeline { stages };
+        if skip > 0 {
+            pipeline = pipeline.skip(skip);
+        }
+        pipeline = pipeline.limit(limit + 1);
+        let mut results: Vec<T> = self
+            .execute_aggregate_pipeline(class_name, &pipeline)
+            .await?;
+        let has_more = results.len() > limit;
+        results.truncate(limit);
+        Ok(AggregatePage {
+            results,
+            has_more,
+            skip,
+            limit,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_sort_project_pipeline() {
+        let pipeline = AggregatePipeline::new()
+            .group(json!({ "_id": "$playerName", "total": { "$sum": "$score" } }))
+            .sort(json!({ "total": -1 }))
+            .project(json!({ "total": 1 }))
+            .skip(20)
+            .limit(10);
+
+        assert_eq!(
+            Value::from(pipeline),
+            json!([
+                { "$group": { "_id": "$playerName", "total": { "$sum": "$score" } } },
+                { "$sort": { "total": -1 } },
+                { "$project": { "total": 1 } },
+                { "$skip": 20 },
+                { "$limit": 10 }
+            ])
+        );
+    }
+}
@@ -0,0 +1,210 @@
+// src/batch.rs
+
+use crate::client::Parse;
+use crate::error::ParseError;
+use serde_json::{json, Value};
+
+/// The most sub-requests Parse Server accepts in a single `/batch` call.
+pub const MAX_BATCH_SIZE: usize = 50;
+
+/// A single operation in a [`ParseBatch`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchOp {
+    /// Creates an object of `class` with the given fields.
+    Create { class: String, fields: Value },
+    /// Updates the given fields (or field operations) of an existing object.
+    Update {
+        class: String,
+        object_id: String,
+        fields: Value,
+    },
+    /// Deletes an object.
+    Delete { class: String, object_id: String },
+    /// Increments a numeric field of an object by `amount` on the server (use a negative amount
+    /// to decrement). The field is created with the value `amount` if it is not set yet.
+    Increment {
+        class: String,
+        object_id: String,
+        field: String,
+        amount: i64,
+    },
+}
+
+impl BatchOp {
+    // Builds the `{ method, path, body }` sub-request, with paths below `mount_path` (e.g. "/parse").
+    // With the `encryption` feature, fields with a cipher registered on `client` are encrypted.
+    fn to_request(&self, client: &Parse, mount_path: &str) -> Result<Value, ParseError> {
+        #[cfg(not(feature = "encryption"))]
+        let _ = client;
+        Ok(match self {
+            BatchOp::Create { class, fields } => {
+                #[cfg(feature = "encryption")]
+                let fields = &client.encrypt_fields(class, fields)?;
+                json!({
+                    "method": "POST",
+                    "path": format!("{}/classes/{}", mount_path, class),
+                    "body": fields,
+                })
+            }
+            BatchOp::Update {
+                class,
+                object_id,
+                fields,
+            } => {
+                #[cfg(feature = "encryption")]
+                let fields = &client.encrypt_fields(class, fields)?;
+                json!({
+                    "method": "PUT",
+                    "path": format!("{}/classes/{}/{}", mount_path, class, object_id),
+                    "body": fields,
+                })
+            }
+            BatchOp::Delete { class, object_id } => json!({
+                "method": "DELETE",
+                "path": format!("{}/classes/{}/{}", mount_path, class, object_id),
+            }),
+            BatchOp::Increment {
+                class,
+                object_id,
+                field,
+                amount,
+            } => json!({
+                "method": "PUT",
+                "path": format!("{}/classes/{}/{}", mount_path, class, object_id),
+                "body": { field.as_str(): { "__op": "Increment", "amount": amount } },
+            }),
+        })
+    }
+}
+
+/// Groups several object operations into one request to the `/batch` endpoint.
+///
+/// Each operation is still applied independently by the server: one failing does not undo the
+/// others, so [`execute`](Self::execute) reports a result per operation.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use parse_rs::{Parse, ParseBatch, ParseError};
+///
+/// # async fn run(client: &Parse) -> Result<(), ParseError> {
+/// let mut batch = ParseBatch::new();
+/// batch
+///     .increment("GameScore", "xWMyZ4YEGZ", "score", 10)
+///     .increment("GameScore", "tR7kX0bq2P", "score", 5);
+/// for result in batch.execute(client).await? {
+///     let updated = result?;
+///     println!("new score: {}", updated["score"]);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParseBatch {
+    ops: Vec<BatchOp>,
+}
+
+impl ParseBatch {
+    /// Creates an empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an operation to the batch.
+    pub fn add(&mut self, op: BatchOp) -> &mut Self {
+        self.ops.push(op);
+        self
+    }
+
+    /// Adds a [`BatchOp::Increment`] of `field` on the given object.
+    pub fn increment(
+        &mut self,
+        class: &str,
+        object_id: &str,
+        field: &str,
+        amount: i64,
+    ) -> &mut Self {
+        self.add(BatchOp::Increment {
+            class: class.to_string(),
+            object_id: object_id.to_string(),
+            field: field.to_string(),
+            amount,
+        })
+    }
+
+    /// Returns the operations added so far.
+    pub fn ops(&self) -> &[BatchOp] {
+        &self.ops
+    }
+
+    /// Returns the number of operations in the batch.
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Returns `true` if the batch has no operations.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Sends all operations in a single `/batch` request.
+    ///
+    /// Returns one result per operation, in order. A successful result holds the server's
+    /// `success` payload, e.g. `objectId`/`createdAt` for a create, or `updatedAt` and the new
+    /// value of the field for an increment. The outer error is returned if the request itself
+    /// fails, or with `ParseError::InvalidInput` if the batch has more than [`MAX_BATCH_SIZE`]
+    /// operations.
+    pub async fn execute(
+        &self,
+        client: &Parse,
+    ) -> Result<Vec<Result<Value, ParseError>>, ParseError> {
+        self.execute_with_master_key(client, false).await
+    }
+
+    // `execute`, sending the batch request with the master key if `use_master_key` is set.
+    pub(crate) async fn execute_with_master_key(
+        &self,
+        client: &Parse,
+        use_master_key: bool,
+    ) -> Result<Vec<Result<Value, ParseError>>, ParseError> {
+        if self.ops.is_empty() {
+            return Ok(Vec::new());
+        }
+        if self.ops.len() > MAX_BATCH_SIZE {
+            return Err(ParseError::InvalidInput(format!(
+                "A batch can hold at most {} operations, got {}",
+                MAX_BATCH_SIZE,
+                self.ops.len()
+            )));
+        }
+
+        // Sub-request paths are absolute, so they need the same mount path as the batch request.
+        let api_root = client._api_url("")?;
+        let mount_path = api_root.path().trim_end_matches('/');
+        let requests: Vec<Value> = self
+            .ops
+            .iter()
+            .map(|op| op.to_request(client, mount_path))
+            .collect::<Result<_, _>>()?;
+        let results: Vec<Value> = client
+            ._request(
+                reqwest::Method::POST,
+                "batch",
+                Some(&json!({ "requests": requests })),
+                use_master_key,
+                None,
+            )
+            .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|mut result| match result.get_mut("success") {
+                Some(success) => Ok(success.take()),
+                None => Err(ParseError::from_response(
+                    400,
+                    result.get("error").cloned().unwrap_or(result),
+                )),
+            })
+            .collect())
+    }
+}
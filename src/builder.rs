@@ -0,0 +1,507 @@
+// src/builder.rs
+
+use crate::client::{LogoutOnDrop, Parse};
+use crate::error::ParseError;
+use crate::interceptor::RequestInterceptor;
+use crate::stats::RequestCounters;
+
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{Client, Url};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+
+pub use reqwest::{Certificate, Identity};
+
+/// How the client handles HTTP redirects, set with [`ParseBuilder::redirect_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RedirectPolicy {
+    /// Follows up to 10 redirects, like reqwest does by default.
+    #[default]
+    Follow,
+    /// Follows at most the given number of redirects; one more fails the request.
+    Limited(usize),
+    /// Does not follow redirects. The redirect response itself is returned, which the client
+    /// reports as an error, e.g. to detect a server URL that needs to be updated.
+    None,
+}
+
+impl RedirectPolicy {
+    fn to_reqwest(self) -> reqwest::redirect::Policy {
+        match self {
+            RedirectPolicy::Follow => reqwest::redirect::Policy::default(),
+            RedirectPolicy::Limited(max) => reqwest::redirect::Policy::limited(max),
+            RedirectPolicy::None => reqwest::redirect::Policy::none(),
+        }
+    }
+}
+
+/// Which requests a [`ProxyConfig`] applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProxyScope {
+    Http,
+    Https,
+    All,
+}
+
+/// An outbound proxy, set with [`ParseBuilder::proxy`].
+///
+/// The proxy URL may use the `http`, `https` or (with the `socks` feature) `socks5` and
+/// `socks5h` schemes. Credentials can be given in the URL or with
+/// [`basic_auth`](Self::basic_auth).
+///
+/// # Example
+///
+/// ```rust
+/// use parse_rs::{Parse, ProxyConfig};
+///
+/// let proxy = ProxyConfig::all("http://proxy.corp.example:3128")
+///     .basic_auth("svc-parse", "s3cret")
+///     .no_proxy("localhost,127.0.0.1,.corp.example");
+/// let client = Parse::builder("https://parse.example.com/parse", "myAppId")
+///     .proxy(proxy)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProxyConfig {
+    url: String,
+    scope: ProxyScope,
+    basic_auth: Option<(String, String)>,
+    no_proxy: Option<String>,
+}
+
+impl ProxyConfig {
+    /// Proxies all requests through `url`.
+    pub fn all(url: &str) -> Self {
+        Self::new(url, ProxyScope::All)
+    }
+
+    /// Proxies only `http://` requests through `url`.
+    pub fn http(url: &str) -> Self {
+        Self::new(url, ProxyScope::Http)
+    }
+
+    /// Proxies only `https://` requests through `url`.
+    pub fn https(url: &str) -> Self {
+        Self::new(url, ProxyScope::Https)
+    }
+
+    fn new(url: &str, scope: ProxyScope) -> Self {
+        Self {
+            url: url.to_string(),
+            scope,
+            basic_auth: None,
+            no_proxy: None,
+        }
+    }
+
+    /// Sends `Proxy-Authorization` basic credentials to the proxy.
+    pub fn basic_auth(mut self, username: &str, password: &str) -> Self {
+        self.basic_auth = Some((username.to_string(), password.to_string()));
+        self
+    }
+
+    /// Bypasses the proxy for the hosts in `exclusions`, a comma-separated list in the format
+    /// of the `NO_PROXY` environment variable: host names (a leading `.` also matches
+    /// subdomains), IP addresses, CIDR ranges, or `*` for all hosts.
+    pub fn no_proxy(mut self, exclusions: &str) -> Self {
+        self.no_proxy = Some(exclusions.to_string());
+        self
+    }
+
+    fn to_reqwest(&self) -> Result<reqwest::Proxy, ParseError> {
+        let proxy = match self.scope {
+            ProxyScope::Http => reqwest::Proxy::http(&self.url),
+            ProxyScope::Https => reqwest::Proxy::https(&self.url),
+            ProxyScope::All => reqwest::Proxy::all(&self.url),
+        }
+        .map_err(ParseError::from)?;
+        let proxy = match &self.basic_auth {
+            Some((username, password)) => proxy.basic_auth(username, password),
+            None => proxy,
+        };
+        Ok(proxy.no_proxy(
+            self.no_proxy
+                .as_deref()
+                .and_then(reqwest::NoProxy::from_string),
+        ))
+    }
+}
+
+impl std::fmt::Debug for ProxyConfig {
+    // The password is never printed.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProxyConfig")
+            .field("url", &self.url)
+            .field("scope", &self.scope)
+            .field(
+                "basic_auth",
+                &self
+                    .basic_auth
+                    .as_ref()
+                    .map(|(username, _)| (username, "***")),
+            )
+            .field("no_proxy", &self.no_proxy)
+            .finish()
+    }
+}
+
+/// Configures and creates a [`Parse`] client.
+///
+/// [`Parse::new`] covers the common case of a server URL, an Application ID and keys; the
+/// builder additionally exposes client-wide options. Obtain one with [`Parse::builder`] or
+/// [`ParseBuilder::new`], chain the setters you need, then call [`build`](Self::build).
+#[derive(Debug, Clone)]
+pub struct ParseBuilder {
+    server_url: String,
+    app_id: String,
+    javascript_key: Option<String>,
+    rest_api_key: Option<String>,
+    master_key: Option<String>,
+    installation_id: Option<String>,
+    log_redaction: bool,
+    validate_object_ids: bool,
+    object_id_length: usize,
+    interceptor: Option<Arc<dyn RequestInterceptor>>,
+    redirect_policy: RedirectPolicy,
+    local_address: Option<IpAddr>,
+    resolve_overrides: Vec<(String, SocketAddr)>,
+    proxies: Vec<ProxyConfig>,
+    root_certificates: Vec<Certificate>,
+    identity: Option<Identity>,
+    #[cfg(feature = "dangerous_accept_invalid_certs")]
+    accept_invalid_certs: bool,
+    logout_on_drop: bool,
+}
+
+impl ParseBuilder {
+    /// Creates a builder for a client talking to `server_url` with the given Application ID.
+    ///
+    /// See [`Parse::new`] for how `server_url` is normalized.
+    pub fn new(server_url: &str, app_id: &str) -> Self {
+        Self {
+            server_url: server_url.to_string(),
+            app_id: app_id.to_string(),
+            javascript_key: None,
+            rest_api_key: None,
+            master_key: None,
+            installation_id: None,
+            log_redaction: true,
+            validate_object_ids: false,
+            object_id_length: 10,
+            interceptor: None,
+            redirect_policy: RedirectPolicy::default(),
+            local_address: None,
+            resolve_overrides: Vec::new(),
+            proxies: Vec::new(),
+            root_certificates: Vec::new(),
+            identity: None,
+            #[cfg(feature = "dangerous_accept_invalid_certs")]
+            accept_invalid_certs: false,
+            logout_on_drop: false,
+        }
+    }
+
+    /// Sets the JavaScript Key.
+    pub fn javascript_key(mut self, javascript_key: &str) -> Self {
+        self.javascript_key = Some(javascript_key.to_string());
+        self
+    }
+
+    /// Sets the REST API Key.
+    pub fn rest_api_key(mut self, rest_api_key: &str) -> Self {
+        self.rest_api_key = Some(rest_api_key.to_string());
+        self
+    }
+
+    /// Sets the Master Key. Use with caution: it bypasses all ACLs and Class-Level Permissions.
+    pub fn master_key(mut self, master_key: &str) -> Self {
+        self.master_key = Some(master_key.to_string());
+        self
+    }
+
+    /// Sets the installation id sent as `X-Parse-Installation-Id` with every request.
+    ///
+    /// Parse Server records it on sessions created by signup and login, correlating them with
+    /// the device's `_Installation` object (used for session management and push targeting).
+    /// Methods taking an explicit installation id, such as
+    /// [`login_with_installation_id`](crate::user::ParseUserHandle::login_with_installation_id),
+    /// override it for that request.
+    pub fn installation_id(mut self, installation_id: &str) -> Self {
+        self.installation_id = Some(installation_id.to_string());
+        self
+    }
+
+    /// Sets how redirects are handled. Defaults to [`RedirectPolicy::Follow`].
+    ///
+    /// A deployment may redirect, e.g. from HTTP to HTTPS, or from file URLs to a CDN.
+    /// [`RedirectPolicy::None`] makes such a redirect fail the request instead, which helps
+    /// detect a misconfigured server URL; keep redirects enabled if file downloads rely on them.
+    /// Note that the master key and other headers are sent again to the redirect target.
+    pub fn redirect_policy(mut self, policy: RedirectPolicy) -> Self {
+        self.redirect_policy = policy;
+        self
+    }
+
+    /// Binds outgoing connections to the local address `address`, e.g. to route Parse traffic
+    /// through a specific network interface on a multi-homed host. Its IP version (IPv4 or
+    /// IPv6) must match the server's.
+    pub fn local_address(mut self, address: IpAddr) -> Self {
+        self.local_address = Some(address);
+        self
+    }
+
+    /// Connects to `address` whenever `host` is requested, instead of resolving `host` through
+    /// DNS, e.g. to test against one backend behind a load-balanced name. TLS and the `Host`
+    /// header still use `host`.
+    ///
+    /// The port of `address` is ignored: connections use the port of the server URL (or the
+    /// scheme's default). Can be called several times for different hosts.
+    pub fn resolve(mut self, host: &str, address: SocketAddr) -> Self {
+        self.resolve_overrides.push((host.to_string(), address));
+        self
+    }
+
+    /// Sends requests through an outbound proxy. Can be called several times, e.g. with
+    /// [`ProxyConfig::http`] and [`ProxyConfig::https`] proxies; the first one that applies to a
+    /// request is used.
+    ///
+    /// Without a proxy set here, the client uses the proxy given by the `HTTP_PROXY`,
+    /// `HTTPS_PROXY` and `NO_PROXY` environment variables, if any. Setting one disables those.
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Trusts `certificate` as an additional root CA, e.g. the private CA of a self-hosted
+    /// server behind internal TLS. The system's roots are still trusted. Can be called several
+    /// times.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use parse_rs::{Certificate, Parse};
+    ///
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ca = Certificate::from_pem(&std::fs::read("internal-ca.pem")?)?;
+    /// let client = Parse::builder("https://parse.internal.example/parse", "myAppId")
+    ///     .add_root_certificate(ca)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Presents `identity` as the client certificate when the server requests one (mutual TLS).
+    /// Create it with [`Identity::from_pkcs8_pem`] or [`Identity::from_pkcs12_der`].
+    pub fn identity(mut self, identity: Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Disables all TLS certificate validation when `accept` is true: expired, self-signed and
+    /// wrong-host certificates are all accepted, so anyone on the network path can impersonate
+    /// the server. Only for local development; prefer
+    /// [`add_root_certificate`](Self::add_root_certificate) with the development CA.
+    ///
+    /// Only available with the `dangerous_accept_invalid_certs` feature, so that it cannot be
+    /// turned on by accident in production builds.
+    #[cfg(feature = "dangerous_accept_invalid_certs")]
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
+
+    /// Logs the current user out when the client is dropped with a session token set, so that
+    /// short-lived tools such as scripts and CLIs do not leave sessions behind on the server.
+    /// Disabled by default.
+    ///
+    /// The logout is best-effort. Since `Drop` cannot await, the request is spawned onto the
+    /// current Tokio runtime and its outcome is only logged. It is not sent if the client is
+    /// dropped outside of a runtime, and it is cancelled if the runtime shuts down first, e.g.
+    /// when the client lives until the end of `#[tokio::main]`. Drop the client explicitly
+    /// before that, or call [`logout`](crate::user::ParseUserHandle::logout) yourself where the
+    /// outcome matters.
+    ///
+    /// Clones of the client, including those from
+    /// [`clone_with_session`](Parse::clone_with_session), do not log out when dropped.
+    pub fn logout_on_drop(mut self, enabled: bool) -> Self {
+        self.logout_on_drop = enabled;
+        self
+    }
+
+    /// Controls whether credentials are masked in the client's debug logs. Enabled by default.
+    ///
+    /// When enabled, the values of the `X-Parse-Master-Key`, `X-Parse-Javascript-Key`,
+    /// `X-Parse-REST-API-Key` and `X-Parse-Session-Token` headers are logged as their first four
+    /// characters followed by `***`, and `password` fields in request bodies as `***`. Disable
+    /// this only for local development.
+    pub fn log_redaction(mut self, enabled: bool) -> Self {
+        self.log_redaction = enabled;
+        self
+    }
+
+    /// Controls whether object ids are checked client-side before a request is sent. Disabled by
+    /// default.
+    ///
+    /// When enabled, methods taking an object id return `ParseError::InvalidInput` for ids that
+    /// are not made of exactly [`object_id_length`](Self::object_id_length) ASCII letters and
+    /// digits, without contacting the server. Leave it disabled if the server accepts custom
+    /// object ids (`allowCustomObjectId`).
+    pub fn validate_object_ids(mut self, enabled: bool) -> Self {
+        self.validate_object_ids = enabled;
+        self
+    }
+
+    /// Sets the object id length expected by [`validate_object_ids`](Self::validate_object_ids).
+    /// Defaults to 10, Parse Server's default `objectIdSize`.
+    pub fn object_id_length(mut self, length: usize) -> Self {
+        self.object_id_length = length;
+        self
+    }
+
+    /// Installs a [`RequestInterceptor`] that is invoked around every HTTP request the client
+    /// makes. Clones of the client share the interceptor.
+    pub fn interceptor(mut self, interceptor: Arc<dyn RequestInterceptor>) -> Self {
+        self.interceptor = Some(interceptor);
+        self
+    }
+
+    // Starts a reqwest client builder with the transport settings (redirects, local address,
+    // resolve overrides, proxies and TLS), shared by the clients created in `build`.
+    fn transport_client_builder(&self) -> Result<reqwest::ClientBuilder, ParseError> {
+        let mut client_builder = Client::builder() // Updated to use alias
+            .redirect(self.redirect_policy.to_reqwest())
+            .local_address(self.local_address);
+        for (host, address) in &self.resolve_overrides {
+            client_builder = client_builder.resolve(host, *address);
+        }
+        for proxy in &self.proxies {
+            client_builder = client_builder.proxy(proxy.to_reqwest()?);
+        }
+        for certificate in &self.root_certificates {
+            client_builder = client_builder.add_root_certificate(certificate.clone());
+        }
+        if let Some(identity) = &self.identity {
+            client_builder = client_builder.identity(identity.clone());
+        }
+        #[cfg(feature = "dangerous_accept_invalid_certs")]
+        {
+            client_builder = client_builder.danger_accept_invalid_certs(self.accept_invalid_certs);
+        }
+        Ok(client_builder)
+    }
+
+    /// Creates the [`Parse`] client.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the new `Parse` instance, or a `ParseError` if the configuration is
+    /// invalid (e.g., invalid URL, invalid header values).
+    pub fn build(self) -> Result<Parse, ParseError> {
+        let server_url = self.server_url.as_str();
+        let app_id = self.app_id.as_str();
+        let javascript_key = self.javascript_key.as_deref();
+        let rest_api_key = self.rest_api_key.as_deref();
+        let master_key = self.master_key.as_deref();
+
+        let mut temp_url_string = server_url.to_string();
+
+        // Ensure scheme is present
+        if !temp_url_string.starts_with("http://") && !temp_url_string.starts_with("https://") {
+            temp_url_string = format!("http://{}", temp_url_string);
+        }
+
+        let parsed_server_url = Url::parse(&temp_url_string)?;
+
+        if parsed_server_url.cannot_be_a_base() {
+            return Err(ParseError::SdkError(format!(
+                "The server_url '{}' (after ensuring scheme) resolved to '{}', which cannot be a base URL. Please provide a full base URL (e.g., http://localhost:1337/parse).",
+                server_url, parsed_server_url
+            )));
+        }
+
+        let mut default_headers = HeaderMap::new();
+        default_headers.insert(
+            "X-Parse-Application-Id",
+            HeaderValue::from_str(app_id).map_err(ParseError::InvalidHeaderValue)?,
+        );
+        if let Some(installation_id) = &self.installation_id {
+            default_headers.insert(
+                "X-Parse-Installation-Id",
+                HeaderValue::from_str(installation_id).map_err(ParseError::InvalidHeaderValue)?,
+            );
+        }
+
+        // Keys are attached per request (see `insert_default_key_header`) so that a request can
+        // opt out of them with an explicit `AuthType`; validate them up front all the same.
+        for key in [master_key, javascript_key, rest_api_key]
+            .into_iter()
+            .flatten()
+        {
+            HeaderValue::from_str(key).map_err(ParseError::InvalidHeaderValue)?;
+        }
+
+        let http_client = self
+            .transport_client_builder()?
+            .default_headers(default_headers)
+            .build()
+            .map_err(ParseError::from)?;
+        let foreign_http_client = self
+            .transport_client_builder()?
+            .build()
+            .map_err(ParseError::from)?;
+
+        let mut final_server_url = parsed_server_url.as_str().trim_end_matches('/').to_string();
+
+        // If the URL ends with /parse, strip it to get the true base server URL.
+        // This makes the client resilient to PARSE_SERVER_URL being http://host/parse or http://host.
+        if final_server_url.ends_with("/parse") {
+            final_server_url.truncate(final_server_url.len() - "/parse".len());
+        }
+        // Ensure it's not empty after stripping (e.g. if PARSE_SERVER_URL was just "/parse")
+        if final_server_url.is_empty() && parsed_server_url.scheme() == "http"
+            || parsed_server_url.scheme() == "https"
+        {
+            // This case is unlikely if original URL was valid, but as a safeguard.
+            // Reconstruct from scheme and host if available, or error.
+            if let Some(host_str) = parsed_server_url.host_str() {
+                final_server_url = format!("{}://{}", parsed_server_url.scheme(), host_str);
+                if let Some(port) = parsed_server_url.port() {
+                    final_server_url.push_str(&format!(":{}", port));
+                }
+            } else {
+                return Err(ParseError::SdkError("Server URL became empty after stripping /parse and could not be reconstructed.".to_string()));
+            }
+        }
+
+        log::debug!(
+            "Parse initialized with base server_url: {}",
+            final_server_url
+        );
+
+        Ok(Parse {
+            server_url: final_server_url,
+            app_id: app_id.to_string(),
+            javascript_key: javascript_key.map(|s| s.to_string()),
+            rest_api_key: rest_api_key.map(|s| s.to_string()),
+            master_key: master_key.map(|s| s.to_string()),
+            http_client,
+            foreign_http_client,
+            session_token: None,
+            current_user: None,
+            installation_id: self.installation_id,
+            last_warnings: Arc::new(Mutex::new(Vec::new())),
+            log_redaction: self.log_redaction,
+            object_id_length: self.validate_object_ids.then_some(self.object_id_length),
+            interceptor: self.interceptor,
+            stats: Arc::new(RequestCounters::default()),
+            logout_on_drop: LogoutOnDrop(self.logout_on_drop),
+            #[cfg(feature = "encryption")]
+            field_ciphers: std::collections::HashMap::new(),
+        })
+    }
+}
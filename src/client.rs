@@ -1,23 +1,28 @@
 // src/client.rs
 
 use crate::error::ParseError;
-use crate::object::ParseObject;
+use crate::interceptor::{RequestInterceptor, RequestParts, ResponseParts};
+use crate::object::{validate_class_name, CreateObjectResponse, ParseObject};
 use crate::schema::{GetAllSchemasResponse, ParseSchema};
-use crate::user::ParseUserHandle;
-use crate::FileField;
+use crate::stats::{ParseStats, RequestCounters};
+use crate::user::{ParseUser, ParseUserHandle};
+use crate::ParseBuilder;
 use crate::ParseCloud;
+use crate::ParseFile;
 use crate::ParseQuery;
 
 use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
-use reqwest::{Client, Method, Url};
+use reqwest::{Client, Method, RequestBuilder, Response, Url};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use serde_json::Value;
+use std::sync::{Arc, Mutex};
 
 /// Specifies the type of authentication credentials to be used for an API request.
 ///
 /// This enum helps determine which keys or tokens are prioritized when constructing
 /// the headers for a request to the Parse Server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AuthType {
     /// Use the current session token. This is typically obtained after a user logs in.
     /// If no session token is available, the request might fail or use other credentials
@@ -30,14 +35,112 @@ pub enum AuthType {
     /// it may fall back to using the JavaScript Key if that is configured.
     /// This key is typically used for general API access from trusted server environments.
     RestApiKey,
-    /// No specific authentication to be actively chosen for this request.
-    /// The request will rely on the default headers configured in the `Parse`
-    /// (e.g., Application ID, and potentially a pre-configured JavaScript Key or REST API Key if no Master Key was set globally).
+    /// Send no credentials beyond the Application ID, even if keys are configured on the client.
     /// This is suitable for operations that don't require user context or elevated privileges,
     /// such as public data queries or user signup/login endpoints themselves.
     NoAuth,
 }
 
+// Deserializes the body of a successful response, given as raw bytes and as the JSON `body`
+// parsed from them (`None` if they are not valid JSON).
+//
+// Empty or whitespace-only bodies (e.g. 204 No Content, or an empty 200) are treated as `{}`,
+// falling back to `null` so that `()` and `Option<_>` targets also accept them. If the target
+// type can be built from neither, a `JsonDeserializationFailed` error is returned.
+pub(crate) fn deserialize_success_body<R: DeserializeOwned>(
+    body_bytes: &[u8],
+    body: Option<Value>,
+) -> Result<R, ParseError> {
+    if body_bytes.iter().all(u8::is_ascii_whitespace) {
+        return serde_json::from_str("{}")
+            .or_else(|_| serde_json::from_str("null"))
+            .map_err(|e| {
+                ParseError::JsonDeserializationFailed(
+                    format!(
+                        "Empty response body cannot be deserialized into the expected type: {}",
+                        e
+                    ),
+                    e,
+                )
+            });
+    }
+    let result = match body {
+        Some(body) => serde_json::from_value(body),
+        // Not JSON: parsing again yields the syntax error to report.
+        None => serde_json::from_slice(body_bytes),
+    };
+    result.map_err(|e| {
+        ParseError::JsonDeserializationFailed(
+            format!(
+                "Error: {}, Body: {}",
+                e,
+                String::from_utf8_lossy(body_bytes)
+            ),
+            e,
+        )
+    })
+}
+
+// Longest URL `_get_or_post_with_url_params` sends as a GET. Longer queries are sent as a POST
+// with `_method=GET`, as many servers and proxies reject URLs beyond 8 KiB.
+const MAX_GET_URL_LENGTH: usize = 8000;
+
+// Request headers whose values are credentials, masked in debug logs unless redaction is disabled.
+const SENSITIVE_HEADERS: [&str; 4] = [
+    "X-Parse-Master-Key",
+    "X-Parse-Javascript-Key",
+    "X-Parse-REST-API-Key",
+    "X-Parse-Session-Token",
+];
+
+// Replaces the value of every `password` field in `value`, at any depth, with `***`.
+fn redact_passwords(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                if key == "password" {
+                    *field = Value::String("***".to_string());
+                } else {
+                    redact_passwords(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_passwords),
+        _ => {}
+    }
+}
+
+// Per-request settings understood by `Parse::_request_with_options`.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct RequestOptions<'a> {
+    // Send the master key (the client's, or `master_key_override`) for this request.
+    pub(crate) use_master_key: bool,
+    // Session token to send instead of the client's current one.
+    pub(crate) session_token_override: Option<&'a str>,
+    // Master key to send instead of the one configured on the client.
+    pub(crate) master_key_override: Option<&'a str>,
+    // Sent as `X-Parse-Installation-Id` to associate the request with a device.
+    pub(crate) installation_id: Option<&'a str>,
+    // Send exactly these credentials instead of applying the implicit precedence above.
+    pub(crate) auth: Option<AuthType>,
+    // Overrides the HTTP client's timeout for this request.
+    pub(crate) timeout: Option<std::time::Duration>,
+    // When a session token is sent, leave out the client's master key so the server resolves the
+    // user; Parse Server ignores the session of a request that carries the master key.
+    pub(crate) as_user: bool,
+}
+
+// Whether a client logs out when dropped. Clones start with the option off: they share the
+// session token, and dropping one must not invalidate the session of the others.
+#[derive(Debug, Default)]
+pub(crate) struct LogoutOnDrop(pub(crate) bool);
+
+impl Clone for LogoutOnDrop {
+    fn clone(&self) -> Self {
+        LogoutOnDrop(false)
+    }
+}
+
 /// The main client for interacting with a Parse Server instance.
 ///
 /// `Parse` handles the configuration of server connection details (URL, Application ID, API keys)
@@ -79,12 +182,33 @@ pub enum AuthType {
 pub struct Parse {
     pub server_url: String, // Changed from Url to String
     pub(crate) app_id: String,
-    #[allow(dead_code)] // Not used by current auth features
     pub(crate) javascript_key: Option<String>,
     pub(crate) rest_api_key: Option<String>,
     pub(crate) master_key: Option<String>,
     pub(crate) http_client: Client, // Updated to use alias
+    // Same transport settings as `http_client`, without the Parse default headers; used for
+    // requests to other origins, such as files served from a CDN.
+    pub(crate) foreign_http_client: Client,
     pub(crate) session_token: Option<String>,
+    // The user who logged in or signed up on this client (see `current_user`).
+    pub(crate) current_user: Option<ParseUser>,
+    // Sent as `X-Parse-Installation-Id` with every request (see `ParseBuilder::installation_id`).
+    pub(crate) installation_id: Option<String>,
+    // Warnings reported by the server on the most recent response (shared between clones).
+    pub(crate) last_warnings: Arc<Mutex<Vec<String>>>,
+    // Mask credential header values in debug logs (see `ParseBuilder::log_redaction`).
+    pub(crate) log_redaction: bool,
+    // Expected length of object ids, or `None` if ids are not validated client-side.
+    pub(crate) object_id_length: Option<usize>,
+    // Hook invoked around every request (see `ParseBuilder::interceptor`).
+    pub(crate) interceptor: Option<Arc<dyn RequestInterceptor>>,
+    // Request counters reported by `stats` (shared between clones).
+    pub(crate) stats: Arc<RequestCounters>,
+    // Log out when dropped (see `ParseBuilder::logout_on_drop`). Not inherited by clones.
+    pub(crate) logout_on_drop: LogoutOnDrop,
+    // Ciphers for fields encrypted client-side, keyed by field name (see `set_field_cipher`).
+    #[cfg(feature = "encryption")]
+    pub(crate) field_ciphers: std::collections::HashMap<String, crate::encryption::FieldCipher>,
 }
 
 impl Parse {
@@ -119,6 +243,8 @@ impl Parse {
     /// 3. REST API Key (if provided and neither Master Key nor JavaScript Key are)
     ///
     /// A session token, once set (e.g., after login), will typically take precedence over these default keys for most operations.
+    /// Requests sent with an explicit [`AuthType`] (see [`Parse::request_with_auth`]) carry only the
+    /// selected credentials, e.g. the REST API Key even when a Master Key is configured.
     ///
     /// # Example
     ///
@@ -160,87 +286,169 @@ impl Parse {
         rest_api_key: Option<&str>,
         master_key: Option<&str>,
     ) -> Result<Self, ParseError> {
-        let mut temp_url_string = server_url.to_string();
-
-        // Ensure scheme is present
-        if !temp_url_string.starts_with("http://") && !temp_url_string.starts_with("https://") {
-            temp_url_string = format!("http://{}", temp_url_string);
+        let mut builder = ParseBuilder::new(server_url, app_id);
+        if let Some(javascript_key) = javascript_key {
+            builder = builder.javascript_key(javascript_key);
         }
-
-        let parsed_server_url = Url::parse(&temp_url_string)?;
-
-        if parsed_server_url.cannot_be_a_base() {
-            return Err(ParseError::SdkError(format!(
-                "The server_url '{}' (after ensuring scheme) resolved to '{}', which cannot be a base URL. Please provide a full base URL (e.g., http://localhost:1337/parse).",
-                server_url, parsed_server_url
-            )));
+        if let Some(rest_api_key) = rest_api_key {
+            builder = builder.rest_api_key(rest_api_key);
         }
-
-        let mut default_headers = HeaderMap::new();
-        default_headers.insert(
-            "X-Parse-Application-Id",
-            HeaderValue::from_str(app_id).map_err(ParseError::InvalidHeaderValue)?,
-        );
-
-        if let Some(mk_str) = master_key {
-            default_headers.insert(
-                "X-Parse-Master-Key",
-                HeaderValue::from_str(mk_str).map_err(ParseError::InvalidHeaderValue)?,
-            );
-        } else if let Some(js_key_str) = javascript_key {
-            default_headers.insert(
-                "X-Parse-Javascript-Key",
-                HeaderValue::from_str(js_key_str).map_err(ParseError::InvalidHeaderValue)?,
-            );
-        } else if let Some(rk_str) = rest_api_key {
-            default_headers.insert(
-                "X-Parse-REST-API-Key",
-                HeaderValue::from_str(rk_str).map_err(ParseError::InvalidHeaderValue)?,
-            );
+        if let Some(master_key) = master_key {
+            builder = builder.master_key(master_key);
         }
+        builder.build()
+    }
 
-        let http_client = Client::builder() // Updated to use alias
-            .default_headers(default_headers)
-            .build()
-            .map_err(ParseError::ReqwestError)?;
+    /// Returns a [`ParseBuilder`] for configuring a client beyond what [`Parse::new`] offers.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use parse_rs::Parse;
+    /// # use parse_rs::ParseError;
+    ///
+    /// # fn main() -> Result<(), ParseError> {
+    /// let client = Parse::builder("http://localhost:1338/parse", "myAppId")
+    ///     .master_key("myMasterKey")
+    ///     .log_redaction(false) // Log credentials in full, e.g. in local development
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder(server_url: &str, app_id: &str) -> ParseBuilder {
+        ParseBuilder::new(server_url, app_id)
+    }
 
-        let mut final_server_url = parsed_server_url.as_str().trim_end_matches('/').to_string();
+    /// Returns the warnings the server attached to the most recent response, if any.
+    ///
+    /// Parse Server reports deprecated usage through a `Warning` (or `X-Parse-Warning`) response
+    /// header, or a `warning`/`warnings` field in the JSON body. These are collected after every
+    /// request made through the client and replaced on the next one; each warning is also
+    /// logged at `warn` level. Clones of a client share this state.
+    pub fn last_warnings(&self) -> Vec<String> {
+        self.last_warnings
+            .lock()
+            .map(|warnings| warnings.clone())
+            .unwrap_or_default()
+    }
 
-        // If the URL ends with /parse, strip it to get the true base server URL.
-        // This makes the client resilient to PARSE_SERVER_URL being http://host/parse or http://host.
-        if final_server_url.ends_with("/parse") {
-            final_server_url.truncate(final_server_url.len() - "/parse".len());
-        }
-        // Ensure it's not empty after stripping (e.g. if PARSE_SERVER_URL was just "/parse")
-        if final_server_url.is_empty() && parsed_server_url.scheme() == "http"
-            || parsed_server_url.scheme() == "https"
-        {
-            // This case is unlikely if original URL was valid, but as a safeguard.
-            // Reconstruct from scheme and host if available, or error.
-            if let Some(host_str) = parsed_server_url.host_str() {
-                final_server_url = format!("{}://{}", parsed_server_url.scheme(), host_str);
-                if let Some(port) = parsed_server_url.port() {
-                    final_server_url.push_str(&format!(":{}", port));
+    /// Returns the request counters of this client: how many requests were sent, how many
+    /// succeeded or failed, and how many body bytes were transferred.
+    ///
+    /// Every request made through the client is counted, including file uploads and raw
+    /// requests. Clones of a client, including those made with
+    /// [`clone_with_session`](Self::clone_with_session), share the counters.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use parse_rs::{Parse, ParseError};
+    /// # async fn run(client: &Parse) -> Result<(), ParseError> {
+    /// let before = client.stats();
+    /// client.ping().await?;
+    /// let after = client.stats();
+    /// println!("{} request(s), {} failed", after.requests - before.requests, after.failures);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stats(&self) -> ParseStats {
+        self.stats.snapshot()
+    }
+
+    // Collects server warnings from a response's headers and JSON body into `last_warnings`.
+    pub(crate) fn record_warnings(&self, headers: &HeaderMap, body: Option<&Value>) {
+        let mut warnings: Vec<String> = ["warning", "x-parse-warning"]
+            .iter()
+            .flat_map(|name| headers.get_all(*name).iter())
+            .filter_map(|value| value.to_str().ok())
+            .map(str::to_string)
+            .collect();
+
+        if let Some(Value::Object(body)) = body {
+            for key in ["warning", "warnings"] {
+                match body.get(key) {
+                    Some(Value::String(warning)) => warnings.push(warning.clone()),
+                    Some(Value::Array(items)) => {
+                        warnings.extend(items.iter().filter_map(Value::as_str).map(str::to_string))
+                    }
+                    _ => {}
                 }
-            } else {
-                return Err(ParseError::SdkError("Server URL became empty after stripping /parse and could not be reconstructed.".to_string()));
             }
         }
 
-        log::debug!(
-            "Parse initialized with base server_url: {}",
-            final_server_url
-        );
+        for warning in &warnings {
+            log::warn!("Parse Server warning: {}", warning);
+        }
+        if let Ok(mut last_warnings) = self.last_warnings.lock() {
+            *last_warnings = warnings;
+        }
+    }
 
-        Ok(Self {
-            server_url: final_server_url,
-            app_id: app_id.to_string(),
-            javascript_key: javascript_key.map(|s| s.to_string()),
-            rest_api_key: rest_api_key.map(|s| s.to_string()),
-            master_key: master_key.map(|s| s.to_string()),
-            http_client,
-            session_token: None,
-        })
+    // Sends a request, running the configured interceptor (if any) around it. With the `tracing`
+    // feature, the response status is recorded on the current request span.
+    pub(crate) async fn send_request(
+        &self,
+        request_builder: RequestBuilder,
+    ) -> Result<Response, ParseError> {
+        self.send_request_with(&self.http_client, request_builder)
+            .await
+    }
+
+    // Like `send_request`, but executes the request on `http_client`, which must be one of this
+    // client's own (`http_client` or `foreign_http_client`).
+    pub(crate) async fn send_request_with(
+        &self,
+        http_client: &Client,
+        request_builder: RequestBuilder,
+    ) -> Result<Response, ParseError> {
+        let request = request_builder.build().map_err(ParseError::from)?;
+        let bytes_sent = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .map_or(0, |bytes| bytes.len() as u64);
+        let result = match &self.interceptor {
+            Some(interceptor) => {
+                Self::send_intercepted(http_client, interceptor.as_ref(), request).await
+            }
+            None => http_client.execute(request).await.map_err(ParseError::from),
+        };
+        self.stats.record(bytes_sent, &result);
+        let response = result?;
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("status", response.status().as_u16());
+        Ok(response)
+    }
+
+    async fn send_intercepted(
+        http_client: &Client,
+        interceptor: &dyn RequestInterceptor,
+        mut request: reqwest::Request,
+    ) -> Result<Response, ParseError> {
+        let mut parts = RequestParts {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: std::mem::take(request.headers_mut()),
+        };
+        interceptor.before_request(&mut parts).await;
+        *request.method_mut() = parts.method.clone();
+        *request.url_mut() = parts.url.clone();
+        *request.headers_mut() = parts.headers;
+
+        let started = std::time::Instant::now();
+        let response = http_client
+            .execute(request)
+            .await
+            .map_err(ParseError::from)?;
+        interceptor
+            .after_response(&ResponseParts {
+                method: parts.method,
+                url: parts.url,
+                status: response.status(),
+                headers: response.headers().clone(),
+                elapsed: started.elapsed(),
+            })
+            .await;
+        Ok(response)
     }
 
     // Internal method to set or clear the session token.
@@ -273,6 +481,72 @@ impl Parse {
         self.session_token.as_deref()
     }
 
+    /// Returns the current user without a network request.
+    ///
+    /// The user is cached when one signs up, logs in (also anonymously), becomes another user
+    /// or is updated through [`user()`](Self::user), and cleared on logout. It reflects the
+    /// user as of that moment; use [`refresh_current_user`](Self::refresh_current_user) to
+    /// fetch its latest state. Returns `None` if no user is logged in on this client, or if the
+    /// server response did not describe the user (e.g. a signup with a custom body without a
+    /// `username`).
+    pub fn current_user(&self) -> Option<&ParseUser> {
+        self.current_user.as_ref()
+    }
+
+    /// Fetches the current user from `/users/me` and caches it for
+    /// [`current_user`](Self::current_user).
+    ///
+    /// Fails with `ParseError::SessionTokenMissing` if no session token is set. If the server
+    /// rejects the session token, the cached user is cleared.
+    pub async fn refresh_current_user(&mut self) -> Result<&ParseUser, ParseError> {
+        match self.user().me().await {
+            Ok(user) => Ok(self.current_user.insert(user)),
+            Err(e) => {
+                if e.is_auth_error() {
+                    self.current_user = None;
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Returns a copy of the client that uses `session_token` instead of this client's token.
+    ///
+    /// This is a cheap way to act on behalf of a particular user in a server handling many users
+    /// concurrently: the copy shares the underlying HTTP client (and its connection pool) and
+    /// configuration, but has its own session token and [`last_warnings`](Self::last_warnings),
+    /// so it can be used without affecting other requests. Pass `None` for a client without a
+    /// session token. Logging in or out on the copy does not change this client.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use parse_rs::{Parse, ParseError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ParseError> {
+    /// let client = Parse::new("http://localhost:1338/parse", "myAppId", None, Some("myRestKey"), None)?;
+    ///
+    /// // E.g. per incoming request, with the token the caller authenticated with:
+    /// let mut user_client = client.clone_with_session(Some("r:abc123".to_string()));
+    /// let user = user_client.user().me().await?;
+    /// println!("Request made by {}", user.username);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clone_with_session(&self, session_token: Option<String>) -> Parse {
+        let mut client = self.clone();
+        client.session_token = session_token;
+        client.current_user = None;
+        client.last_warnings = Arc::new(Mutex::new(Vec::new()));
+        client
+    }
+
+    /// Returns the installation id configured with
+    /// [`ParseBuilder::installation_id`](crate::ParseBuilder::installation_id), if any.
+    pub fn installation_id(&self) -> Option<&str> {
+        self.installation_id.as_deref()
+    }
+
     // Installation related methods
 
     /// Creates a query for Installation objects.
@@ -283,6 +557,43 @@ impl Parse {
         ParseQuery::new("_Installation")
     }
 
+    /// Creates a query for User objects, for admin tooling that lists or cleans up users.
+    ///
+    /// The query targets `_User`, so constraints apply as usual and results can be read with
+    /// `find::<ParseUser>`. It is set to use the Master Key, as listing other users is typically
+    /// restricted; call `set_master_key(false)` on it to query with the client's regular
+    /// credentials instead. Pair it with [`delete_user`](Self::delete_user) to remove users.
+    ///
+    /// # Returns
+    /// A `ParseQuery` instance, configured for the "_User" class.
+    pub fn query_users(&self) -> ParseQuery {
+        let mut query = ParseQuery::new("_User");
+        query.set_master_key(true);
+        query
+    }
+
+    /// Creates a query for Role objects, e.g. to look up a role by name.
+    ///
+    /// Results deserialize into [`ParseRole`](crate::ParseRole), including the role's ACL:
+    ///
+    /// ```rust,no_run
+    /// # use parse_rs::{Parse, ParseError, ParseRole};
+    /// # async fn run(client: &Parse) -> Result<(), ParseError> {
+    /// let admin: Option<ParseRole> = client
+    ///     .query_roles()
+    ///     .equal_to("name", "Admin")
+    ///     .first(client)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    /// A `ParseQuery` instance, configured for the "_Role" class.
+    pub fn query_roles(&self) -> ParseQuery {
+        ParseQuery::new("_Role")
+    }
+
     /// Checks if the client currently has an active session token.
     ///
     /// This is a convenience method equivalent to `client.session_token().is_some()`.
@@ -309,11 +620,15 @@ impl Parse {
     /// Uploads a file to the Parse Server.
     ///
     /// This method sends the raw byte data of a file to the Parse Server, which then stores it
-    /// and returns a `FileField` containing the URL and name of the stored file. This `FileField`
-    /// can then be associated with a `ParseObject`.
+    /// and returns a `ParseFile` containing the name and URL assigned by the server, along with the
+    /// MIME type and size of the uploaded content. The returned `ParseFile` can then be associated
+    /// with a `ParseObject`; it serializes as a Parse `File` reference.
     ///
-    /// Note: File uploads require the Master Key to be configured on the `Parse` or for the
-    /// `use_master_key` parameter in the underlying `_request_file_upload` to be true (which is the default for this public method).
+    /// Credentials are chosen like for any other request: the session token if a user is logged
+    /// in, along with the client's configured key (the Master Key, else the JavaScript key, else
+    /// the REST API key). Without the Master Key, the upload is subject to the server's file
+    /// upload settings. Use [`upload_file_with_auth`](Self::upload_file_with_auth) to pick the
+    /// credentials explicitly.
     ///
     /// # Arguments
     ///
@@ -323,14 +638,15 @@ impl Parse {
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `FileField` on success, which includes the `name` and `url` of the uploaded file.
+    /// A `Result` containing a `ParseFile` on success, which includes the `name` and `url` of the
+    /// uploaded file as well as its `mime_type()` and `size()`. Its `data` is left empty.
     /// Returns a `ParseError` if the upload fails due to network issues, server errors, incorrect permissions,
     /// or misconfiguration.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use parse_rs::{Parse, ParseError, FileField, ParseObject, object::CreateObjectResponse};
+    /// use parse_rs::{Parse, ParseError, ParseFile, ParseObject, object::CreateObjectResponse};
     /// use serde_json::Value;
     /// use std::collections::HashMap;
     ///
@@ -346,14 +662,20 @@ impl Parse {
     /// let mime_type = "image/png";
     ///
     /// // Upload the file
-    /// let file_field: FileField = client.upload_file(file_name, file_data, mime_type).await?;
+    /// let file: ParseFile = client.upload_file(file_name, file_data, mime_type).await?;
     ///
-    /// println!("File uploaded successfully: Name - {}, URL - {}", file_field.name, file_field.url);
+    /// println!(
+    ///     "File uploaded successfully: Name - {}, URL - {:?}, {} bytes of {}",
+    ///     file.name(),
+    ///     file.url(),
+    ///     file.size(),
+    ///     file.mime_type()
+    /// );
     ///
-    /// // Now, you can associate this FileField with a ParseObject
+    /// // Now, you can associate this file with a ParseObject
     /// let mut player_profile_data = HashMap::new();
     /// player_profile_data.insert("playerName".to_string(), Value::String("John Doe".to_string()));
-    /// player_profile_data.insert("profilePicture".to_string(), serde_json::to_value(file_field)?);
+    /// player_profile_data.insert("profilePicture".to_string(), serde_json::to_value(&file)?);
     ///
     /// let mut player_profile = ParseObject::new("PlayerProfile");
     /// let created_profile: CreateObjectResponse = client.create_object("PlayerProfile", &player_profile).await?;
@@ -367,7 +689,89 @@ impl Parse {
         file_name: &str,
         data: Vec<u8>,
         mime_type: &str,
-    ) -> Result<FileField, ParseError> {
+    ) -> Result<ParseFile, ParseError> {
+        self._upload_file(file_name, data, mime_type, RequestOptions::default())
+            .await
+    }
+
+    /// Uploads a file, authenticated with exactly the credentials selected by `auth`.
+    ///
+    /// Behaves like [`upload_file`](Self::upload_file), but sends only what `auth` asks for
+    /// (see [`request_with_auth`](Self::request_with_auth)), e.g. `AuthType::RestApiKey` to
+    /// upload under the file CLPs on a client that also holds the Master Key.
+    pub async fn upload_file_with_auth(
+        &self,
+        file_name: &str,
+        data: Vec<u8>,
+        mime_type: &str,
+        auth: AuthType,
+    ) -> Result<ParseFile, ParseError> {
+        let options = RequestOptions {
+            auth: Some(auth),
+            ..Default::default()
+        };
+        self._upload_file(file_name, data, mime_type, options).await
+    }
+
+    /// Uploads a file and creates an object referencing it in the field `file_field_name`.
+    ///
+    /// `body` holds the object's other fields (e.g. a [`ParseObject`] or a `serde_json` map).
+    /// The file is uploaded first; if that fails, no object is created. The body is checked
+    /// before uploading, so an invalid class name or a body that is not a JSON object fails
+    /// with `ParseError::InvalidInput` without uploading anything. If creating the object
+    /// fails after the upload, the uploaded file is left unreferenced on the server.
+    ///
+    /// Returns the created object's `objectId`/`createdAt` together with the uploaded file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use parse_rs::{Parse, ParseError, ParseObject};
+    ///
+    /// # async fn run(client: &Parse, avatar_png: Vec<u8>) -> Result<(), ParseError> {
+    /// let profile = ParseObject::new("PlayerProfile").with("playerName", "Sean");
+    /// let (created, avatar) = client
+    ///     .save_object_with_file("PlayerProfile", &profile, "avatar", "avatar.png", avatar_png, "image/png")
+    ///     .await?;
+    /// println!("Created {} with avatar at {:?}", created.object_id, avatar.url());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn save_object_with_file<T: Serialize + Send + Sync>(
+        &self,
+        class_name: &str,
+        body: &T,
+        file_field_name: &str,
+        file_name: &str,
+        data: Vec<u8>,
+        mime_type: &str,
+    ) -> Result<(CreateObjectResponse, ParseFile), ParseError> {
+        validate_class_name(class_name)?;
+        let mut fields = match serde_json::to_value(body)? {
+            Value::Object(fields) => fields,
+            other => {
+                return Err(ParseError::InvalidInput(format!(
+                    "The object body must be a JSON object, got {}",
+                    other
+                )))
+            }
+        };
+
+        let file = self.upload_file(file_name, data, mime_type).await?;
+        fields.insert(file_field_name.to_string(), serde_json::to_value(&file)?);
+        let created = self
+            .create_object(class_name, &Value::Object(fields))
+            .await?;
+        Ok((created, file))
+    }
+
+    async fn _upload_file(
+        &self,
+        file_name: &str,
+        data: Vec<u8>,
+        mime_type: &str,
+        options: RequestOptions<'_>,
+    ) -> Result<ParseFile, ParseError> {
         let file_path_segment = format!("files/{}", file_name); // Path relative to /parse endpoint
         let server_url_str = self.server_url.as_str();
 
@@ -399,32 +803,13 @@ impl Parse {
             "X-Parse-Application-Id",
             HeaderValue::from_str(&self.app_id).map_err(ParseError::InvalidHeaderValue)?,
         );
-        // Master key is typically required for creating files directly, unless CLPs are very open.
-        // If session token is present, it might be used depending on server config / CLPs.
-        // For simplicity here, let's assume master key if no session token, or make it explicit if needed.
-        if let Some(token) = &self.session_token {
-            headers.insert(
-                "X-Parse-Session-Token",
-                HeaderValue::from_str(token).map_err(ParseError::InvalidHeaderValue)?,
-            );
-        } else if let Some(mk) = &self.master_key {
-            headers.insert(
-                "X-Parse-Master-Key",
-                HeaderValue::from_str(mk).map_err(ParseError::InvalidHeaderValue)?,
-            );
-        } else {
-            // Or return an error if neither is present and master key is strictly required
-            // For now, proceed, relying on server's default behavior / public CLPs if any.
-            log::warn!(
-                "Uploading file without explicit master key or session token. Relies on CLPs."
-            );
-        }
+        self.apply_auth_headers(&mut headers, &options)?;
         headers.insert(
             CONTENT_TYPE,
             HeaderValue::from_str(mime_type).map_err(ParseError::InvalidHeaderValue)?,
         );
 
-        request_builder = request_builder.headers(headers);
+        request_builder = request_builder.headers(headers.clone()); // Clone headers for logging
 
         let data_len = data.len(); // Capture length before move
         request_builder = request_builder.body(data); // data is moved here
@@ -433,26 +818,22 @@ impl Parse {
         log::debug!("--- Parse: Uploading File ---");
         log::debug!("URL: {}", final_url.as_str());
         log::debug!("Method: POST");
-        // Headers are already part of request_builder, logging them directly from it is complex.
-        // For now, we'll skip detailed header logging here, assuming _request's logging is the primary source.
-        log::debug!("Content-Type: {}", mime_type);
+        self.log_request_headers(&headers);
         log::debug!("Body: <binary data of size {}>", data_len); // Use captured length
         log::debug!("-----------------------------------");
 
-        let response = request_builder
-            .send()
-            .await
-            .map_err(ParseError::ReqwestError)?;
+        let response = self.send_request(request_builder).await?;
 
         let upload_response: FileUploadResponse = self
             ._send_and_process_response(response, &file_path_segment)
             .await?; // Pass response and endpoint context
 
-        Ok(FileField {
-            _type: "File".to_string(),
-            name: upload_response.name,
-            url: upload_response.url,
-        })
+        Ok(ParseFile::uploaded(
+            upload_response.name,
+            upload_response.url,
+            mime_type.to_string(),
+            data_len,
+        ))
     }
 
     // Aggregate queries
@@ -535,7 +916,7 @@ impl Parse {
         let endpoint = format!("aggregate/{}", class_name);
         // Serialize the pipeline to a JSON string
         let pipeline_str = serde_json::to_string(&pipeline).map_err(|e| {
-            ParseError::SerializationError(format!("Failed to serialize pipeline: {}", e))
+            ParseError::SerializationError(format!("Failed to serialize pipeline: {}", e), e)
         })?;
 
         // Construct query parameters
@@ -625,6 +1006,7 @@ impl Parse {
                 "Deleting a user by objectId requires the Master Key to be configured on the client.".to_string(),
             ));
         }
+        self.validate_object_id(object_id)?;
         let endpoint = format!("users/{}", object_id);
         // Always use master key for deleting another user by ID.
         let _response: Value = self
@@ -642,14 +1024,15 @@ impl Parse {
     /// Executes a `ParseQuery` and returns a list of matching objects.
     ///
     /// # Arguments
-    /// * `query`: A reference to the `ParseQuery` to execute.
+    /// * `query`: The `ParseQuery` to execute, either owned or borrowed.
     ///
     /// # Returns
     /// A `Result` containing a `Vec<T>` of the deserialized objects or a `ParseError`.
     pub async fn execute_query<T: DeserializeOwned + Send + Sync + 'static>(
         &self,
-        query: &crate::query::ParseQuery, // Corrected: ParseQuery is not generic itself
+        query: impl Into<ParseQuery>,
     ) -> Result<Vec<T>, ParseError> {
+        let query = query.into();
         let class_name = query.class_name();
         let base_endpoint = format!("classes/{}", class_name);
         let params = query.build_query_params(); // Assuming this method exists on ParseQuery
@@ -659,30 +1042,36 @@ impl Parse {
         let use_master_key = query.uses_master_key(); // Check if query explicitly needs master key
         let session_token_to_use = self.session_token.as_deref();
 
-        let response: QueryResponse<T> = self
-            ._get_with_url_params(
+        let response: QueryResponse<Value> = self
+            ._get_or_post_with_url_params(
                 &base_endpoint, // Pass relative endpoint
                 &params,
                 use_master_key,
                 session_token_to_use,
+                query.forces_post(),
             )
             .await?;
-        Ok(response.results)
+        response
+            .results
+            .into_iter()
+            .map(|object| self.decode_object(class_name, object))
+            .collect()
     }
 
     /// Executes a `ParseQuery` and returns a list of `ParseObject` instances,
     /// ensuring the `class_name` field is populated for each object from the query.
     ///
     /// # Arguments
-    /// * `query`: A reference to the `ParseQuery` to execute.
+    /// * `query`: The `ParseQuery` to execute, either owned or borrowed.
     ///
     /// # Returns
     /// A `Result` containing a `Vec<ParseObject>` or a `ParseError`.
     pub async fn find_objects(
         &self,
-        query: &crate::query::ParseQuery,
+        query: impl Into<ParseQuery>,
     ) -> Result<Vec<ParseObject>, ParseError> {
-        let mut objects: Vec<ParseObject> = self.execute_query(query).await?;
+        let query = query.into();
+        let mut objects: Vec<ParseObject> = self.execute_query(&query).await?;
         let class_name_from_query = query.class_name().to_string();
 
         for object in objects.iter_mut() {
@@ -1129,7 +1518,8 @@ impl Parse {
 
     /// Returns a `ParseCloud` handle for calling Parse Cloud Code functions.
     ///
-    /// The `ParseCloud` handle provides the `call_function` method to execute server-side Cloud Code.
+    /// The `ParseCloud` handle provides the `run` method to execute server-side Cloud Code, as the
+    /// current user if one is logged in, and `run_as_master` to run it with the Master Key.
     ///
     /// # Examples
     ///
@@ -1146,10 +1536,10 @@ impl Parse {
     /// let function_name = "helloWorld";
     /// let params = json!({ "name": "Rustaceans" });
     ///
-    /// // match client.cloud().call_function(function_name, Some(params)).await {
-    /// //     Ok(result) => println!("Cloud function '{}' returned: {}", function_name, result),
-    /// //     Err(e) => eprintln!("Cloud function '{}' failed: {}", function_name, e),
-    /// // }
+    /// match client.cloud().run::<_, serde_json::Value>(function_name, &params).await {
+    ///     Ok(result) => println!("Cloud function '{}' returned: {}", function_name, result),
+    ///     Err(e) => eprintln!("Cloud function '{}' failed: {}", function_name, e),
+    /// }
     /// # Ok(())
     /// # }
     /// ```
@@ -1210,6 +1600,26 @@ impl Parse {
     }
 }
 
+impl Drop for Parse {
+    // Best-effort logout for `ParseBuilder::logout_on_drop`. `Drop` cannot await, so the request
+    // runs on a task spawned onto the current Tokio runtime, sent by a clone of this client.
+    fn drop(&mut self) {
+        if !self.logout_on_drop.0 || self.session_token.is_none() {
+            return;
+        }
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            log::warn!("Client dropped outside of a Tokio runtime; its session was not logged out");
+            return;
+        };
+        let mut client = self.clone();
+        runtime.spawn(async move {
+            if let Err(e) = client.user().logout().await {
+                log::warn!("Logout on drop failed: {}", e);
+            }
+        });
+    }
+}
+
 // Temporary struct for deserializing file upload response
 #[derive(serde::Deserialize, Debug)]
 struct FileUploadResponse {
@@ -1245,21 +1655,38 @@ impl Parse {
         use_master_key: bool,
         session_token_override: Option<&str>,
     ) -> Result<R, ParseError> {
-        let base_url = Url::parse(&self.server_url).map_err(|e| {
-            ParseError::InvalidUrl(format!(
-                "Base server URL '{}' is invalid: {}",
-                self.server_url, e
-            ))
-        })?;
-
-        let api_path = format!("/parse/{}", endpoint.trim_start_matches('/'));
+        self._get_or_post_with_url_params(
+            endpoint,
+            params,
+            use_master_key,
+            session_token_override,
+            false,
+        )
+        .await
+    }
 
-        let mut full_url = base_url.join(&api_path).map_err(|e| {
-            ParseError::InvalidUrl(format!(
-                "Failed to join base URL '{}' with API path '{}': {}",
-                base_url, api_path, e
-            ))
-        })?;
+    // Same as `_get_with_url_params`, but sends the parameters as the JSON body of a POST with
+    // `_method=GET` (which Parse Server treats as a GET) when `force_post` is set or the URL would
+    // exceed `MAX_GET_URL_LENGTH`, e.g. for queries with large `$in` or `$or` clauses.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "parse_request",
+            level = "debug",
+            skip_all,
+            fields(method = "GET", endpoint = endpoint, status = tracing::field::Empty)
+        )
+    )]
+    pub(crate) async fn _get_or_post_with_url_params<R: DeserializeOwned + Send + 'static>(
+        &self,
+        endpoint: &str,
+        params: &[(String, String)],
+        use_master_key: bool,
+        session_token_override: Option<&str>,
+        force_post: bool,
+    ) -> Result<R, ParseError> {
+        let base_url = self._api_url(endpoint)?;
+        let mut full_url = base_url.clone();
 
         // Add query parameters
         if !params.is_empty() {
@@ -1268,15 +1695,33 @@ impl Parse {
             }
         }
 
+        let use_post = force_post || full_url.as_str().len() > MAX_GET_URL_LENGTH;
+        let mut request_builder = if use_post {
+            // Values are decoded the way Parse Server decodes query strings: JSON where possible
+            // (so `where` becomes an object and `limit` a number), the raw string otherwise.
+            let mut body = serde_json::Map::new();
+            body.insert("_method".to_string(), Value::String("GET".to_string()));
+            for (key, value) in params {
+                let value =
+                    serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.clone()));
+                body.insert(key.clone(), value);
+            }
+            full_url = base_url;
+            self.http_client
+                .post(full_url.clone())
+                .body(Value::Object(body).to_string())
+        } else {
+            self.http_client.get(full_url.clone())
+        };
+
         log::debug!(
-            "Preparing GET request with params: URL={}, UseMasterKey={}, SessionTokenOverride={:?}",
+            "Preparing GET request with params: URL={}, UseMasterKey={}, HasSessionTokenOverride={}, ViaPost={}",
             full_url.as_str(),
             use_master_key,
-            session_token_override
+            session_token_override.is_some(),
+            use_post
         );
 
-        let mut request_builder = self.http_client.get(full_url.clone());
-
         // Apply authentication headers based on context
         let mut headers = HeaderMap::new();
         headers.insert(
@@ -1285,42 +1730,12 @@ impl Parse {
         );
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
 
-        // Authentication headers - applied in order of precedence
-        if let Some(token_override) = session_token_override {
-            headers.insert(
-                "X-Parse-Session-Token",
-                HeaderValue::from_str(token_override).map_err(ParseError::InvalidHeaderValue)?,
-            );
-        } else if use_master_key {
-            if let Some(master_key) = &self.master_key {
-                headers.insert(
-                    "X-Parse-Master-Key",
-                    HeaderValue::from_str(master_key).map_err(ParseError::InvalidHeaderValue)?,
-                );
-            } else {
-                log::warn!("Master key requested for operation but not configured.");
-                return Err(ParseError::MasterKeyRequired(
-                    "Master key is required for this operation but not configured.".to_string(),
-                ));
-            }
-        } else if let Some(session_token) = &self.session_token {
-            // Client's default session token
-            headers.insert(
-                "X-Parse-Session-Token",
-                HeaderValue::from_str(session_token).map_err(ParseError::InvalidHeaderValue)?,
-            );
-        } else if let Some(js_key) = &self.javascript_key {
-            headers.insert(
-                "X-Parse-Javascript-Key",
-                HeaderValue::from_str(js_key).map_err(ParseError::InvalidHeaderValue)?,
-            );
-        } else if let Some(rest_key) = &self.rest_api_key {
-            // Fallback to REST API Key
-            headers.insert(
-                "X-Parse-REST-API-Key",
-                HeaderValue::from_str(rest_key).map_err(ParseError::InvalidHeaderValue)?,
-            );
-        }
+        let options = RequestOptions {
+            use_master_key,
+            session_token_override,
+            ..Default::default()
+        };
+        self.apply_auth_headers(&mut headers, &options)?;
 
         request_builder = request_builder.headers(headers.clone()); // Clone headers for logging if needed
 
@@ -1328,22 +1743,20 @@ impl Parse {
         if log::log_enabled!(log::Level::Debug) {
             log::debug!("--- Parse GET Request --- ");
             log::debug!("URL: {}", full_url.as_str());
-            log::debug!("Method: GET");
-            for (name, value) in headers.iter() {
-                log::debug!(
-                    "Header: {}: {:?}",
-                    name.as_str(),
-                    value.to_str().unwrap_or("[non-ASCII value]")
-                );
-            }
+            log::debug!(
+                "Method: {}",
+                if use_post {
+                    "POST (_method=GET)"
+                } else {
+                    "GET"
+                }
+            );
+            self.log_request_headers(&headers);
             log::debug!("------------------------------");
         }
 
         // Perform the actual HTTP request
-        let response = request_builder
-            .send()
-            .await
-            .map_err(ParseError::ReqwestError)?;
+        let response = self.send_request(request_builder).await?;
 
         // Log response status and headers (conditionally)
         if log::log_enabled!(log::Level::Debug) {
@@ -1355,30 +1768,29 @@ impl Parse {
         }
 
         let status = response.status();
+        let response_headers = response.headers().clone();
         if status.is_success() {
-            let body_bytes = response.bytes().await.map_err(ParseError::ReqwestError)?;
+            let body_bytes = response.bytes().await.map_err(ParseError::from)?;
+            let body = serde_json::from_slice::<Value>(&body_bytes).ok();
+            self.record_warnings(&response_headers, body.as_ref());
             log::debug!(
                 "Request successful. Response body: {}",
                 String::from_utf8_lossy(&body_bytes)
             );
-            serde_json::from_slice(&body_bytes).map_err(|e| {
-                ParseError::JsonDeserializationFailed(format!(
-                    "Error: {}, Body: {}",
-                    e,
-                    String::from_utf8_lossy(&body_bytes).into_owned()
-                ))
-            })
+            deserialize_success_body(&body_bytes, body)
         } else {
-            let error_body_bytes = response.bytes().await.map_err(ParseError::ReqwestError)?;
+            let error_body_bytes = response.bytes().await.map_err(ParseError::from)?;
+            let error_body = serde_json::from_slice::<Value>(&error_body_bytes).ok();
+            self.record_warnings(&response_headers, error_body.as_ref());
             let error_body_string = String::from_utf8_lossy(&error_body_bytes).to_string();
             log::warn!(
                 "Request failed with status {} and body: {}",
                 status,
                 error_body_string
             );
-            match serde_json::from_slice::<Value>(&error_body_bytes) {
-                Ok(json_value) => Err(ParseError::from_response(status.as_u16(), json_value)),
-                Err(_) => {
+            match error_body {
+                Some(json_value) => Err(ParseError::from_response(status.as_u16(), json_value)),
+                None => {
                     let fallback_json = serde_json::json!({
                         "code": status.as_u16(),
                         "error": error_body_string
@@ -1389,18 +1801,8 @@ impl Parse {
         }
     }
 
-    // Central request method
-    pub(crate) async fn _request<
-        T: Serialize + Send + Sync,
-        R: DeserializeOwned + Send + 'static,
-    >(
-        &self,
-        method: Method,
-        endpoint: &str, // Takes relative endpoint string
-        body: Option<&T>,
-        use_master_key: bool,
-        session_token_override: Option<&str>,
-    ) -> Result<R, ParseError> {
+    // Builds the absolute URL for an API endpoint relative to the `/parse` mount path.
+    pub(crate) fn _api_url(&self, endpoint: &str) -> Result<Url, ParseError> {
         let base_url = Url::parse(&self.server_url).map_err(|e| {
             ParseError::InvalidUrl(format!(
                 "Base server URL '{}' is invalid: {}",
@@ -1412,51 +1814,303 @@ impl Parse {
         // Trim any leading slashes from the original endpoint to avoid issues like "/parse//classes".
         let api_path = format!("/parse/{}", endpoint.trim_start_matches('/'));
 
-        let full_url = base_url.join(&api_path).map_err(|e| {
+        base_url.join(&api_path).map_err(|e| {
             ParseError::InvalidUrl(format!(
                 "Failed to join base URL '{}' with API path '{}': {}",
                 base_url, api_path, e
             ))
-        })?;
+        })
+    }
 
-        log::debug!(
-            "Preparing request: Method={}, URL={}, UseMasterKey={}, SessionTokenOverride={:?}",
-            method,
-            full_url.as_str(), // Log the full_url
-            use_master_key,
-            session_token_override
-        );
+    // Rejects object ids that Parse Server cannot have generated (wrong length or characters other
+    // than ASCII letters and digits) before making a round-trip. Only enabled with
+    // `ParseBuilder::validate_object_ids(true)`, since servers may allow custom object ids.
+    pub(crate) fn validate_object_id(&self, object_id: &str) -> Result<(), ParseError> {
+        let Some(expected_length) = self.object_id_length else {
+            return Ok(());
+        };
+        if object_id.len() != expected_length
+            || !object_id.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err(ParseError::InvalidInput(format!(
+                "Malformed object ID '{}': expected {} letters or digits.",
+                object_id, expected_length
+            )));
+        }
+        Ok(())
+    }
 
-        let mut request_builder = self.http_client.request(method.clone(), full_url.clone());
+    // Formats a header value for debug logging. Credentials are reduced to their first four
+    // characters followed by `***` (or just `***` if they are too short to reveal anything).
+    pub(crate) fn loggable_header_value(&self, name: &str, value: &HeaderValue) -> String {
+        let value = value.to_str().unwrap_or("[non-ASCII value]");
+        if !self.log_redaction
+            || !SENSITIVE_HEADERS
+                .iter()
+                .any(|sensitive| sensitive.eq_ignore_ascii_case(name))
+        {
+            return value.to_string();
+        }
+        if value.chars().count() > 8 {
+            format!("{}***", value.chars().take(4).collect::<String>())
+        } else {
+            "***".to_string()
+        }
+    }
+
+    // Formats a JSON request body for debug logging. Unless redaction is disabled, the values of
+    // `password` fields (e.g. in signup, login or user update bodies) are replaced by `***`.
+    pub(crate) fn loggable_body(&self, body: &str) -> String {
+        if !self.log_redaction {
+            return body.to_string();
+        }
+        match serde_json::from_str::<Value>(body) {
+            Ok(mut value) => {
+                redact_passwords(&mut value);
+                serde_json::to_string_pretty(&value).unwrap_or_else(|_| body.to_string())
+            }
+            Err(_) => body.to_string(),
+        }
+    }
 
-        let mut headers = HeaderMap::new(); // Start with an empty map for request-specific headers
+    // Logs request headers at debug level, masking credentials.
+    fn log_request_headers(&self, headers: &HeaderMap) {
+        if log::log_enabled!(log::Level::Debug) {
+            for (name, value) in headers.iter() {
+                log::debug!(
+                    "Header: {}: {}",
+                    name.as_str(),
+                    self.loggable_header_value(name.as_str(), value)
+                );
+            }
+        }
+    }
 
-        // Determine effective session token
-        let effective_session_token = session_token_override.or(self.session_token.as_deref());
+    // Inserts the credentials for a request, shared by every request path so they cannot drift.
+    //
+    // Precedence: explicit `AuthType` > session token override > per-request master key > client
+    // session token, so a session-based client can elevate a single call with the master key
+    // without being reconfigured. Unless the credentials were chosen explicitly, the client's
+    // default key is then added alongside (see `insert_default_key_header`); `as_user` requests
+    // that carry a session token get the client key without the master key instead.
+    pub(crate) fn apply_auth_headers(
+        &self,
+        headers: &mut HeaderMap,
+        options: &RequestOptions<'_>,
+    ) -> Result<(), ParseError> {
+        if let Some(auth) = options.auth {
+            return self.insert_auth_type_headers(
+                headers,
+                auth,
+                options.session_token_override,
+                options.master_key_override,
+            );
+        }
 
-        if let Some(token) = effective_session_token {
+        if let Some(token) = options.session_token_override {
             headers.insert(
                 "X-Parse-Session-Token",
                 HeaderValue::from_str(token).map_err(ParseError::InvalidHeaderValue)?,
             );
-        } else if use_master_key {
-            // Only add Master Key if no session token is being used for this request
-            if let Some(master_key) = &self.master_key {
-                headers.insert(
-                    "X-Parse-Master-Key",
-                    HeaderValue::from_str(master_key).map_err(ParseError::InvalidHeaderValue)?,
-                );
-            } else {
+        } else if options.use_master_key {
+            let Some(master_key) = options.master_key_override.or(self.master_key.as_deref())
+            else {
                 log::warn!("Master key requested for operation but not configured for the client.");
                 return Err(ParseError::MasterKeyRequired(
                     "Master key is required for this operation but not configured on the client."
                         .to_string(),
                 ));
+            };
+            headers.insert(
+                "X-Parse-Master-Key",
+                HeaderValue::from_str(master_key).map_err(ParseError::InvalidHeaderValue)?,
+            );
+        } else if let Some(token) = &self.session_token {
+            headers.insert(
+                "X-Parse-Session-Token",
+                HeaderValue::from_str(token).map_err(ParseError::InvalidHeaderValue)?,
+            );
+        }
+
+        if options.as_user && headers.contains_key("X-Parse-Session-Token") {
+            self.insert_client_key_header(headers)
+        } else {
+            self.insert_default_key_header(headers)
+        }
+    }
+
+    // Inserts the key a request falls back to when its credentials were not chosen explicitly:
+    // the master key, else the JavaScript key, else the REST API key (the precedence documented
+    // on `Parse::new`). A header that is already present is left untouched.
+    pub(crate) fn insert_default_key_header(
+        &self,
+        headers: &mut HeaderMap,
+    ) -> Result<(), ParseError> {
+        match &self.master_key {
+            Some(master_key) => Self::insert_key_header(headers, "X-Parse-Master-Key", master_key),
+            None => self.insert_client_key_header(headers),
+        }
+    }
+
+    // Inserts the JavaScript key, else the REST API key: the default key header without the
+    // master key.
+    pub(crate) fn insert_client_key_header(
+        &self,
+        headers: &mut HeaderMap,
+    ) -> Result<(), ParseError> {
+        if let Some(js_key) = &self.javascript_key {
+            Self::insert_key_header(headers, "X-Parse-Javascript-Key", js_key)
+        } else if let Some(rest_key) = &self.rest_api_key {
+            Self::insert_key_header(headers, "X-Parse-REST-API-Key", rest_key)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn insert_key_header(
+        headers: &mut HeaderMap,
+        name: &'static str,
+        key: &str,
+    ) -> Result<(), ParseError> {
+        if !headers.contains_key(name) {
+            headers.insert(
+                name,
+                HeaderValue::from_str(key).map_err(ParseError::InvalidHeaderValue)?,
+            );
+        }
+        Ok(())
+    }
+
+    // Inserts exactly the credentials selected by `auth`, and nothing else.
+    //
+    // `SessionToken` and `MasterKey` fail if the client has no such credential (the overrides take
+    // the place of the client's own); `RestApiKey` falls back to the JavaScript key.
+    pub(crate) fn insert_auth_type_headers(
+        &self,
+        headers: &mut HeaderMap,
+        auth: AuthType,
+        session_token_override: Option<&str>,
+        master_key_override: Option<&str>,
+    ) -> Result<(), ParseError> {
+        match auth {
+            AuthType::SessionToken => {
+                let token = session_token_override
+                    .or(self.session_token.as_deref())
+                    .ok_or(ParseError::SessionTokenMissing)?;
+                headers.insert(
+                    "X-Parse-Session-Token",
+                    HeaderValue::from_str(token).map_err(ParseError::InvalidHeaderValue)?,
+                );
             }
+            AuthType::MasterKey => {
+                let master_key = master_key_override
+                    .or(self.master_key.as_deref())
+                    .ok_or_else(|| {
+                        ParseError::MasterKeyRequired(
+                            "Master key is required for this request but not configured on the client."
+                                .to_string(),
+                        )
+                    })?;
+                headers.insert(
+                    "X-Parse-Master-Key",
+                    HeaderValue::from_str(master_key).map_err(ParseError::InvalidHeaderValue)?,
+                );
+            }
+            AuthType::RestApiKey => {
+                if let Some(rest_key) = &self.rest_api_key {
+                    headers.insert(
+                        "X-Parse-REST-API-Key",
+                        HeaderValue::from_str(rest_key).map_err(ParseError::InvalidHeaderValue)?,
+                    );
+                } else if let Some(js_key) = &self.javascript_key {
+                    headers.insert(
+                        "X-Parse-Javascript-Key",
+                        HeaderValue::from_str(js_key).map_err(ParseError::InvalidHeaderValue)?,
+                    );
+                } else {
+                    log::warn!("REST API key requested for operation but no REST or JavaScript key is configured.");
+                }
+            }
+            AuthType::NoAuth => {}
+        }
+        Ok(())
+    }
+
+    // Central request method
+    pub(crate) async fn _request<
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned + Send + 'static,
+    >(
+        &self,
+        method: Method,
+        endpoint: &str, // Takes relative endpoint string
+        body: Option<&T>,
+        use_master_key: bool,
+        session_token_override: Option<&str>,
+    ) -> Result<R, ParseError> {
+        let options = RequestOptions {
+            use_master_key,
+            session_token_override,
+            ..Default::default()
+        };
+        self._request_with_options(method, endpoint, body, options)
+            .await
+    }
+
+    // Same as `_request`, but takes the full set of per-request settings.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "parse_request",
+            level = "debug",
+            skip_all,
+            fields(method = %method, endpoint = endpoint, status = tracing::field::Empty)
+        )
+    )]
+    pub(crate) async fn _request_with_options<
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned + Send + 'static,
+    >(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<&T>,
+        options: RequestOptions<'_>,
+    ) -> Result<R, ParseError> {
+        let RequestOptions {
+            use_master_key,
+            session_token_override,
+            installation_id,
+            timeout,
+            ..
+        } = options;
+
+        let full_url = self._api_url(endpoint)?;
+
+        log::debug!(
+            "Preparing request: Method={}, URL={}, UseMasterKey={}, HasSessionTokenOverride={}",
+            method,
+            full_url.as_str(), // Log the full_url
+            use_master_key,
+            session_token_override.is_some()
+        );
+
+        let mut request_builder = self.http_client.request(method.clone(), full_url.clone());
+        if let Some(timeout) = timeout {
+            request_builder = request_builder.timeout(timeout);
+        }
+
+        // Start with an empty map for request-specific headers.
+        // Note: App ID is part of http_client.default_headers().
+        let mut headers = HeaderMap::new();
+        self.apply_auth_headers(&mut headers, &options)?;
+
+        if let Some(installation_id) = installation_id {
+            headers.insert(
+                "X-Parse-Installation-Id",
+                HeaderValue::from_str(installation_id).map_err(ParseError::InvalidHeaderValue)?,
+            );
         }
-        // Note: App ID and User-Agent are part of http_client.default_headers().
-        // If no session token or master key is specified here, and if the client was initialized
-        // with a JS key or REST key in its default_headers, those will be used by reqwest.
 
         if method == Method::POST || method == Method::PUT || method == Method::PATCH {
             headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
@@ -1475,51 +2129,44 @@ impl Parse {
 
         // For logging, we want to see the effective headers. Reqwest doesn't easily show
         // the final merged headers before sending. So, we'll log what we're adding,
-        // acknowledging that http_client adds its defaults (AppID, UserAgent, potentially initial JS/REST/Master key).
-        log::debug!(
-            "Preparing request: Method={}, URL={}, UseMasterKey={}, SessionTokenOverride={:?}",
-            method,
-            full_url,
-            use_master_key,
-            session_token_override
-        );
+        // acknowledging that http_client adds its defaults (AppID, UserAgent).
+        self.log_request_headers(&headers);
 
         if let Some(log_body) = &body_str_for_log {
-            log::debug!("Request body: {}", log_body);
+            log::debug!("Request body: {}", self.loggable_body(log_body));
         } else {
             log::debug!("Request body: None");
         }
 
         // Send the request
-        let response = request_builder
-            .send()
-            .await
-            .map_err(ParseError::ReqwestError)?;
+        let response = self.send_request(request_builder).await?;
 
         // Process the response
+        let response_headers = response.headers().clone();
         if response.status().is_success() {
-            // For 204 No Content, deserialize to a default value if R is Option or unit type
-            if response.status() == reqwest::StatusCode::NO_CONTENT {
-                return serde_json::from_str("{}").map_err(ParseError::JsonError);
-            }
-            let body_bytes = response.bytes().await.map_err(ParseError::ReqwestError)?;
+            // A 204 No Content (or any empty body) is handled by `deserialize_success_body`.
+            let body_bytes = response.bytes().await.map_err(ParseError::from)?;
+            let body = serde_json::from_slice::<Value>(&body_bytes).ok();
+            self.record_warnings(&response_headers, body.as_ref());
             log::debug!(
                 "Request successful. Response body: {}",
                 String::from_utf8_lossy(&body_bytes)
             );
-            serde_json::from_slice(&body_bytes).map_err(ParseError::JsonError)
+            deserialize_success_body(&body_bytes, body)
         } else {
             let status = response.status();
-            let error_body_bytes = response.bytes().await.map_err(ParseError::ReqwestError)?;
+            let error_body_bytes = response.bytes().await.map_err(ParseError::from)?;
+            let error_body = serde_json::from_slice::<Value>(&error_body_bytes).ok();
+            self.record_warnings(&response_headers, error_body.as_ref());
             let error_body_str = String::from_utf8_lossy(&error_body_bytes).to_string();
             log::warn!(
                 "Request failed with status {}. Response body: {}",
                 status,
                 error_body_str
             );
-            match serde_json::from_slice::<Value>(&error_body_bytes) {
-                Ok(json_value) => Err(ParseError::from_response(status.as_u16(), json_value)),
-                Err(_) => {
+            match error_body {
+                Some(json_value) => Err(ParseError::from_response(status.as_u16(), json_value)),
+                None => {
                     let fallback_json = serde_json::json!({
                         "code": status.as_u16(),
                         "error": error_body_str
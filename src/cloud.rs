@@ -1,7 +1,10 @@
 // src/cloud.rs
 
-use crate::{client::Parse, error::ParseError};
+use crate::client::{Parse, RequestOptions};
+use crate::error::ParseError;
+use reqwest::Method;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::time::Duration;
 
 /// Internal helper struct to deserialize the `{"result": ...}` wrapper from Parse Cloud Function responses.
 ///
@@ -41,6 +44,12 @@ impl<'a> ParseCloud<'a> {
     /// `:functionName` is the name of the Cloud Code function to execute. The `params` argument
     /// is serialized to JSON and sent as the request body.
     ///
+    /// If the client has a session token (e.g. after a login), it is sent with the call so the
+    /// function sees the current user as `request.user`. The client's Master Key is left out of
+    /// such calls, since Parse Server ignores the session of a request made with the Master Key.
+    /// Use [`run_as_master`](Self::run_as_master) to run the function with the Master Key and no
+    /// user context instead. Without a session token, the client's default key is sent as usual.
+    ///
     /// The Parse Server executes the specified function and is expected to return a JSON object
     /// of the form `{"result": ...}`, where `...` is the actual value returned by the function.
     /// This method automatically unwraps the `result` field and deserializes its content into
@@ -141,8 +150,75 @@ impl<'a> ParseCloud<'a> {
         R: DeserializeOwned + Send + Sync + 'static,
     {
         let endpoint = format!("functions/{}", function_name);
-        let response_wrapper: CloudFunctionResponse<R> =
-            self.client.post(&endpoint, params).await?;
+        let options = RequestOptions {
+            as_user: true,
+            ..Default::default()
+        };
+        let response_wrapper: CloudFunctionResponse<R> = self
+            .client
+            ._request_with_options(Method::POST, &endpoint, Some(params), options)
+            .await?;
+        Ok(response_wrapper.result)
+    }
+
+    /// Runs a Parse Cloud Function with a timeout that applies to this call only.
+    ///
+    /// Behaves like [`run`](Self::run), but the request fails if the function has not responded
+    /// within `timeout`, overriding the HTTP client's default for this one call. Use it to give
+    /// long-running functions (e.g. report generation) more time, or to bound a call that must
+    /// return quickly, without changing the timeout of every other request.
+    ///
+    /// A call that times out returns `ParseError::Timeout`. The function may still complete on
+    /// the server.
+    pub async fn run_with_timeout<P, R>(
+        &self,
+        function_name: &str,
+        params: &P,
+        timeout: Duration,
+    ) -> Result<R, ParseError>
+    where
+        P: Serialize + Send + Sync,
+        R: DeserializeOwned + Send + Sync + 'static,
+    {
+        let endpoint = format!("functions/{}", function_name);
+        let options = RequestOptions {
+            timeout: Some(timeout),
+            as_user: true,
+            ..Default::default()
+        };
+        let response_wrapper: CloudFunctionResponse<R> = self
+            .client
+            ._request_with_options(Method::POST, &endpoint, Some(params), options)
+            .await?;
+        Ok(response_wrapper.result)
+    }
+
+    /// Runs a Parse Cloud Function with the Master Key and no user context.
+    ///
+    /// [`run`](Self::run) sends the client's session token when one is set, so Cloud Code sees
+    /// the logged-in user as `request.user`. This variant sends the Master Key instead of the
+    /// session token, so the function runs with `request.master` set and `request.user`
+    /// undefined, regardless of whether a user is logged in.
+    ///
+    /// Returns `ParseError::MasterKeyRequired` if the client has no Master Key configured.
+    pub async fn run_as_master<P, R>(
+        &self,
+        function_name: &str,
+        params: &P,
+    ) -> Result<R, ParseError>
+    where
+        P: Serialize + Send + Sync,
+        R: DeserializeOwned + Send + Sync + 'static,
+    {
+        let endpoint = format!("functions/{}", function_name);
+        let options = RequestOptions {
+            use_master_key: true,
+            ..Default::default()
+        };
+        let response_wrapper: CloudFunctionResponse<R> = self
+            .client
+            ._request_with_options(Method::POST, &endpoint, Some(params), options)
+            .await?;
         Ok(response_wrapper.result)
     }
 
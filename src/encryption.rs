@@ -0,0 +1,196 @@
+// src/encryption.rs
+
+use crate::client::Parse;
+use crate::error::ParseError;
+use crate::object::RetrievedParseObject;
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::Value;
+
+// Marks a string value as produced by `FieldCipher::encrypt`; the version allows the format to
+// change without misreading existing data.
+const ENCRYPTED_PREFIX: &str = "enc:v1:";
+const NONCE_LENGTH: usize = 12;
+
+/// An AES-256-GCM cipher for encrypting individual object fields on the client.
+///
+/// Register it for a field with [`Parse::set_field_cipher`]. The field's JSON value is encrypted
+/// with a random nonce and stored on the server as a string of the form
+/// `enc:v1:<base64 of nonce and ciphertext>`, so the server never sees the plaintext. Encrypted
+/// fields can therefore not be used in query constraints. The class and field name
+/// (`<class>:<field>`) are authenticated along with the value, so a ciphertext copied into
+/// another field or class fails to decrypt.
+#[derive(Clone)]
+pub struct FieldCipher {
+    cipher: Aes256Gcm,
+}
+
+impl std::fmt::Debug for FieldCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print key material.
+        f.write_str("FieldCipher { .. }")
+    }
+}
+
+impl FieldCipher {
+    /// Creates a cipher from a 256-bit key.
+    pub fn aes_256_gcm(key: &[u8; 32]) -> Self {
+        Self {
+            cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key)),
+        }
+    }
+
+    /// Encrypts a JSON value of the field `field_name` of `class_name` into an `enc:v1:` string
+    /// value.
+    pub fn encrypt(
+        &self,
+        class_name: &str,
+        field_name: &str,
+        value: &Value,
+    ) -> Result<Value, ParseError> {
+        let plaintext = serde_json::to_vec(value)?;
+        let aad = associated_data(class_name, field_name);
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: &plaintext,
+                    aad: aad.as_bytes(),
+                },
+            )
+            .map_err(|e| ParseError::SdkError(format!("Failed to encrypt field: {}", e)))?;
+
+        let mut payload = nonce.to_vec();
+        payload.extend_from_slice(&ciphertext);
+        Ok(Value::String(format!(
+            "{}{}",
+            ENCRYPTED_PREFIX,
+            BASE64.encode(payload)
+        )))
+    }
+
+    /// Decrypts a value produced by [`encrypt`](Self::encrypt) for the same class and field.
+    ///
+    /// `null` (an unset field) is returned as is. Any other value that is not encrypted, e.g. one
+    /// written before the cipher was registered, is an error, as is a value that cannot be
+    /// decrypted with this key or was encrypted for another field.
+    pub fn decrypt(
+        &self,
+        class_name: &str,
+        field_name: &str,
+        value: &Value,
+    ) -> Result<Value, ParseError> {
+        if value.is_null() {
+            return Ok(Value::Null);
+        }
+        let Some(encoded) = value
+            .as_str()
+            .and_then(|s| s.strip_prefix(ENCRYPTED_PREFIX))
+        else {
+            return Err(ParseError::SdkError(format!(
+                "Field '{}' of class '{}' has a cipher but holds an unencrypted value.",
+                field_name, class_name
+            )));
+        };
+
+        let payload = BASE64.decode(encoded).map_err(|e| {
+            ParseError::SdkError(format!("Encrypted field is not valid base64: {}", e))
+        })?;
+        if payload.len() < NONCE_LENGTH {
+            return Err(ParseError::SdkError(
+                "Encrypted field is too short to contain a nonce.".to_string(),
+            ));
+        }
+        let (nonce, ciphertext) = payload.split_at(NONCE_LENGTH);
+        let aad = associated_data(class_name, field_name);
+        let plaintext = self
+            .cipher
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: aad.as_bytes(),
+                },
+            )
+            .map_err(|e| ParseError::SdkError(format!("Failed to decrypt field: {}", e)))?;
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+}
+
+// Binds a ciphertext to the field it was written to.
+fn associated_data(class_name: &str, field_name: &str) -> String {
+    format!("{}:{}", class_name, field_name)
+}
+
+impl Parse {
+    /// Encrypts `field_name` with `cipher` on every write and decrypts it on read, in any class.
+    ///
+    /// Applies to the top-level fields of objects written with `create_object`/`update_object`
+    /// (and their variants), [`ParseBatch`](crate::ParseBatch) and
+    /// [`ParseQuery::update_all`](crate::ParseQuery::update_all), and read with
+    /// `retrieve_object`, `fetch_object_fields`, `fetch_objects_by_ids` and the `ParseQuery`
+    /// `find`, `first`, `get` and `find_with_count` methods and their variants. Operation values
+    /// such as `{"__op": "Delete"}` and `null` are sent as-is. Reading an object whose field
+    /// holds an unencrypted value fails. Registering a field again replaces its cipher.
+    pub fn set_field_cipher(&mut self, field_name: &str, cipher: FieldCipher) {
+        self.field_ciphers.insert(field_name.to_string(), cipher);
+    }
+
+    // Returns `data`, written to `class_name`, serialized with every field that has a registered
+    // cipher encrypted.
+    pub(crate) fn encrypt_fields<T: serde::Serialize>(
+        &self,
+        class_name: &str,
+        data: &T,
+    ) -> Result<Value, ParseError> {
+        let mut value = serde_json::to_value(data)?;
+        if let Value::Object(fields) = &mut value {
+            for (field_name, cipher) in &self.field_ciphers {
+                if let Some(field) = fields.get_mut(field_name) {
+                    let is_operation = field.get("__op").is_some();
+                    if !field.is_null() && !is_operation {
+                        *field = cipher.encrypt(class_name, field_name, field)?;
+                    }
+                }
+            }
+        }
+        Ok(value)
+    }
+
+    // Returns `object`, read from `class_name`, with every field that has a registered cipher
+    // decrypted.
+    pub(crate) fn decrypt_fields(
+        &self,
+        class_name: &str,
+        mut object: RetrievedParseObject,
+    ) -> Result<RetrievedParseObject, ParseError> {
+        for (field_name, cipher) in &self.field_ciphers {
+            if let Some(field) = object.fields.get_mut(field_name) {
+                *field = cipher.decrypt(class_name, field_name, field)?;
+            }
+        }
+        Ok(object)
+    }
+
+    // Returns the JSON `object`, read from `class_name`, with every field that has a registered
+    // cipher decrypted.
+    pub(crate) fn decrypt_json_fields(
+        &self,
+        class_name: &str,
+        mut object: Value,
+    ) -> Result<Value, ParseError> {
+        if let Value::Object(fields) = &mut object {
+            for (field_name, cipher) in &self.field_ciphers {
+                if let Some(field) = fields.get_mut(field_name) {
+                    *field = cipher.decrypt(class_name, field_name, field)?;
+                }
+            }
+        }
+        Ok(object)
+    }
+}
@@ -1,12 +1,34 @@
 use reqwest::header::InvalidHeaderValue;
 // src/error.rs
+use regex::Regex;
 use serde_json::Value;
+use std::sync::LazyLock;
 use thiserror::Error;
 
+/// Errors returned by the SDK.
+///
+/// Failures to reach the server are reported separately from errors the server returned:
+/// [`Network`](Self::Network) (e.g. DNS resolution failed, connection refused or reset) and
+/// [`Timeout`](Self::Timeout) mean no usable response was received, so the outcome of the
+/// operation is unknown; every other variant built from a response (such as
+/// [`OperationForbidden`](Self::OperationForbidden) or
+/// [`InvalidSessionToken`](Self::InvalidSessionToken)) means the server was reached and
+/// rejected the request. This lets applications tell "offline" apart from "not allowed".
 #[derive(Error, Debug)]
 pub enum ParseError {
+    /// An HTTP client error that is neither a network failure nor a timeout, e.g. a request that
+    /// could not be built or a redirect loop.
     #[error("HTTP request failed: {0}")]
-    ReqwestError(#[from] reqwest::Error),
+    ReqwestError(#[source] reqwest::Error),
+
+    /// The server could not be reached, or the connection failed before a complete response was
+    /// received.
+    #[error("Network error: {0}")]
+    Network(#[source] reqwest::Error),
+
+    /// No response was received within the configured timeout.
+    #[error("Request timed out: {0}")]
+    Timeout(#[source] reqwest::Error),
 
     #[error("URL parsing failed: {0}")]
     UrlParseError(#[from] url::ParseError),
@@ -14,8 +36,10 @@ pub enum ParseError {
     #[error("JSON processing error: {0}")]
     JsonError(#[from] serde_json::Error),
 
+    /// A JSON value, such as a response body, did not have the expected shape. The message
+    /// gives context; the underlying `serde_json` error is the [`source`](std::error::Error::source).
     #[error("JSON deserialization failed: {0}")]
-    JsonDeserializationFailed(String),
+    JsonDeserializationFailed(String, #[source] serde_json::Error),
 
     #[error("Parse API error (code {code}): {error}")]
     ApiError { code: i32, error: String },
@@ -39,7 +63,7 @@ pub enum ParseError {
     MasterKeyRequired(String),
 
     #[error("Serialization error: {0}")]
-    SerializationError(String),
+    SerializationError(String, #[source] serde_json::Error),
 
     #[error("Unexpected response: {0}")]
     UnexpectedResponse(String),
@@ -65,6 +89,10 @@ pub enum ParseError {
     #[error("Connection failed: {0}")]
     ConnectionFailed(String),
 
+    /// The server is rate limiting this client (HTTP 429). The request can be retried later.
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+
     #[error("Invalid query: {0}")]
     InvalidQuery(String),
 
@@ -81,10 +109,62 @@ pub enum ParseError {
     InternalServerError(String),
 
     #[error("Invalid header value: {0}")]
-    InvalidHeaderValue(InvalidHeaderValue),
+    InvalidHeaderValue(#[source] InvalidHeaderValue),
 
     #[error("Invalid URL: {0}")]
     InvalidUrl(String),
+
+    #[error("Unsupported by the server: {0}")]
+    Unsupported(String),
+
+    #[error("Query matched more than {0} objects")]
+    TooManyResults(usize),
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    /// The class name is invalid, or the class does not exist or already exists (code 103).
+    #[error("Invalid class (code 103): {message}")]
+    InvalidClass {
+        class_name: Option<String>,
+        message: String,
+    },
+
+    /// A field name is not allowed, e.g. it contains invalid characters (code 105).
+    #[error("Invalid field name (code 105): {message}")]
+    InvalidFieldName {
+        field: Option<String>,
+        message: String,
+    },
+
+    /// The request body, such as a schema definition, is malformed (code 107).
+    #[error("Invalid JSON (code 107): {message}")]
+    InvalidJson {
+        class_name: Option<String>,
+        field: Option<String>,
+        message: String,
+    },
+
+    /// A schema change was rejected, e.g. adding a field that exists or dropping a non-empty
+    /// class (code 255).
+    #[error("Invalid schema operation (code 255): {message}")]
+    InvalidSchemaOperation {
+        class_name: Option<String>,
+        field: Option<String>,
+        message: String,
+    },
+}
+
+impl From<reqwest::Error> for ParseError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            ParseError::Timeout(error)
+        } else if error.is_connect() || error.is_request() || error.is_body() {
+            ParseError::Network(error)
+        } else {
+            ParseError::ReqwestError(error)
+        }
+    }
 }
 
 impl ParseError {
@@ -104,6 +184,19 @@ impl ParseError {
             100 => ParseError::ConnectionFailed(format!("({}) {}", error_code, error_message)),
             101 => ParseError::ObjectNotFound(format!("({}) {}", error_code, error_message)), // Invalid username/password or object not found
             102 => ParseError::InvalidQuery(format!("({}) {}", error_code, error_message)),
+            103 => ParseError::InvalidClass {
+                class_name: schema_class_name(&error_message),
+                message: error_message,
+            },
+            105 => ParseError::InvalidFieldName {
+                field: schema_field_name(&error_message),
+                message: error_message,
+            },
+            107 => ParseError::InvalidJson {
+                class_name: schema_class_name(&error_message),
+                field: schema_field_name(&error_message),
+                message: error_message,
+            },
             111 => ParseError::InvalidInput(format!(
                 "Invalid field type: ({}) {}",
                 error_code, error_message
@@ -116,12 +209,22 @@ impl ParseError {
             202 => ParseError::UsernameTaken(format!("({}) {}", error_code, error_message)),
             203 => ParseError::EmailTaken(format!("({}) {}", error_code, error_message)),
             209 => ParseError::InvalidSessionToken(format!("({}) {}", error_code, error_message)),
+            255 => ParseError::InvalidSchemaOperation {
+                class_name: schema_class_name(&error_message),
+                field: schema_field_name(&error_message),
+                message: error_message,
+            },
             _ => {
                 if status_code >= 500 {
                     ParseError::InternalServerError(format!(
                         "Server error (HTTP {}): ({}) {}",
                         status_code, error_code, error_message
                     ))
+                } else if status_code == 429 {
+                    ParseError::RateLimited(format!(
+                        "Too many requests (HTTP {}): ({}) {}",
+                        status_code, error_code, error_message
+                    ))
                 } else if status_code == 401 || status_code == 403 {
                     ParseError::AuthenticationError(format!(
                         "Auth error (HTTP {}): ({}) {}",
@@ -141,4 +244,242 @@ impl ParseError {
             }
         }
     }
+
+    /// Returns `true` if the operation that produced this error may succeed if retried unchanged.
+    ///
+    /// Covers timeouts, connection failures, rate limiting (HTTP 429) and server-side (5xx)
+    /// errors, as well as the equivalent Parse error codes (1, 100, 124, 155).
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ParseError::ReqwestError(e) => {
+                e.is_timeout()
+                    || e.is_connect()
+                    || e.status().is_some_and(|s| {
+                        s.is_server_error() || s == reqwest::StatusCode::TOO_MANY_REQUESTS
+                    })
+            }
+            ParseError::Network(_) | ParseError::Timeout(_) => true,
+            ParseError::ConnectionFailed(_)
+            | ParseError::RateLimited(_)
+            | ParseError::InternalServerError(_) => true,
+            ParseError::ApiError { code, .. } => Self::is_retryable_code(*code as i64),
+            ParseError::OtherParseError { code, .. } => Self::is_retryable_code(*code as i64),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if this error means the request was not (or no longer) authenticated,
+    /// e.g. an invalid or expired session token (code 209) or an unauthorized response.
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            ParseError::InvalidSessionToken(_)
+            | ParseError::SessionTokenMissing
+            | ParseError::AuthenticationError(_) => true,
+            ParseError::ReqwestError(e) => e.status().is_some_and(|s| {
+                s == reqwest::StatusCode::UNAUTHORIZED || s == reqwest::StatusCode::FORBIDDEN
+            }),
+            ParseError::ApiError { code, .. } => *code == 209,
+            ParseError::OtherParseError { code, .. } => *code == 209,
+            _ => false,
+        }
+    }
+
+    // Parse error codes that indicate a transient condition:
+    // 1 (internal server error), 100 (connection failed), 124 (timeout), 155 (request limit exceeded).
+    fn is_retryable_code(code: i64) -> bool {
+        matches!(code, 1 | 100 | 124 | 155)
+    }
+}
+
+// Parse Server does not return the class or field a schema error refers to as separate keys,
+// so they are recovered from the messages its schema controller produces, e.g.
+// "Class GameScore does not exist." or "Field score exists, cannot update.".
+// The patterns are compiled once, on the first schema error.
+static CLASS_NAME_PATTERNS: LazyLock<[Regex; 3]> = LazyLock::new(|| {
+    [
+        r"^Invalid classname: ([^,\s]+)",
+        r"^Class ([^\s.]+) (?:already exists|does not exist|is not empty)",
+        r"^Class name mismatch between ([^\s]+) and",
+    ]
+    .map(|pattern| Regex::new(pattern).expect("valid class name pattern"))
+});
+
+static FIELD_NAME_PATTERNS: LazyLock<[Regex; 2]> = LazyLock::new(|| {
+    [
+        r"^Field ([^\s]+) (?:exists|does not exist)",
+        r"(?i)invalid field name: ([^\s]+)",
+    ]
+    .map(|pattern| Regex::new(pattern).expect("valid field name pattern"))
+});
+
+fn schema_class_name(message: &str) -> Option<String> {
+    first_capture(&*CLASS_NAME_PATTERNS, message)
+}
+
+fn schema_field_name(message: &str) -> Option<String> {
+    first_capture(&*FIELD_NAME_PATTERNS, message)
+}
+
+fn first_capture(patterns: &[Regex], message: &str) -> Option<String> {
+    patterns.iter().find_map(|pattern| {
+        pattern
+            .captures(message)
+            .map(|captures| captures[1].to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_retryable_from_response() {
+        assert!(ParseError::from_response(500, json!({"error": "boom"})).is_retryable());
+        assert!(ParseError::from_response(503, json!({})).is_retryable());
+        let rate_limited = ParseError::from_response(429, json!({"error": "slow down"}));
+        assert!(matches!(rate_limited, ParseError::RateLimited(_)));
+        assert!(rate_limited.is_retryable());
+        assert!(
+            ParseError::from_response(400, json!({"code": 100, "error": "conn"})).is_retryable()
+        );
+        assert!(
+            ParseError::from_response(400, json!({"code": 124, "error": "timeout"})).is_retryable()
+        );
+    }
+
+    #[test]
+    fn test_not_retryable() {
+        assert!(!ParseError::from_response(404, json!({})).is_retryable());
+        assert!(
+            !ParseError::from_response(400, json!({"code": 137, "error": "dup"})).is_retryable()
+        );
+        assert!(
+            !ParseError::from_response(400, json!({"code": 209, "error": "bad session"}))
+                .is_retryable()
+        );
+        assert!(!ParseError::InvalidInput("x".to_string()).is_retryable());
+        assert!(!ParseError::SessionTokenMissing.is_retryable());
+    }
+
+    #[test]
+    fn test_api_error_codes() {
+        let transient = ParseError::ApiError {
+            code: 155,
+            error: "Request limit exceeded".to_string(),
+        };
+        assert!(transient.is_retryable());
+        assert!(!transient.is_auth_error());
+
+        let invalid_session = ParseError::ApiError {
+            code: 209,
+            error: "Invalid session token".to_string(),
+        };
+        assert!(invalid_session.is_auth_error());
+        assert!(!invalid_session.is_retryable());
+    }
+
+    #[test]
+    fn test_auth_errors() {
+        assert!(
+            ParseError::from_response(400, json!({"code": 209, "error": "bad session"}))
+                .is_auth_error()
+        );
+        assert!(ParseError::from_response(401, json!({"error": "unauthorized"})).is_auth_error());
+        assert!(ParseError::from_response(403, json!({"error": "unauthorized"})).is_auth_error());
+        assert!(ParseError::SessionTokenMissing.is_auth_error());
+
+        assert!(!ParseError::from_response(500, json!({})).is_auth_error());
+        assert!(!ParseError::from_response(404, json!({})).is_auth_error());
+        assert!(!ParseError::InvalidInput("x".to_string()).is_auth_error());
+    }
+
+    #[tokio::test]
+    async fn test_reqwest_connect_error_is_retryable() {
+        // Nothing listens on port 1, so this fails with a connection error.
+        let err = reqwest::get("http://127.0.0.1:1/")
+            .await
+            .expect_err("request to a closed port should fail");
+        let parse_error = ParseError::from(err);
+        assert!(parse_error.is_retryable());
+        assert!(!parse_error.is_auth_error());
+    }
+
+    #[test]
+    fn test_schema_error_variants() {
+        let err = ParseError::from_response(
+            400,
+            json!({"code": 103, "error": "Class GameScore already exists."}),
+        );
+        assert!(matches!(
+            err,
+            ParseError::InvalidClass { class_name: Some(ref c), .. } if c == "GameScore"
+        ));
+
+        let err = ParseError::from_response(
+            400,
+            json!({
+                "code": 103,
+                "error": "Invalid classname: 1bad-name, classnames can only have alphanumeric characters and _, and must start with an alpha character "
+            }),
+        );
+        assert!(matches!(
+            err,
+            ParseError::InvalidClass { class_name: Some(ref c), .. } if c == "1bad-name"
+        ));
+
+        let err = ParseError::from_response(
+            400,
+            json!({"code": 105, "error": "invalid field name: bad-field"}),
+        );
+        assert!(matches!(
+            err,
+            ParseError::InvalidFieldName { field: Some(ref f), .. } if f == "bad-field"
+        ));
+
+        let err = ParseError::from_response(
+            400,
+            json!({"code": 107, "error": "Class name mismatch between GameScore and Other."}),
+        );
+        assert!(matches!(
+            err,
+            ParseError::InvalidJson { class_name: Some(ref c), field: None, .. } if c == "GameScore"
+        ));
+
+        let err = ParseError::from_response(
+            400,
+            json!({"code": 255, "error": "Field score exists, cannot update."}),
+        );
+        match err {
+            ParseError::InvalidSchemaOperation {
+                class_name,
+                field,
+                message,
+            } => {
+                assert_eq!(class_name, None);
+                assert_eq!(field.as_deref(), Some("score"));
+                assert_eq!(message, "Field score exists, cannot update.");
+            }
+            other => panic!("Unexpected error: {:?}", other),
+        }
+
+        let err = ParseError::from_response(
+            400,
+            json!({
+                "code": 255,
+                "error": "Class GameScore is not empty, contains 3 objects, cannot drop schema."
+            }),
+        );
+        assert!(matches!(
+            err,
+            ParseError::InvalidSchemaOperation { class_name: Some(ref c), field: None, .. } if c == "GameScore"
+        ));
+
+        // Messages in an unknown format still produce the structured variant.
+        let err = ParseError::from_response(400, json!({"code": 107, "error": "invalid JSON"}));
+        assert!(matches!(
+            err,
+            ParseError::InvalidJson { class_name: None, field: None, ref message } if message == "invalid JSON"
+        ));
+    }
 }
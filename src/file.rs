@@ -1,4 +1,9 @@
-use serde::{Deserialize, Serialize};
+use crate::{Parse, ParseError, ParseQuery};
+use reqwest::header::CONTENT_LENGTH;
+use serde::ser::Error as _;
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
+use url::Url;
 
 /// Represents a file field as stored in a ParseObject.
 /// This struct is used for serialization and deserialization
@@ -24,6 +29,11 @@ impl FileField {
 
 /// Represents a file to be uploaded to Parse Server or a file
 /// that has already been uploaded.
+///
+/// When serialized (e.g. with `ParseObject::set`), an uploaded file is written as the
+/// `{ "__type": "File", "name": ..., "url": ... }` reference Parse Server expects; the MIME type,
+/// size and data are local metadata and are not sent. Serializing a file that has not been
+/// uploaded yet fails, since Parse cannot reference it without a URL.
 #[derive(Debug, Clone)]
 pub struct ParseFile {
     /// The name of the file. This could be the original filename
@@ -34,8 +44,11 @@ pub struct ParseFile {
     pub url: Option<String>,
     /// The MIME type of the file (e.g., "image/jpeg", "text/plain").
     pub mime_type: String,
-    /// The raw byte data of the file.
+    /// The raw byte data of the file. Files returned by
+    /// [`Parse::upload_file`](crate::Parse::upload_file) do not keep their data, so this is empty
+    /// for them; use [`size`](Self::size) for the length.
     pub data: Vec<u8>,
+    size: usize,
 }
 
 impl ParseFile {
@@ -46,10 +59,42 @@ impl ParseFile {
             name,
             url: None,
             mime_type,
+            size: data.len(),
             data,
         }
     }
 
+    // Builds the metadata-only `ParseFile` returned by `upload_file`.
+    pub(crate) fn uploaded(name: String, url: String, mime_type: String, size: usize) -> Self {
+        ParseFile {
+            name,
+            url: Some(url),
+            mime_type,
+            data: Vec::new(),
+            size,
+        }
+    }
+
+    /// Returns the name of the file.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the URL of the file, or `None` if it has not been uploaded.
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Returns the MIME type of the file.
+    pub fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    /// Returns the size of the file content in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
     /// Converts this `ParseFile` into a `FileField` suitable for embedding
     /// within a `ParseObject`. Returns `None` if the file has not been uploaded
     /// (i.e., if `url` is `None`).
@@ -61,3 +106,109 @@ impl ParseFile {
         })
     }
 }
+
+impl Serialize for ParseFile {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.to_field() {
+            Some(field) => field.serialize(serializer),
+            None => Err(S::Error::custom(format!(
+                "file '{}' has not been uploaded and has no URL",
+                self.name
+            ))),
+        }
+    }
+}
+
+impl Parse {
+    /// Estimates the total storage, in bytes, used by the files referenced from a class.
+    ///
+    /// Pages through every object in `class_name` (using the Master Key if one is configured),
+    /// sends a `HEAD` request for each file stored in one of `file_fields`, and sums the reported
+    /// `Content-Length`. Files whose server does not report a length are counted as zero bytes.
+    /// A file referenced from several objects is counted once per reference. Files hosted on
+    /// another origin than the server, such as a CDN, are requested without the client's
+    /// headers and interceptor.
+    ///
+    /// This issues one request per file, so it is intended for occasional reporting
+    /// (e.g. admin dashboards), not hot paths.
+    pub async fn estimate_class_file_bytes(
+        &self,
+        class_name: &str,
+        file_fields: &[&str],
+    ) -> Result<u64, ParseError> {
+        const PAGE_SIZE: usize = 100;
+
+        if file_fields.is_empty() {
+            return Ok(0);
+        }
+
+        let mut total_bytes: u64 = 0;
+        let mut skip: usize = 0;
+        loop {
+            let mut query = ParseQuery::new(class_name);
+            query
+                .select(file_fields)
+                .order("objectId")
+                .limit(PAGE_SIZE as isize)
+                .skip(skip)
+                .set_master_key(self.master_key.is_some());
+            let page: Vec<Value> = query.find(self).await?;
+
+            for object in &page {
+                for field in file_fields {
+                    let file_url = object
+                        .get(*field)
+                        .filter(|v| v.get("__type").and_then(Value::as_str) == Some("File"))
+                        .and_then(|v| v.get("url"))
+                        .and_then(Value::as_str);
+                    if let Some(url) = file_url {
+                        total_bytes += self.file_content_length(url).await?;
+                    }
+                }
+            }
+
+            if page.len() < PAGE_SIZE {
+                break;
+            }
+            skip += PAGE_SIZE;
+        }
+
+        Ok(total_bytes)
+    }
+
+    // Sends a HEAD request for a stored file and returns its Content-Length (0 if not reported).
+    //
+    // Files served from another origin than the Parse server (e.g. a CDN or S3 bucket) are
+    // requested without the Application ID and other default headers, through a client with the
+    // same proxy and TLS settings.
+    async fn file_content_length(&self, url: &str) -> Result<u64, ParseError> {
+        let same_origin = match (Url::parse(url), Url::parse(&self.server_url)) {
+            (Ok(file_url), Ok(server_url)) => file_url.origin() == server_url.origin(),
+            _ => false,
+        };
+        let http_client = if same_origin {
+            &self.http_client
+        } else {
+            &self.foreign_http_client
+        };
+        let response = self
+            .send_request_with(http_client, http_client.head(url))
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ParseError::from_response(
+                status.as_u16(),
+                serde_json::json!({ "error": format!("HEAD {} failed", url) }),
+            ));
+        }
+        let length = response
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+        if length.is_none() {
+            log::warn!("No Content-Length reported for file {}; counting as 0", url);
+        }
+        Ok(length.unwrap_or(0))
+    }
+}
@@ -1,5 +1,6 @@
 // src/geopoint.rs
 
+use crate::ParseError;
 use serde::{Deserialize, Serialize};
 
 /// Represents a geographical point.
@@ -14,19 +15,48 @@ pub struct ParseGeoPoint {
 impl ParseGeoPoint {
     /// Creates a new `ParseGeoPoint`.
     ///
-    /// # Panics
-    /// Panics if latitude is not between -90 and 90, or longitude is not between -180 and 180.
-    pub fn new(latitude: f64, longitude: f64) -> Self {
+    /// Returns `ParseError::InvalidInput` if latitude is not between -90 and 90, or longitude is
+    /// not between -180 and 180 (including `NaN` values).
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, ParseError> {
         if !(-90.0..=90.0).contains(&latitude) {
-            panic!("Latitude must be between -90 and 90 degrees.");
+            return Err(ParseError::InvalidInput(format!(
+                "Latitude must be between -90 and 90 degrees, got {}",
+                latitude
+            )));
         }
         if !(-180.0..=180.0).contains(&longitude) {
-            panic!("Longitude must be between -180 and 180 degrees.");
+            return Err(ParseError::InvalidInput(format!(
+                "Longitude must be between -180 and 180 degrees, got {}",
+                longitude
+            )));
         }
-        ParseGeoPoint {
+        Ok(ParseGeoPoint {
             type_field: "GeoPoint".to_string(),
             latitude,
             longitude,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_validates_coordinates() {
+        let point = ParseGeoPoint::new(-90.0, 180.0).unwrap();
+        assert_eq!((point.latitude, point.longitude), (-90.0, 180.0));
+
+        for (latitude, longitude) in [(90.1, 0.0), (0.0, -180.5), (f64::NAN, 0.0)] {
+            assert!(
+                matches!(
+                    ParseGeoPoint::new(latitude, longitude),
+                    Err(ParseError::InvalidInput(_))
+                ),
+                "({}, {}) should be rejected",
+                latitude,
+                longitude
+            );
         }
     }
 }
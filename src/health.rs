@@ -0,0 +1,174 @@
+use crate::ParseError;
+use reqwest::Method;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// The response of the Parse Server `/health` endpoint.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ServerHealth {
+    /// The reported status, `"ok"` once the server is ready to accept requests.
+    pub status: String,
+}
+
+impl ServerHealth {
+    /// Returns `true` if the server reported an `ok` status.
+    pub fn is_ok(&self) -> bool {
+        self.status == "ok"
+    }
+}
+
+/// The response of the Parse Server `/serverInfo` endpoint.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ServerInfo {
+    /// The version of parse-server, e.g. `"6.2.0"`.
+    #[serde(rename = "parseServerVersion")]
+    pub parse_server_version: String,
+    /// Feature flags grouped by area (e.g. `"push"`, `"schemas"`, `"logs"`).
+    #[serde(default)]
+    pub features: HashMap<String, Value>,
+}
+
+/// A normalized view of the `/health` endpoint response.
+///
+/// Deployments differ in what they return: a bare boolean, `{"status": "ok"}`, or an object
+/// with per-component statuses (e.g. `{"status": "ok", "database": "ok", "cache": "degraded"}`).
+/// [`HealthReport::from_value`] accepts all of these shapes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthReport {
+    /// Whether the server reported itself as healthy overall.
+    pub healthy: bool,
+    /// The overall status string, if the server returned one.
+    pub status: Option<String>,
+    /// Any other fields of an object-style response, keyed by component name.
+    pub components: HashMap<String, Value>,
+    /// The unmodified response body.
+    pub raw: Value,
+}
+
+impl HealthReport {
+    /// Builds a report from a raw `/health` response body.
+    ///
+    /// A boolean body is used as-is. For an object body, the overall health is taken from a
+    /// `status` string (`"ok"` means healthy) or, failing that, from a boolean `healthy` or `ok`
+    /// field; all remaining fields are treated as components. Any other shape is unhealthy.
+    pub fn from_value(raw: Value) -> Self {
+        match &raw {
+            Value::Bool(healthy) => HealthReport {
+                healthy: *healthy,
+                status: None,
+                components: HashMap::new(),
+                raw,
+            },
+            Value::Object(map) => {
+                let status = map.get("status").and_then(Value::as_str).map(String::from);
+                let healthy = match &status {
+                    Some(status) => status == "ok",
+                    None => map
+                        .get("healthy")
+                        .or_else(|| map.get("ok"))
+                        .and_then(Value::as_bool)
+                        .unwrap_or(false),
+                };
+                let components = map
+                    .iter()
+                    .filter(|(key, _)| !matches!(key.as_str(), "status" | "healthy" | "ok"))
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect();
+                HealthReport {
+                    healthy,
+                    status,
+                    components,
+                    raw,
+                }
+            }
+            _ => HealthReport {
+                healthy: false,
+                status: None,
+                components: HashMap::new(),
+                raw,
+            },
+        }
+    }
+}
+
+impl crate::Parse {
+    /// Checks whether the Parse Server is reachable and ready.
+    ///
+    /// Calls the unauthenticated `/health` endpoint. A non-success HTTP status (e.g. 503 while
+    /// the server is still starting) is returned as an `Err`.
+    ///
+    /// # Returns
+    /// A `Result` containing the `ServerHealth` or a `ParseError`.
+    pub async fn server_health(&self) -> Result<ServerHealth, ParseError> {
+        self._request(Method::GET, "health", None::<&Value>, false, None)
+            .await
+    }
+
+    /// Checks server health and returns a normalized [`HealthReport`].
+    ///
+    /// Unlike [`server_health`](Self::server_health), this accepts any response shape the
+    /// `/health` endpoint may return, including per-component statuses. A non-success HTTP
+    /// status is still returned as an `Err`.
+    ///
+    /// # Returns
+    /// A `Result` containing the `HealthReport` or a `ParseError`.
+    pub async fn health_detailed(&self) -> Result<HealthReport, ParseError> {
+        let raw: Value = self
+            ._request(Method::GET, "health", None::<&Value>, false, None)
+            .await?;
+        Ok(HealthReport::from_value(raw))
+    }
+
+    /// Measures the round-trip time of a minimal request to the server.
+    ///
+    /// Sends `GET /health` through the regular request path (same headers, credentials and
+    /// interceptor as any other call) and returns the time until the response was received and
+    /// read. Errors, including a non-success status, are returned as `Err` rather than a duration.
+    ///
+    /// # Returns
+    /// A `Result` containing the elapsed `Duration` or a `ParseError`.
+    pub async fn ping(&self) -> Result<Duration, ParseError> {
+        let started = Instant::now();
+        let _: Value = self
+            ._request(Method::GET, "health", None::<&Value>, false, None)
+            .await?;
+        Ok(started.elapsed())
+    }
+
+    /// Retrieves the server version and enabled features.
+    ///
+    /// This operation requires the Master Key.
+    ///
+    /// # Returns
+    /// A `Result` containing the `ServerInfo` or a `ParseError`.
+    pub async fn server_info(&self) -> Result<ServerInfo, ParseError> {
+        if self.master_key.is_none() {
+            return Err(ParseError::MasterKeyRequired(
+                "Master key is required to get server info.".to_string(),
+            ));
+        }
+
+        self._request(Method::GET, "serverInfo", None::<&Value>, true, None)
+            .await
+    }
+
+    /// Checks whether the server accepts the configured Master Key.
+    ///
+    /// Performs a cheap master-key-only request (`GET /serverInfo`), which makes it suitable for
+    /// startup self-checks. A rejected key yields `Ok(false)` rather than an error; other failures
+    /// (e.g. network errors, or no Master Key configured) are still returned as `Err`.
+    ///
+    /// # Returns
+    /// A `Result` containing `true` if the Master Key is accepted, `false` if it is rejected.
+    pub async fn verify_master_key(&self) -> Result<bool, ParseError> {
+        match self.server_info().await {
+            Ok(_) => Ok(true),
+            Err(e) if e.is_auth_error() || matches!(e, ParseError::OperationForbidden(_)) => {
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -198,6 +198,7 @@ impl Parse {
                 "Object ID cannot be empty.".to_string(),
             ));
         }
+        self.validate_object_id(object_id)?;
         let endpoint = format!("installations/{}", object_id);
         // Retrieving an installation usually requires Master Key, JS Key, or REST API Key.
         // It's generally not tied to a user session for direct GET by ID.
@@ -232,6 +233,7 @@ impl Parse {
                 "Object ID cannot be empty.".to_string(),
             ));
         }
+        self.validate_object_id(object_id)?;
         let endpoint = format!("installations/{}", object_id);
         // Updating an installation usually requires Master Key, JS Key, or REST API Key.
         let use_master_key = self.master_key.is_some();
@@ -260,6 +262,7 @@ impl Parse {
                 "Object ID cannot be empty.".to_string(),
             ));
         }
+        self.validate_object_id(object_id)?;
         let endpoint = format!("installations/{}", object_id);
         // Deleting an installation usually requires Master Key.
         let use_master_key = self.master_key.is_some();
@@ -0,0 +1,89 @@
+// src/interceptor.rs
+
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode, Url};
+use std::fmt;
+use std::time::Duration;
+
+/// Re-exported so that interceptors can be implemented without depending on `async-trait`.
+pub use async_trait::async_trait;
+
+/// The parts of an outgoing request that a [`RequestInterceptor`] can inspect and modify.
+#[derive(Debug, Clone)]
+pub struct RequestParts {
+    /// The HTTP method.
+    pub method: Method,
+    /// The full request URL, including any query string.
+    pub url: Url,
+    /// The request-specific headers (credentials, `Content-Type`, ...). The client's default
+    /// headers, such as `X-Parse-Application-Id`, are added when the request is sent and do not
+    /// appear here, but a header set here replaces the default of the same name.
+    pub headers: HeaderMap,
+}
+
+/// The parts of a received response that are passed to [`RequestInterceptor::after_response`].
+#[derive(Debug, Clone)]
+pub struct ResponseParts {
+    /// The HTTP method of the request, after any changes made by `before_request`.
+    pub method: Method,
+    /// The URL of the request, after any changes made by `before_request`.
+    pub url: Url,
+    /// The response status.
+    pub status: StatusCode,
+    /// The response headers.
+    pub headers: HeaderMap,
+    /// The time from sending the request to receiving the response headers.
+    pub elapsed: Duration,
+}
+
+/// A hook invoked around every HTTP request made by a [`Parse`](crate::Parse) client, e.g. to
+/// add tracing headers, sign requests or record metrics.
+///
+/// Install one with [`ParseBuilder::interceptor`](crate::ParseBuilder::interceptor). Both
+/// methods default to doing nothing. `after_response` is only called when a response was
+/// received; requests that fail to send (connection errors, timeouts) skip it.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use parse_rs::interceptor::{async_trait, RequestInterceptor, RequestParts, ResponseParts};
+/// use parse_rs::{Parse, ParseError};
+/// use reqwest::header::HeaderValue;
+/// use std::sync::Arc;
+///
+/// struct RequestIdInterceptor;
+///
+/// #[async_trait]
+/// impl RequestInterceptor for RequestIdInterceptor {
+///     async fn before_request(&self, request: &mut RequestParts) {
+///         request
+///             .headers
+///             .insert("X-Request-Id", HeaderValue::from_static("abc123"));
+///     }
+///
+///     async fn after_response(&self, response: &ResponseParts) {
+///         println!("{} {} -> {}", response.method, response.url, response.status);
+///     }
+/// }
+///
+/// # fn main() -> Result<(), ParseError> {
+/// let client = Parse::builder("http://localhost:1338/parse", "myAppId")
+///     .interceptor(Arc::new(RequestIdInterceptor))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait]
+pub trait RequestInterceptor: Send + Sync {
+    /// Called before a request is sent. Changes to `request` apply to the request being sent.
+    async fn before_request(&self, _request: &mut RequestParts) {}
+
+    /// Called once the response status and headers have been received.
+    async fn after_response(&self, _response: &ResponseParts) {}
+}
+
+impl fmt::Debug for dyn RequestInterceptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RequestInterceptor")
+    }
+}
@@ -18,8 +18,6 @@
 //!
 //! ```rust,no_run
 //! use parse_rs::{Parse, ParseError, ParseObject, object::CreateObjectResponse};
-//! use serde_json::Value;
-//! use std::collections::HashMap;
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), ParseError> {
@@ -39,11 +37,9 @@
 //!     )?;
 //!
 //!     // Create a new ParseObject
-//!     let mut game_score_data = HashMap::new();
-//!     game_score_data.insert("score".to_string(), Value::Number(1337.into()));
-//!     game_score_data.insert("playerName".to_string(), Value::String("Sean Plott".to_string()));
-//!
-//!     let mut new_score = ParseObject::new("GameScore");
+//!     let new_score = ParseObject::new("GameScore")
+//!         .with("score", 1337)
+//!         .with("playerName", "Sean Plott");
 //!     let created_object: CreateObjectResponse = client.create_object("GameScore", &new_score).await?;
 //!
 //!     println!("Successfully created GameScore with objectId: {}", created_object.object_id);
@@ -52,17 +48,34 @@
 //! ```
 //!
 //! For more examples and detailed API documentation, please explore the individual modules.
+//!
+//! ## Number Precision
+//!
+//! Integers that fit in `i64`/`u64` are kept exact in both directions, including in
+//! `ParseObject::increment` and numeric query constraints. Enable the `arbitrary_precision`
+//! feature to also keep numbers that fit in neither (e.g. very large integers or high-precision
+//! decimals) exact in `serde_json::Value`s.
 
 pub mod acl;
+pub mod aggregate;
 pub mod analytics;
+pub mod batch;
+pub mod builder;
 pub mod client;
 pub mod cloud;
 pub mod config;
+#[cfg(feature = "encryption")]
+pub mod encryption;
 pub mod error;
 pub mod file;
 pub mod geopoint;
+pub mod health;
 pub mod installation;
+pub mod interceptor;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod object;
+pub mod push;
 pub mod query;
 pub mod relations;
 pub mod requests;
@@ -70,11 +83,18 @@ pub mod role;
 /// Module for defining Parse Server class schemas and their fields.
 pub mod schema;
 pub mod session;
+pub mod stats;
 pub mod types;
 pub mod user;
 
 /// Represents a Parse Access Control List. See [`acl::ParseACL`](acl/struct.ParseACL.html) for details.
 pub use acl::ParseACL;
+/// Builds aggregation pipelines. See [`aggregate::AggregatePipeline`](aggregate/struct.AggregatePipeline.html).
+pub use aggregate::{AggregatePage, AggregatePipeline};
+/// Sends several object operations in one request. See [`batch::ParseBatch`](batch/struct.ParseBatch.html).
+pub use batch::{BatchOp, ParseBatch};
+/// Configures and creates a [`Parse`] client. See [`builder::ParseBuilder`](builder/struct.ParseBuilder.html).
+pub use builder::{Certificate, Identity, ParseBuilder, ProxyConfig, RedirectPolicy};
 /// The main client for interacting with a Parse Server.
 /// See [`client::Parse`](client/struct.Parse.html) for detailed API methods and usage examples.
 pub use client::Parse;
@@ -82,6 +102,10 @@ pub use client::Parse;
 pub use cloud::ParseCloud;
 /// Represents server configuration retrievable via the Parse API. See [`config::ParseConfig`](config/struct.ParseConfig.html).
 pub use config::ParseConfig;
+/// Client-side field encryption, enabled with the `encryption` feature.
+/// See [`encryption::FieldCipher`](encryption/struct.FieldCipher.html).
+#[cfg(feature = "encryption")]
+pub use encryption::FieldCipher;
 /// Represents errors that can occur when interacting with Parse Server.
 /// See [`error::ParseError`](error/enum.ParseError.html) for different error variants and their meanings.
 pub use error::ParseError;
@@ -89,10 +113,12 @@ pub use error::ParseError;
 pub use file::{FileField, ParseFile};
 /// Represents a generic Parse Object, the fundamental data unit in Parse.
 /// See [`object::ParseObject`](object/struct.ParseObject.html) for details on creating, retrieving, updating, and deleting objects.
-pub use object::{ParseObject, RetrievedParseObject};
+pub use object::{NormalizedParseObject, ParseFieldValue, ParseObject, RetrievedParseObject};
+/// A push notification sent with `Parse::send_push`. See [`push::PushPayload`](push/struct.PushPayload.html).
+pub use push::PushPayload;
 /// Used to construct and execute queries against Parse Server.
 /// See [`query::ParseQuery`](query/struct.ParseQuery.html) for building complex queries with various constraints.
-pub use query::ParseQuery;
+pub use query::{Constraint, PaginatedResults, ParseQuery, Sort};
 /// Represents a Parse Role, used for managing groups of users and their permissions.
 /// See [`role::ParseRole`](role/struct.ParseRole.html) for details.
 pub use role::{NewParseRole, ParseRole};
@@ -104,6 +130,8 @@ pub use schema::{
 /// Represents a Parse Session, linking a user to their logged-in state.
 /// See [`session::ParseSession`](session/struct.ParseSession.html) for details.
 pub use session::ParseSession;
+/// Request counters of a client. See [`stats::ParseStats`](stats/struct.ParseStats.html).
+pub use stats::ParseStats;
 /// Contains common Parse-specific data types like `ParseDate` and `Pointer`.
 /// See the [`types`](types/index.html) module for more information.
 pub use types::{
@@ -112,4 +140,6 @@ pub use types::{
 };
 /// Represents a Parse User, handling authentication and user-specific data.
 /// See [`user::ParseUser`](user/struct.ParseUser.html) for details.
-pub use user::{LoginRequest, ParseUser, PasswordResetRequest, SignupRequest, SignupResponse};
+pub use user::{
+    LoginRequest, ParseUser, PasswordResetRequest, SignupRequest, SignupResponse, UserUpdate,
+};
@@ -0,0 +1,751 @@
+// src/mock.rs
+
+//! An in-memory stand-in for Parse Server, for testing code that uses the SDK without running a
+//! server. Enabled with the `mock` feature.
+//!
+//! [`MockParse`] listens on a local port and answers the REST API the SDK speaks, so the regular
+//! [`Parse`] client (and everything built on it) works against it unchanged. It supports:
+//!
+//! * object CRUD (`create_object`, `retrieve_object`, `update_object`, `delete_object`), including
+//!   the `Increment`, `Delete`, `Add`, `AddUnique` and `Remove` field operations;
+//! * queries with equality constraints (`equal_to`, or `$eq`), `$ne` and `$exists`, `limit`,
+//!   `skip`, `order` and `count`;
+//! * user signup (including anonymous `authData`), login, `me` and logout.
+//!
+//! Anything else, including other query operators, is rejected with an error. Like Parse Server,
+//! it rejects requests without the expected `X-Parse-Application-Id` as unauthorized (HTTP 403).
+//! ACLs, Class-Level Permissions and keys are not enforced.
+
+use crate::client::Parse;
+use crate::error::ParseError;
+
+use chrono::{SecondsFormat, Utc};
+use serde_json::{json, Map, Value};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+const USER_CLASS: &str = "_User";
+const DEFAULT_LIMIT: usize = 100;
+const SUPPORTED_OPERATORS: [&str; 3] = ["$eq", "$ne", "$exists"];
+
+/// An in-memory Parse Server for tests.
+///
+/// The server runs on the current Tokio runtime until the `MockParse` is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use parse_rs::mock::MockParse;
+/// use parse_rs::{ParseObject, ParseQuery, RetrievedParseObject};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), parse_rs::ParseError> {
+/// let mock = MockParse::start().await?;
+/// let client = mock.client()?;
+///
+/// let score = ParseObject::new("GameScore").with("playerName", "Sean");
+/// client.create_object("GameScore", &score).await?;
+///
+/// let mut query = ParseQuery::new("GameScore");
+/// query.equal_to("playerName", "Sean");
+/// let results: Vec<RetrievedParseObject> = query.find(&client).await?;
+/// assert_eq!(results.len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MockParse {
+    server_url: String,
+    app_id: String,
+    store: Arc<Mutex<MockStore>>,
+    accept_task: JoinHandle<()>,
+}
+
+impl MockParse {
+    /// Starts a server on a random local port, expecting the Application ID `"mockAppId"`.
+    pub async fn start() -> Result<Self, ParseError> {
+        Self::start_with_app_id("mockAppId").await
+    }
+
+    /// Starts a server on a random local port, expecting the given Application ID.
+    pub async fn start_with_app_id(app_id: &str) -> Result<Self, ParseError> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(|e| ParseError::SdkError(format!("Failed to start the mock server: {}", e)))?;
+        let addr = listener
+            .local_addr()
+            .map_err(|e| ParseError::SdkError(format!("Failed to start the mock server: {}", e)))?;
+        let store = Arc::new(Mutex::new(MockStore::default()));
+
+        let server_store = store.clone();
+        let server_app_id: Arc<str> = Arc::from(app_id);
+        let accept_task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let store = server_store.clone();
+                tokio::spawn(handle_connection(stream, store, server_app_id.clone()));
+            }
+        });
+
+        Ok(Self {
+            server_url: format!("http://{}/parse", addr),
+            app_id: app_id.to_string(),
+            store,
+            accept_task,
+        })
+    }
+
+    /// The server URL, including the `/parse` mount path.
+    pub fn server_url(&self) -> &str {
+        &self.server_url
+    }
+
+    /// Returns a client connected to this server.
+    pub fn client(&self) -> Result<Parse, ParseError> {
+        Parse::new(&self.server_url, &self.app_id, None, None, None)
+    }
+
+    /// Returns the stored objects of a class as JSON, in creation order. User objects do not
+    /// include their passwords.
+    pub fn objects(&self, class_name: &str) -> Vec<Value> {
+        let store = self.store.lock().unwrap_or_else(|e| e.into_inner());
+        store
+            .classes
+            .get(class_name)
+            .map(|objects| objects.values().map(|o| Value::Object(o.clone())).collect())
+            .unwrap_or_default()
+    }
+
+    /// Removes all objects, users and sessions.
+    pub fn clear(&self) {
+        *self.store.lock().unwrap_or_else(|e| e.into_inner()) = MockStore::default();
+    }
+}
+
+impl Drop for MockParse {
+    fn drop(&mut self) {
+        self.accept_task.abort();
+    }
+}
+
+// A response from the mock server: status and JSON body.
+type MockReply = (u16, Value);
+
+fn error_reply(status: u16, code: u16, message: impl Into<String>) -> MockReply {
+    (status, json!({ "code": code, "error": message.into() }))
+}
+
+fn now_iso() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+#[derive(Debug, Default)]
+struct MockStore {
+    // Objects by class name, then object id. Ids are generated in increasing order, so iterating
+    // a class yields its objects in creation order.
+    classes: HashMap<String, BTreeMap<String, Map<String, Value>>>,
+    // Passwords by user object id.
+    passwords: HashMap<String, String>,
+    // User object ids by session token.
+    sessions: HashMap<String, String>,
+    next_id: u64,
+}
+
+impl MockStore {
+    fn generate_id(&mut self) -> String {
+        self.next_id += 1;
+        format!("mock{:06}", self.next_id)
+    }
+
+    fn handle(
+        &mut self,
+        method: &str,
+        path: &str,
+        params: &[(String, String)],
+        session_token: Option<&str>,
+        body: Value,
+    ) -> MockReply {
+        let segments: Vec<&str> = path
+            .trim_start_matches('/')
+            .trim_start_matches("parse/")
+            .trim_end_matches('/')
+            .split('/')
+            .collect();
+
+        // Queries may be sent as a POST with `_method=GET` and the parameters in the body.
+        let mut body = body;
+        let (method, params) = match body.as_object_mut() {
+            Some(fields) if method == "POST" && fields.get("_method") == Some(&json!("GET")) => {
+                fields.remove("_method");
+                let params = fields
+                    .iter()
+                    .map(|(key, value)| match value {
+                        Value::String(s) => (key.clone(), s.clone()),
+                        other => (key.clone(), other.to_string()),
+                    })
+                    .collect();
+                ("GET", params)
+            }
+            _ => (method, params.to_vec()),
+        };
+
+        match (method, segments.as_slice()) {
+            ("POST", ["classes", class]) => self.create(class, body),
+            ("GET", ["classes", class]) => self.query(class, &params),
+            ("GET", ["classes", class, id]) => self.get(class, id),
+            ("PUT", ["classes", class, id]) => self.update(class, id, body),
+            ("DELETE", ["classes", class, id]) => self.delete(class, id),
+            ("GET", ["users", "me"]) => self.me(session_token),
+            ("POST", ["users"]) => self.signup(body),
+            ("GET", ["users"]) => self.query(USER_CLASS, &params),
+            ("GET", ["users", id]) => self.get(USER_CLASS, id),
+            ("PUT", ["users", id]) => self.update(USER_CLASS, id, body),
+            ("DELETE", ["users", id]) => self.delete(USER_CLASS, id),
+            ("POST", ["login"]) => self.login(&body),
+            ("GET", ["login"]) => {
+                let credentials: Map<String, Value> = params
+                    .into_iter()
+                    .map(|(key, value)| (key, Value::String(value)))
+                    .collect();
+                self.login(&Value::Object(credentials))
+            }
+            ("POST", ["logout"]) => self.logout(session_token),
+            _ => error_reply(
+                400,
+                1,
+                format!("{} /{} is not supported by MockParse", method, path),
+            ),
+        }
+    }
+
+    fn create(&mut self, class_name: &str, body: Value) -> MockReply {
+        let Value::Object(fields) = body else {
+            return error_reply(400, 107, "The request body must be a JSON object");
+        };
+        let object_id = self.generate_id();
+        let now = now_iso();
+        let mut object = Map::new();
+        for (key, value) in fields {
+            if let Err(reply) = apply_field(&mut object, &key, value) {
+                return reply;
+            }
+        }
+        object.insert("objectId".to_string(), json!(object_id));
+        object.insert("createdAt".to_string(), json!(now));
+        object.insert("updatedAt".to_string(), json!(now));
+        self.classes
+            .entry(class_name.to_string())
+            .or_default()
+            .insert(object_id.clone(), object);
+        (201, json!({ "objectId": object_id, "createdAt": now }))
+    }
+
+    fn get(&self, class_name: &str, object_id: &str) -> MockReply {
+        match self
+            .classes
+            .get(class_name)
+            .and_then(|objects| objects.get(object_id))
+        {
+            Some(object) => (200, Value::Object(object.clone())),
+            None => error_reply(404, 101, "Object not found."),
+        }
+    }
+
+    fn update(&mut self, class_name: &str, object_id: &str, body: Value) -> MockReply {
+        let Value::Object(fields) = body else {
+            return error_reply(400, 107, "The request body must be a JSON object");
+        };
+        let Some(object) = self
+            .classes
+            .get_mut(class_name)
+            .and_then(|objects| objects.get_mut(object_id))
+        else {
+            return error_reply(404, 101, "Object not found.");
+        };
+        let mut updated = object.clone();
+        for (key, value) in fields {
+            if key == "password" && class_name == USER_CLASS {
+                if let Some(password) = value.as_str() {
+                    self.passwords
+                        .insert(object_id.to_string(), password.to_string());
+                }
+                continue;
+            }
+            if key == "authData" && class_name == USER_CLASS {
+                // Providers are merged into the existing authData; `null` unlinks one.
+                let mut auth_data = match updated.remove("authData") {
+                    Some(Value::Object(auth_data)) => auth_data,
+                    _ => Map::new(),
+                };
+                for (provider, data) in value.as_object().cloned().unwrap_or_default() {
+                    if data.is_null() {
+                        auth_data.remove(&provider);
+                    } else {
+                        auth_data.insert(provider, data);
+                    }
+                }
+                if !auth_data.is_empty() {
+                    updated.insert("authData".to_string(), Value::Object(auth_data));
+                }
+                continue;
+            }
+            if let Err(reply) = apply_field(&mut updated, &key, value) {
+                return reply;
+            }
+        }
+        let now = now_iso();
+        updated.insert("updatedAt".to_string(), json!(now));
+        *object = updated;
+        (200, json!({ "updatedAt": now }))
+    }
+
+    fn delete(&mut self, class_name: &str, object_id: &str) -> MockReply {
+        let removed = self
+            .classes
+            .get_mut(class_name)
+            .and_then(|objects| objects.remove(object_id));
+        if removed.is_none() {
+            return error_reply(404, 101, "Object not found.");
+        }
+        if class_name == USER_CLASS {
+            self.passwords.remove(object_id);
+            self.sessions.retain(|_, user_id| user_id != object_id);
+        }
+        (200, json!({}))
+    }
+
+    fn query(&self, class_name: &str, params: &[(String, String)]) -> MockReply {
+        let param = |name: &str| {
+            params
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        let constraints = match param("where").map(serde_json::from_str::<Value>) {
+            None => Map::new(),
+            Some(Ok(Value::Object(constraints))) => constraints,
+            Some(_) => return error_reply(400, 102, "Invalid 'where' parameter"),
+        };
+        let mut conditions = Vec::new();
+        for (field, constraint) in constraints {
+            match constraint {
+                Value::Object(operators) if operators.keys().any(|k| k.starts_with('$')) => {
+                    for (operator, expected) in operators {
+                        if !SUPPORTED_OPERATORS.contains(&operator.as_str()) {
+                            return error_reply(
+                                400,
+                                102,
+                                format!(
+                                    "MockParse does not support the {} operator (on '{}')",
+                                    operator, field
+                                ),
+                            );
+                        }
+                        conditions.push((field.clone(), operator, expected));
+                    }
+                }
+                other => conditions.push((field, "$eq".to_string(), other)),
+            }
+        }
+
+        let mut matches: Vec<&Map<String, Value>> = self
+            .classes
+            .get(class_name)
+            .map(|objects| {
+                objects
+                    .values()
+                    .filter(|object| {
+                        conditions.iter().all(|(field, operator, expected)| {
+                            operator_matches(object.get(field), operator, expected)
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if let Some(order) = param("order") {
+            let keys: Vec<(&str, bool)> = order
+                .split(',')
+                .filter(|key| !key.is_empty())
+                .map(|key| match key.strip_prefix('-') {
+                    Some(field) => (field, true),
+                    None => (key, false),
+                })
+                .collect();
+            matches.sort_by(|a, b| {
+                keys.iter()
+                    .map(|(field, descending)| {
+                        let ordering = compare_values(a.get(*field), b.get(*field));
+                        if *descending {
+                            ordering.reverse()
+                        } else {
+                            ordering
+                        }
+                    })
+                    .find(|ordering| *ordering != Ordering::Equal)
+                    .unwrap_or(Ordering::Equal)
+            });
+        }
+
+        let count = matches.len();
+        let skip = param("skip").and_then(|s| s.parse().ok()).unwrap_or(0);
+        let limit = param("limit")
+            .and_then(|l| l.parse::<i64>().ok())
+            .map_or(
+                DEFAULT_LIMIT,
+                |l| if l < 0 { usize::MAX } else { l as usize },
+            );
+        let results: Vec<Value> = matches
+            .into_iter()
+            .skip(skip)
+            .take(limit)
+            .map(|object| Value::Object(object.clone()))
+            .collect();
+
+        let mut response = json!({ "results": results });
+        if param("count") == Some("1") {
+            response["count"] = json!(count);
+        }
+        (200, response)
+    }
+
+    fn signup(&mut self, body: Value) -> MockReply {
+        let Value::Object(mut fields) = body else {
+            return error_reply(400, 107, "The request body must be a JSON object");
+        };
+        if let Some(anonymous_id) = fields
+            .get("authData")
+            .and_then(|auth_data| auth_data["anonymous"]["id"].as_str())
+            .map(str::to_string)
+        {
+            return self.login_anonymous(&anonymous_id, fields);
+        }
+        let username = match fields.get("username").and_then(Value::as_str) {
+            Some(username) if !username.is_empty() => username.to_string(),
+            _ => return error_reply(400, 200, "bad or missing username"),
+        };
+        let password = match fields.remove("password") {
+            Some(Value::String(password)) if !password.is_empty() => password,
+            _ => return error_reply(400, 201, "password is required."),
+        };
+        if self.find_user(&username).is_some() {
+            return error_reply(400, 202, "Account already exists for this username.");
+        }
+
+        let (status, mut reply) = self.create(USER_CLASS, Value::Object(fields));
+        if status != 201 {
+            return (status, reply);
+        }
+        let object_id = reply["objectId"].as_str().unwrap_or_default().to_string();
+        self.passwords.insert(object_id.clone(), password);
+        reply["sessionToken"] = json!(self.create_session(&object_id));
+        (201, reply)
+    }
+
+    // Logs in the user linked to `anonymous_id`, creating one with a random username first if
+    // there is none, like Parse Server does for anonymous `authData`.
+    fn login_anonymous(&mut self, anonymous_id: &str, mut fields: Map<String, Value>) -> MockReply {
+        let existing = self.classes.get(USER_CLASS).and_then(|users| {
+            users
+                .values()
+                .find(|user| {
+                    user.get("authData")
+                        .and_then(|auth_data| auth_data["anonymous"]["id"].as_str())
+                        == Some(anonymous_id)
+                })
+                .cloned()
+        });
+        if let Some(mut user) = existing {
+            let object_id = user["objectId"].as_str().unwrap_or_default().to_string();
+            user.insert(
+                "sessionToken".to_string(),
+                json!(self.create_session(&object_id)),
+            );
+            return (200, Value::Object(user));
+        }
+
+        let username = format!("anon_{}", self.generate_id());
+        fields.insert("username".to_string(), json!(username));
+        let (status, mut reply) = self.create(USER_CLASS, Value::Object(fields));
+        if status != 201 {
+            return (status, reply);
+        }
+        let object_id = reply["objectId"].as_str().unwrap_or_default().to_string();
+        reply["username"] = json!(username);
+        reply["sessionToken"] = json!(self.create_session(&object_id));
+        (201, reply)
+    }
+
+    fn login(&mut self, credentials: &Value) -> MockReply {
+        let username = credentials["username"].as_str().unwrap_or_default();
+        let password = credentials["password"].as_str().unwrap_or_default();
+        let user = self
+            .find_user(username)
+            .filter(|user| {
+                let id = user["objectId"].as_str().unwrap_or_default();
+                self.passwords.get(id).map(String::as_str) == Some(password)
+            })
+            .cloned();
+        let Some(mut user) = user else {
+            return error_reply(404, 101, "Invalid username/password.");
+        };
+        let object_id = user["objectId"].as_str().unwrap_or_default().to_string();
+        user.insert(
+            "sessionToken".to_string(),
+            json!(self.create_session(&object_id)),
+        );
+        (200, Value::Object(user))
+    }
+
+    fn me(&self, session_token: Option<&str>) -> MockReply {
+        let user = session_token
+            .and_then(|token| self.sessions.get(token).map(|id| (token, id)))
+            .and_then(|(token, id)| {
+                let mut user = self.classes.get(USER_CLASS)?.get(id)?.clone();
+                user.insert("sessionToken".to_string(), json!(token));
+                Some(user)
+            });
+        match user {
+            Some(user) => (200, Value::Object(user)),
+            None => error_reply(400, 209, "Invalid session token"),
+        }
+    }
+
+    fn logout(&mut self, session_token: Option<&str>) -> MockReply {
+        match session_token.and_then(|token| self.sessions.remove(token)) {
+            Some(_) => (200, json!({})),
+            None => error_reply(400, 209, "Invalid session token"),
+        }
+    }
+
+    fn find_user(&self, username: &str) -> Option<&Map<String, Value>> {
+        self.classes
+            .get(USER_CLASS)?
+            .values()
+            .find(|user| user.get("username").and_then(Value::as_str) == Some(username))
+    }
+
+    fn create_session(&mut self, user_id: &str) -> String {
+        let token = format!("r:{}", self.generate_id());
+        self.sessions.insert(token.clone(), user_id.to_string());
+        token
+    }
+}
+
+// Sets a field on a stored object, applying `__op` field operations.
+fn apply_field(object: &mut Map<String, Value>, key: &str, value: Value) -> Result<(), MockReply> {
+    let op = value
+        .get("__op")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    let Some(op) = op else {
+        object.insert(key.to_string(), value);
+        return Ok(());
+    };
+
+    let current = object.get(key).cloned();
+    let items = || value["objects"].as_array().cloned().unwrap_or_default();
+    let array = || match &current {
+        Some(Value::Array(items)) => items.clone(),
+        _ => Vec::new(),
+    };
+    match op.as_str() {
+        "Delete" => {
+            object.remove(key);
+        }
+        "Increment" => {
+            let amount = &value["amount"];
+            let incremented = match (current.as_ref().unwrap_or(&json!(0)), amount) {
+                (Value::Number(a), Value::Number(b))
+                    if a.is_i64()
+                        && b.is_i64()
+                        && a.as_i64()
+                            .unwrap_or_default()
+                            .checked_add(b.as_i64().unwrap_or_default())
+                            .is_some() =>
+                {
+                    json!(a.as_i64().unwrap_or_default() + b.as_i64().unwrap_or_default())
+                }
+                (Value::Number(a), Value::Number(b)) => {
+                    json!(a.as_f64().unwrap_or_default() + b.as_f64().unwrap_or_default())
+                }
+                _ => {
+                    return Err(error_reply(
+                        400,
+                        111,
+                        format!("Cannot increment a non-number field '{}'", key),
+                    ))
+                }
+            };
+            object.insert(key.to_string(), incremented);
+        }
+        "Add" => {
+            let mut array = array();
+            array.extend(items());
+            object.insert(key.to_string(), Value::Array(array));
+        }
+        "AddUnique" => {
+            let mut array = array();
+            for item in items() {
+                if !array.contains(&item) {
+                    array.push(item);
+                }
+            }
+            object.insert(key.to_string(), Value::Array(array));
+        }
+        "Remove" => {
+            let removed = items();
+            let mut array = array();
+            array.retain(|item| !removed.contains(item));
+            object.insert(key.to_string(), Value::Array(array));
+        }
+        other => {
+            return Err(error_reply(
+                400,
+                1,
+                format!("The '{}' operation is not supported by MockParse", other),
+            ))
+        }
+    }
+    Ok(())
+}
+
+fn operator_matches(actual: Option<&Value>, operator: &str, expected: &Value) -> bool {
+    match operator {
+        "$eq" => field_equals(actual, expected),
+        "$ne" => !field_equals(actual, expected),
+        "$exists" => actual.is_some() == expected.as_bool().unwrap_or(true),
+        _ => false,
+    }
+}
+
+// Parse's equality semantics: an array field matches if it contains the expected value.
+fn field_equals(actual: Option<&Value>, expected: &Value) -> bool {
+    match (actual, expected) {
+        (None, Value::Null) => true,
+        (Some(Value::Array(items)), expected) if !expected.is_array() => items.contains(expected),
+        (Some(actual), expected) => actual == expected,
+        (None, _) => false,
+    }
+}
+
+// Orders missing values first, then numbers and strings by value; other values compare equal.
+// Integers are compared exactly, so values beyond 2^53 keep their order.
+fn compare_values(a: Option<&Value>, b: Option<&Value>) -> Ordering {
+    match (a, b) {
+        (Some(Value::Number(a)), Some(Value::Number(b))) if a.is_i64() && b.is_i64() => {
+            a.as_i64().cmp(&b.as_i64())
+        }
+        (Some(Value::Number(a)), Some(Value::Number(b))) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Some(Value::String(a)), Some(Value::String(b))) => a.cmp(b),
+        (Some(Value::Bool(a)), Some(Value::Bool(b))) => a.cmp(b),
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        _ => Ordering::Equal,
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, store: Arc<Mutex<MockStore>>, app_id: Arc<str>) {
+    while let Some(request) = read_request(&mut stream).await {
+        let body = if request.body.iter().all(u8::is_ascii_whitespace) {
+            Value::Null
+        } else {
+            serde_json::from_slice(&request.body).unwrap_or(Value::Null)
+        };
+        let (path, query) = request
+            .target
+            .split_once('?')
+            .unwrap_or((request.target.as_str(), ""));
+        let params: Vec<(String, String)> = url::form_urlencoded::parse(query.as_bytes())
+            .into_owned()
+            .collect();
+        let (status, reply) = if request.app_id.as_deref() != Some(&*app_id) {
+            (403, json!({ "error": "unauthorized" }))
+        } else {
+            store.lock().unwrap_or_else(|e| e.into_inner()).handle(
+                &request.method,
+                path,
+                &params,
+                request.session_token.as_deref(),
+                body,
+            )
+        };
+
+        let reply = reply.to_string();
+        let response = format!(
+            "HTTP/1.1 {} MockParse\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            status,
+            reply.len(),
+            reply
+        );
+        if stream.write_all(response.as_bytes()).await.is_err() {
+            return;
+        }
+    }
+}
+
+struct MockRequest {
+    method: String,
+    target: String,
+    app_id: Option<String>,
+    session_token: Option<String>,
+    body: Vec<u8>,
+}
+
+async fn read_request(stream: &mut TcpStream) -> Option<MockRequest> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        if let Some(pos) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos;
+        }
+        let read = stream.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            return None;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split_whitespace();
+    let method = request_line.next()?.to_string();
+    let target = request_line.next()?.to_string();
+    let mut content_length = 0;
+    let mut app_id = None;
+    let mut session_token = None;
+    for (name, value) in lines.filter_map(|line| line.split_once(':')) {
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.parse().unwrap_or(0);
+        } else if name.eq_ignore_ascii_case("x-parse-application-id") {
+            app_id = Some(value.to_string());
+        } else if name.eq_ignore_ascii_case("x-parse-session-token") {
+            session_token = Some(value.to_string());
+        }
+    }
+
+    let mut body = buffer[header_end + 4..].to_vec();
+    while body.len() < content_length {
+        let read = stream.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..read]);
+    }
+
+    Some(MockRequest {
+        method,
+        target,
+        app_id,
+        session_token,
+        body,
+    })
+}
@@ -2,12 +2,15 @@
 
 use crate::acl::ParseACL;
 use crate::client::Parse;
+use crate::geopoint::ParseGeoPoint;
 use crate::types::date::ParseDate;
+use crate::types::{ParseRelation, Pointer};
 use crate::ParseError;
+use reqwest::Method;
 use serde::de::{DeserializeOwned, Deserializer};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 // Helper function to deserialize a string into Option<ParseDate>
 pub fn deserialize_string_to_option_parse_date<'de, D>(
@@ -35,12 +38,14 @@ pub struct ParseObject {
     pub object_id: Option<String>,
     #[serde(
         deserialize_with = "deserialize_string_to_option_parse_date",
+        default,
         skip_serializing_if = "Option::is_none",
         rename = "createdAt"
     )]
     pub created_at: Option<ParseDate>,
     #[serde(
         deserialize_with = "deserialize_string_to_option_parse_date",
+        default,
         skip_serializing_if = "Option::is_none",
         rename = "updatedAt"
     )]
@@ -52,6 +57,10 @@ pub struct ParseObject {
     #[serde(skip_serializing, default)]
     // Should not be serialized, only used for context. Default if missing.
     pub class_name: String,
+    // Fields (and "ACL") changed through the setters since the object was created, deserialized
+    // or last saved with `Parse::save_object`.
+    #[serde(skip)]
+    dirty: BTreeSet<String>,
 }
 
 impl ParseObject {
@@ -63,12 +72,81 @@ impl ParseObject {
             object_id: None,
             created_at: None,
             updated_at: None,
+            dirty: BTreeSet::new(),
         }
     }
 
+    /// Creates an object of `class_name` from a JSON object of its fields.
+    ///
+    /// `objectId`, `createdAt`, `updatedAt` and `ACL` keys populate the corresponding members;
+    /// every other key becomes a field. Returns `ParseError::InvalidInput` if `data` is not a
+    /// JSON object, and `ParseError::JsonError` if one of the reserved keys has the wrong shape.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use parse_rs::ParseObject;
+    /// use serde_json::json;
+    ///
+    /// let score = ParseObject::from_json(
+    ///     "GameScore",
+    ///     json!({ "score": 1337, "playerName": "Sean Plott" }),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(score.get::<i64>("score"), Some(1337));
+    /// ```
+    pub fn from_json(class_name: &str, data: Value) -> Result<Self, ParseError> {
+        if !data.is_object() {
+            return Err(ParseError::InvalidInput(format!(
+                "Data for a '{}' object must be a JSON object, got: {}",
+                class_name, data
+            )));
+        }
+        let mut object: ParseObject = serde_json::from_value(data)?;
+        object.class_name = class_name.to_string();
+        Ok(object)
+    }
+
+    /// Sets a field and returns the object, for building objects in a single expression.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use parse_rs::ParseObject;
+    ///
+    /// let score = ParseObject::new("GameScore")
+    ///     .with("score", 1337)
+    ///     .with("playerName", "Sean Plott");
+    /// assert_eq!(score.get::<String>("playerName").as_deref(), Some("Sean Plott"));
+    /// ```
+    pub fn with<T: Serialize>(mut self, field_name: &str, value: T) -> Self {
+        self.set(field_name, value);
+        self
+    }
+
     pub fn set<T: Serialize>(&mut self, field_name: &str, value: T) {
-        self.fields
-            .insert(field_name.to_string(), serde_json::to_value(value).unwrap());
+        self.insert_field(field_name, serde_json::to_value(value).unwrap());
+    }
+
+    // Stores a field value or operation and marks the field dirty.
+    fn insert_field(&mut self, field_name: &str, value: Value) {
+        self.fields.insert(field_name.to_string(), value);
+        self.dirty.insert(field_name.to_string());
+    }
+
+    /// Returns `true` if a field or the ACL was changed since the object was created,
+    /// deserialized or last saved with [`Parse::save_object`].
+    ///
+    /// Only changes made through the object's methods (`set`, `increment`, `unset`, `set_acl`,
+    /// ...) are tracked, not direct edits of the `fields` map.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Returns the names of the changed fields, in alphabetical order. A changed ACL is
+    /// reported as `"ACL"`.
+    pub fn dirty_keys(&self) -> Vec<&str> {
+        self.dirty.iter().map(String::as_str).collect()
     }
 
     pub fn get<T: DeserializeOwned>(&self, field_name: &str) -> Option<T> {
@@ -77,16 +155,63 @@ impl ParseObject {
             .and_then(|v| serde_json::from_value(v.clone()).ok())
     }
 
+    /// Sets a field to a geographical point.
+    pub fn set_geo_point(&mut self, field_name: &str, point: &ParseGeoPoint) {
+        self.set(field_name, point);
+    }
+
+    /// Deserializes an array field into a `Vec<T>`.
+    ///
+    /// Returns `ParseError::InvalidInput` if the field is missing or not an array, and
+    /// `ParseError::JsonDeserializationFailed` naming the offending index if any element
+    /// does not match `T`.
+    pub fn get_array<T: DeserializeOwned>(&self, field_name: &str) -> Result<Vec<T>, ParseError> {
+        let items = match self.fields.get(field_name) {
+            Some(Value::Array(items)) => items,
+            Some(other) => {
+                return Err(ParseError::InvalidInput(format!(
+                    "Field '{}' is not an array: {}",
+                    field_name, other
+                )))
+            }
+            None => {
+                return Err(ParseError::InvalidInput(format!(
+                    "Field '{}' is not set",
+                    field_name
+                )))
+            }
+        };
+        items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                T::deserialize(item).map_err(|e| {
+                    ParseError::JsonDeserializationFailed(
+                        format!(
+                            "Element {} of array field '{}' has an unexpected shape: {}",
+                            index, field_name, e
+                        ),
+                        e,
+                    )
+                })
+            })
+            .collect()
+    }
+
     pub fn set_acl(&mut self, acl: ParseACL) {
         self.acl = Some(acl);
+        self.dirty.insert("ACL".to_string());
     }
 
+    /// Sets an `Increment` operation on a field. The amount is sent as a JSON integer, so it is
+    /// never rounded on the client, even beyond 2^53. Parse Server reads request bodies as
+    /// JavaScript numbers, though, so a real server may still round such values.
     pub fn increment(&mut self, field_name: &str, amount: i64) {
         let op = json!({
             "__op": "Increment",
             "amount": amount
         });
-        self.fields.insert(field_name.to_string(), op);
+        self.insert_field(field_name, op);
     }
 
     pub fn decrement(&mut self, field_name: &str, amount: i64) {
@@ -98,7 +223,7 @@ impl ParseObject {
             "__op": "Add",
             "objects": items
         });
-        self.fields.insert(field_name.to_string(), op);
+        self.insert_field(field_name, op);
     }
 
     pub fn add_unique_to_array<T: Serialize>(&mut self, field_name: &str, items: &[T]) {
@@ -106,7 +231,7 @@ impl ParseObject {
             "__op": "AddUnique",
             "objects": items
         });
-        self.fields.insert(field_name.to_string(), op);
+        self.insert_field(field_name, op);
     }
 
     pub fn remove_from_array<T: Serialize>(&mut self, field_name: &str, items: &[T]) {
@@ -114,7 +239,18 @@ impl ParseObject {
             "__op": "Remove",
             "objects": items
         });
-        self.fields.insert(field_name.to_string(), op);
+        self.insert_field(field_name, op);
+    }
+
+    /// Stages removal of a field from the stored object (`{"__op": "Delete"}`).
+    ///
+    /// Unlike `set(field_name, Value::Null)`, which keeps the key with a `null` value,
+    /// the field is removed entirely once the object is saved.
+    pub fn unset(&mut self, field_name: &str) {
+        let op = json!({
+            "__op": "Delete"
+        });
+        self.insert_field(field_name, op);
     }
 }
 
@@ -132,6 +268,182 @@ pub struct RetrievedParseObject {
     pub acl: Option<ParseACL>,
 }
 
+impl TryFrom<Value> for RetrievedParseObject {
+    type Error = ParseError;
+
+    /// Converts a JSON object (e.g. embedded in a Cloud function result) into a `RetrievedParseObject`.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        serde_json::from_value(value).map_err(|e| {
+            ParseError::JsonDeserializationFailed(format!("Invalid RetrievedParseObject: {}", e), e)
+        })
+    }
+}
+
+impl RetrievedParseObject {
+    /// Returns the object's access control list, if the server returned one.
+    ///
+    /// Objects saved without an ACL have none; they are readable and writable by everyone
+    /// subject to the class-level permissions.
+    pub fn acl(&self) -> Option<ParseACL> {
+        self.acl.clone()
+    }
+
+    /// Returns the value of a GeoPoint field.
+    ///
+    /// Returns `None` if the field is missing or does not hold a GeoPoint.
+    pub fn get_geo_point(&self, field_name: &str) -> Option<ParseGeoPoint> {
+        let value = self.fields.get(field_name)?;
+        if value.get("__type").and_then(Value::as_str) != Some("GeoPoint") {
+            return None;
+        }
+        serde_json::from_value(value.clone()).ok()
+    }
+
+    /// Reads a value nested inside object and array fields, following a dotted path.
+    ///
+    /// Each segment after the first is an object key or, for arrays, a zero-based index, so
+    /// `"stats.level"` reads `fields["stats"]["level"]` and `"items.0.name"` reads the name of the
+    /// first element of the `items` array. Returns `None` if any part of the path is missing or
+    /// the value cannot be deserialized as `T`.
+    pub fn get_nested<T: DeserializeOwned>(&self, dotted_key: &str) -> Option<T> {
+        let mut segments = dotted_key.split('.');
+        let mut value = self.fields.get(segments.next()?)?;
+        for segment in segments {
+            value = match value {
+                Value::Object(map) => map.get(segment)?,
+                Value::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+        serde_json::from_value(value.clone()).ok()
+    }
+
+    /// Replaces the object's ACL locally.
+    ///
+    /// This does not save anything; pass the new ACL to
+    /// [`Parse::update_object_acl`](crate::Parse::update_object_acl) to store it.
+    pub fn set_acl(&mut self, acl: ParseACL) {
+        self.acl = Some(acl);
+    }
+
+    /// Converts the raw JSON fields into typed values based on their `__type`.
+    ///
+    /// Pointers (and included objects, which carry the same `className`/`objectId`), relations,
+    /// dates and geopoints become [`ParseFieldValue`] variants; every other field is kept as
+    /// plain JSON. Fields with a `__type` that does not match the expected shape are also kept
+    /// as plain JSON rather than failing the whole conversion.
+    pub fn normalize(self) -> NormalizedParseObject {
+        let fields = self
+            .fields
+            .into_iter()
+            .map(|(key, value)| (key, ParseFieldValue::from_value(value)))
+            .collect();
+        NormalizedParseObject {
+            object_id: self.object_id,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            fields,
+            acl: self.acl,
+        }
+    }
+}
+
+/// A field value of a [`NormalizedParseObject`], typed according to its Parse `__type`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseFieldValue {
+    Pointer(Pointer),
+    Relation(ParseRelation),
+    Date(ParseDate),
+    GeoPoint(ParseGeoPoint),
+    /// Any other value, including plain strings, numbers, arrays and objects.
+    Value(Value),
+}
+
+impl ParseFieldValue {
+    fn from_value(value: Value) -> Self {
+        let type_name = value
+            .get("__type")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let typed = match type_name.as_deref() {
+            // Included objects are returned in place of the pointer; keep the reference part.
+            Some("Pointer") | Some("Object") => {
+                match (
+                    value.get("className").and_then(Value::as_str),
+                    value.get("objectId").and_then(Value::as_str),
+                ) {
+                    (Some(class_name), Some(object_id)) => Some(ParseFieldValue::Pointer(
+                        Pointer::new(class_name, object_id),
+                    )),
+                    _ => None,
+                }
+            }
+            Some("Relation") => serde_json::from_value(value.clone())
+                .ok()
+                .map(ParseFieldValue::Relation),
+            Some("Date") => serde_json::from_value(value.clone())
+                .ok()
+                .map(ParseFieldValue::Date),
+            Some("GeoPoint") => serde_json::from_value(value.clone())
+                .ok()
+                .map(ParseFieldValue::GeoPoint),
+            _ => None,
+        };
+        typed.unwrap_or(ParseFieldValue::Value(value))
+    }
+}
+
+/// A [`RetrievedParseObject`] whose special fields have been converted to typed values.
+///
+/// Created with [`RetrievedParseObject::normalize`]. The typed accessors return `None` when the
+/// field is missing or holds a different kind of value.
+#[derive(Debug, Clone)]
+pub struct NormalizedParseObject {
+    pub object_id: String,
+    pub created_at: ParseDate,
+    pub updated_at: ParseDate,
+    pub fields: HashMap<String, ParseFieldValue>,
+    pub acl: Option<ParseACL>,
+}
+
+impl NormalizedParseObject {
+    pub fn get_pointer(&self, field_name: &str) -> Option<&Pointer> {
+        match self.fields.get(field_name) {
+            Some(ParseFieldValue::Pointer(pointer)) => Some(pointer),
+            _ => None,
+        }
+    }
+
+    pub fn get_relation(&self, field_name: &str) -> Option<&ParseRelation> {
+        match self.fields.get(field_name) {
+            Some(ParseFieldValue::Relation(relation)) => Some(relation),
+            _ => None,
+        }
+    }
+
+    pub fn get_date(&self, field_name: &str) -> Option<&ParseDate> {
+        match self.fields.get(field_name) {
+            Some(ParseFieldValue::Date(date)) => Some(date),
+            _ => None,
+        }
+    }
+
+    pub fn get_geo_point(&self, field_name: &str) -> Option<&ParseGeoPoint> {
+        match self.fields.get(field_name) {
+            Some(ParseFieldValue::GeoPoint(geo_point)) => Some(geo_point),
+            _ => None,
+        }
+    }
+
+    /// Returns a field that has no special Parse type as plain JSON.
+    pub fn get_value(&self, field_name: &str) -> Option<&Value> {
+        match self.fields.get(field_name) {
+            Some(ParseFieldValue::Value(value)) => Some(value),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateObjectResponse {
@@ -147,38 +459,122 @@ pub struct UpdateObjectResponse {
     pub updated_at: ParseDate,
 }
 
+// Rejects class names Parse Server would refuse, before anything is sent.
+pub(crate) fn validate_class_name(class_name: &str) -> Result<(), ParseError> {
+    if class_name.is_empty() {
+        return Err(ParseError::InvalidInput(
+            "Class name cannot be empty".to_string(),
+        ));
+    }
+    if !class_name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+    {
+        return Err(ParseError::InvalidInput(
+            "Invalid class name: must start with a letter or underscore.".to_string(),
+        ));
+    }
+    if !class_name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Err(ParseError::InvalidInput(
+            "Invalid class name: can only contain letters, numbers, or underscores.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 impl Parse {
     pub async fn create_object<T: Serialize + Send + Sync>(
         &self,
         class_name: &str,
         data: &T,
     ) -> Result<CreateObjectResponse, ParseError> {
-        if class_name.is_empty() {
-            return Err(ParseError::InvalidInput(
-                "Class name cannot be empty".to_string(),
-            ));
-        }
-        if !class_name
-            .chars()
-            .next()
-            .is_some_and(|c| c.is_alphabetic() || c == '_')
-        {
-            return Err(ParseError::InvalidInput(
-                "Invalid class name: must start with a letter or underscore.".to_string(),
-            ));
-        }
-        if !class_name.chars().all(|c| c.is_alphanumeric() || c == '_') {
-            return Err(ParseError::InvalidInput(
-                "Invalid class name: can only contain letters, numbers, or underscores."
-                    .to_string(),
-            ));
-        }
+        self._create_object(class_name, data, false).await
+    }
+
+    /// Creates an object using the Master Key for this single request.
+    ///
+    /// The client's session token (if any) is left untouched; only this call is elevated.
+    /// Returns `ParseError::MasterKeyRequired` if no Master Key is configured on the client.
+    pub async fn create_object_with_master_key<T: Serialize + Send + Sync>(
+        &self,
+        class_name: &str,
+        data: &T,
+    ) -> Result<CreateObjectResponse, ParseError> {
+        self._create_object(class_name, data, true).await
+    }
+
+    async fn _create_object<T: Serialize + Send + Sync>(
+        &self,
+        class_name: &str,
+        data: &T,
+        use_master_key: bool,
+    ) -> Result<CreateObjectResponse, ParseError> {
+        validate_class_name(class_name)?;
+
+        #[cfg(feature = "encryption")]
+        let data = &self.encrypt_fields(class_name, data)?;
 
         let endpoint = format!("classes/{}", class_name);
-        match self.post(&endpoint, data).await {
-            Ok(res) => Ok(res),
-            Err(e) => Err(e),
-        }
+        self._request(Method::POST, &endpoint, Some(data), use_master_key, None)
+            .await
+    }
+
+    /// Creates an object, then fetches it back and returns it as a `T`.
+    ///
+    /// The create response only holds the `objectId` and `createdAt`; fetching the object also
+    /// returns the fields the server set itself, such as defaults from a `beforeSave` trigger or
+    /// schema, which merging the response into the sent body would miss. This costs a second
+    /// request. If the object cannot be read back by the current user (e.g. its ACL denies
+    /// reads), the object is still created and `ParseError::ObjectNotFound` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use parse_rs::{Parse, ParseError};
+    /// use serde::Deserialize;
+    /// use serde_json::json;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct GameScore {
+    ///     #[serde(rename = "objectId")]
+    ///     object_id: String,
+    ///     score: i64,
+    ///     rank: Option<String>, // set by a beforeSave trigger
+    /// }
+    ///
+    /// # async fn run(client: &Parse) -> Result<(), ParseError> {
+    /// let score: GameScore = client
+    ///     .create_object_and_fetch("GameScore", &json!({ "score": 1337 }))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_object_and_fetch<T, B>(
+        &self,
+        class_name: &str,
+        data: &B,
+    ) -> Result<T, ParseError>
+    where
+        T: DeserializeOwned + Send + 'static,
+        B: Serialize + Send + Sync,
+    {
+        let created = self.create_object(class_name, data).await?;
+        let endpoint = format!("classes/{}/{}", class_name, created.object_id);
+        let object: Value = self
+            ._request(Method::GET, &endpoint, None::<&Value>, false, None)
+            .await?;
+        #[cfg(feature = "encryption")]
+        let object = self.decrypt_json_fields(class_name, object)?;
+        T::deserialize(object).map_err(|e| {
+            ParseError::JsonDeserializationFailed(
+                format!(
+                    "Created '{}' object {} has an unexpected shape: {}",
+                    class_name, created.object_id, e
+                ),
+                e,
+            )
+        })
     }
 
     pub async fn retrieve_object(
@@ -186,6 +582,107 @@ impl Parse {
         class_name: &str,
         object_id: &str,
     ) -> Result<RetrievedParseObject, ParseError> {
+        self._retrieve_object(class_name, object_id, false).await
+    }
+
+    /// Retrieves an object using the Master Key for this single request, bypassing ACLs.
+    ///
+    /// Returns `ParseError::MasterKeyRequired` if no Master Key is configured on the client.
+    pub async fn retrieve_object_with_master_key(
+        &self,
+        class_name: &str,
+        object_id: &str,
+    ) -> Result<RetrievedParseObject, ParseError> {
+        self._retrieve_object(class_name, object_id, true).await
+    }
+
+    /// Retrieves an object with only the given fields, to avoid transferring large fields that
+    /// aren't needed.
+    ///
+    /// `keys` is sent as the `keys` parameter of the request; the server always includes
+    /// `objectId`, `createdAt` and `updatedAt`. Nested fields can be selected with dot notation
+    /// (e.g. `"address.city"`). With the `encryption` feature, selected encrypted fields are
+    /// decrypted.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use parse_rs::{Parse, ParseError, RetrievedParseObject};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ParseError> {
+    /// # let client = Parse::new("http://localhost:1338/parse", "myAppId", None, None, Some("myMasterKey"))?;
+    /// let score: RetrievedParseObject = client
+    ///     .fetch_object_fields("GameScore", "xWMyZ4YEGZ", &["score"])
+    ///     .await?;
+    /// assert!(!score.fields.contains_key("replay"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_object_fields<T: DeserializeOwned + Send + 'static>(
+        &self,
+        class_name: &str,
+        object_id: &str,
+        keys: &[&str],
+    ) -> Result<T, ParseError> {
+        Self::validate_retrieve_target(class_name, object_id)?;
+        self.validate_object_id(object_id)?;
+
+        let endpoint = format!("classes/{}/{}", class_name, object_id);
+        let params = [("keys".to_string(), keys.join(","))];
+        if !self.decrypts_fields() {
+            return self
+                ._get_with_url_params(&endpoint, &params, false, None)
+                .await;
+        }
+        let object: Value = self
+            ._get_with_url_params(&endpoint, &params, false, None)
+            .await?;
+        self.decode_object(class_name, object)
+    }
+
+    // Whether objects read from the server have fields to decrypt before deserialization.
+    pub(crate) fn decrypts_fields(&self) -> bool {
+        #[cfg(feature = "encryption")]
+        return !self.field_ciphers.is_empty();
+        #[cfg(not(feature = "encryption"))]
+        false
+    }
+
+    // Deserializes an object read from `class_name`, decrypting its encrypted fields first.
+    pub(crate) fn decode_object<T: DeserializeOwned>(
+        &self,
+        class_name: &str,
+        object: Value,
+    ) -> Result<T, ParseError> {
+        #[cfg(feature = "encryption")]
+        let object = self.decrypt_json_fields(class_name, object)?;
+        T::deserialize(object).map_err(|e| {
+            ParseError::JsonDeserializationFailed(
+                format!("'{}' object has an unexpected shape: {}", class_name, e),
+                e,
+            )
+        })
+    }
+
+    async fn _retrieve_object(
+        &self,
+        class_name: &str,
+        object_id: &str,
+        use_master_key: bool,
+    ) -> Result<RetrievedParseObject, ParseError> {
+        Self::validate_retrieve_target(class_name, object_id)?;
+        self.validate_object_id(object_id)?;
+
+        let endpoint = format!("classes/{}/{}", class_name, object_id);
+        let object: RetrievedParseObject = self
+            ._request(Method::GET, &endpoint, None::<&Value>, use_master_key, None)
+            .await?;
+        #[cfg(feature = "encryption")]
+        let object = self.decrypt_fields(class_name, object)?;
+        Ok(object)
+    }
+
+    fn validate_retrieve_target(class_name: &str, object_id: &str) -> Result<(), ParseError> {
         if class_name.is_empty() {
             return Err(ParseError::InvalidInput(
                 "Class name cannot be empty".to_string(),
@@ -211,9 +708,7 @@ impl Parse {
                     .to_string(),
             ));
         }
-
-        let endpoint = format!("classes/{}/{}", class_name, object_id);
-        self.get(&endpoint).await
+        Ok(())
     }
 
     pub async fn update_object<T: Serialize + Send + Sync>(
@@ -221,6 +716,106 @@ impl Parse {
         class_name: &str,
         object_id: &str,
         data: &T,
+    ) -> Result<UpdateObjectResponse, ParseError> {
+        self._update_object(class_name, object_id, data, false)
+            .await
+    }
+
+    /// Updates an object using the Master Key for this single request, bypassing ACLs.
+    ///
+    /// Returns `ParseError::MasterKeyRequired` if no Master Key is configured on the client.
+    pub async fn update_object_with_master_key<T: Serialize + Send + Sync>(
+        &self,
+        class_name: &str,
+        object_id: &str,
+        data: &T,
+    ) -> Result<UpdateObjectResponse, ParseError> {
+        self._update_object(class_name, object_id, data, true).await
+    }
+
+    /// Updates an object only if it has not been modified since `expected_updated_at`.
+    ///
+    /// Parse Server has no conditional update, so this first reads the object's current
+    /// `updatedAt` and returns `ParseError::Conflict` without writing if it differs from
+    /// `expected_updated_at` (typically the `updated_at` of the copy the change is based on).
+    /// Otherwise the update is sent as with [`update_object`](Self::update_object).
+    ///
+    /// This narrows lost updates to a short race window rather than closing it: a write by another
+    /// client that lands between the check and the update is still overwritten. Where that
+    /// matters, enforce the check server-side, e.g. in a `beforeSave` trigger.
+    ///
+    /// Returns `ParseError::ObjectNotFound` if the object does not exist (or is not readable).
+    pub async fn update_object_if_unchanged<T: Serialize + Send + Sync>(
+        &self,
+        class_name: &str,
+        object_id: &str,
+        data: &T,
+        expected_updated_at: &ParseDate,
+    ) -> Result<UpdateObjectResponse, ParseError> {
+        self.validate_object_id(object_id)?;
+
+        let mut query = crate::ParseQuery::new(class_name);
+        query.select(&["objectId"]);
+        let current: RetrievedParseObject = query.get(object_id, self).await?;
+        let unchanged = match (
+            current.updated_at.to_datetime(),
+            expected_updated_at.to_datetime(),
+        ) {
+            (Ok(current), Ok(expected)) => current == expected,
+            _ => current.updated_at.iso == expected_updated_at.iso,
+        };
+        if !unchanged {
+            return Err(ParseError::Conflict(format!(
+                "{} {} was modified at {}, expected last modification at {}",
+                class_name, object_id, current.updated_at.iso, expected_updated_at.iso
+            )));
+        }
+
+        self._update_object(class_name, object_id, data, false)
+            .await
+    }
+
+    /// Updates only the fields present in `partial`, after checking that it is a safe partial
+    /// update.
+    ///
+    /// Like any update, fields not named in `partial` are left untouched on the server. Unlike
+    /// [`update_object`](Self::update_object), which sends whatever it is given, this returns
+    /// `ParseError::InvalidInput` without contacting the server if `partial` is not a JSON
+    /// object, or if it names a reserved field: `objectId`, `createdAt` and `updatedAt`, or a
+    /// field starting with `_` or `$`. Values may be field operations (e.g. `Increment`) and keys
+    /// may use dot notation to update nested fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use parse_rs::{Parse, ParseError};
+    /// # use serde_json::json;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ParseError> {
+    /// # let client = Parse::new("http://localhost:1338/parse", "myAppId", None, None, Some("myMasterKey"))?;
+    /// client
+    ///     .merge_object("GameScore", "xWMyZ4YEGZ", json!({ "score": 73, "cheatMode": false }))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn merge_object(
+        &self,
+        class_name: &str,
+        object_id: &str,
+        partial: Value,
+    ) -> Result<UpdateObjectResponse, ParseError> {
+        validate_partial_update(&partial)?;
+        self._update_object(class_name, object_id, &partial, false)
+            .await
+    }
+
+    async fn _update_object<T: Serialize + Send + Sync>(
+        &self,
+        class_name: &str,
+        object_id: &str,
+        data: &T,
+        use_master_key: bool,
     ) -> Result<UpdateObjectResponse, ParseError> {
         if class_name.is_empty() {
             return Err(ParseError::InvalidInput(
@@ -247,9 +842,147 @@ impl Parse {
                     .to_string(),
             ));
         }
+        self.validate_object_id(object_id)?;
+
+        #[cfg(feature = "encryption")]
+        let data = &self.encrypt_fields(class_name, data)?;
 
         let endpoint = format!("classes/{}/{}", class_name, object_id);
-        self.put(&endpoint, data).await
+        self._request(Method::PUT, &endpoint, Some(data), use_master_key, None)
+            .await
+    }
+
+    /// Replaces the access control list of an existing object.
+    ///
+    /// Only the `ACL` field is sent, so other fields are left untouched. For example, to make an
+    /// object publicly readable:
+    ///
+    /// ```rust,no_run
+    /// # use parse_rs::{Parse, ParseError};
+    /// # async fn make_public(client: &Parse) -> Result<(), ParseError> {
+    /// let object = client.retrieve_object("Post", "xWMyZ4YEGZ").await?;
+    /// let mut acl = object.acl().unwrap_or_default();
+    /// acl.set_public_read_access(true);
+    /// client.update_object_acl("Post", &object.object_id, &acl).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_object_acl(
+        &self,
+        class_name: &str,
+        object_id: &str,
+        acl: &ParseACL,
+    ) -> Result<UpdateObjectResponse, ParseError> {
+        self.update_object(class_name, object_id, &json!({ "ACL": acl }))
+            .await
+    }
+
+    /// Removes a single field from an existing object without deleting the object.
+    ///
+    /// Sends `{"__op": "Delete"}` for the field, so the key is absent from subsequent fetches
+    /// (as opposed to updating it to `null`, which keeps the key).
+    pub async fn unset_field(
+        &self,
+        class_name: &str,
+        object_id: &str,
+        field_name: &str,
+    ) -> Result<UpdateObjectResponse, ParseError> {
+        if field_name.is_empty() {
+            return Err(ParseError::InvalidInput(
+                "Field name cannot be empty".to_string(),
+            ));
+        }
+        let mut patch = ParseObject::new(class_name);
+        patch.unset(field_name);
+        self.update_object(class_name, object_id, &patch.fields)
+            .await
+    }
+
+    /// Fetches several objects of the same class by `objectId`.
+    ///
+    /// Instead of one request per object, this issues a query with an `objectId` `$in`
+    /// constraint. Long id lists are split into chunks of 100 ids per query to keep request
+    /// URLs short. Ids with no matching object (or that the caller may not read) are skipped,
+    /// and the results are not guaranteed to be in the order of `object_ids`.
+    ///
+    /// # Arguments
+    /// * `class_name`: The name of the class to fetch from.
+    /// * `object_ids`: The ids of the objects to fetch.
+    ///
+    /// # Returns
+    /// A `Result` containing the fetched objects deserialized as `T`, or a `ParseError`.
+    pub async fn fetch_objects_by_ids<T: DeserializeOwned + Send + Sync + 'static>(
+        &self,
+        class_name: &str,
+        object_ids: &[&str],
+    ) -> Result<Vec<T>, ParseError> {
+        const IDS_PER_QUERY: usize = 100;
+
+        for object_id in object_ids {
+            self.validate_object_id(object_id)?;
+        }
+
+        let mut results = Vec::with_capacity(object_ids.len());
+        for chunk in object_ids.chunks(IDS_PER_QUERY) {
+            let mut query = crate::ParseQuery::new(class_name);
+            query
+                .contained_in("objectId", chunk.to_vec())
+                .limit(chunk.len() as isize);
+            results.extend(self.execute_query::<T>(&query).await?);
+        }
+        Ok(results)
+    }
+
+    /// Saves `object`: creates it if it has no `objectId` yet, otherwise sends only its
+    /// [dirty keys](ParseObject::dirty_keys) as an update, and sends nothing if it has none.
+    ///
+    /// On success the object's `objectId` and timestamps are filled in from the response and it
+    /// is clean again. On failure it keeps its dirty keys, so saving can be retried.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use parse_rs::{Parse, ParseError, ParseObject};
+    ///
+    /// # async fn run(client: &Parse) -> Result<(), ParseError> {
+    /// let mut score = ParseObject::new("GameScore").with("score", 1337);
+    /// client.save_object(&mut score).await?;
+    /// assert!(!score.is_dirty());
+    ///
+    /// score.set("cheatMode", false);
+    /// assert_eq!(score.dirty_keys(), vec!["cheatMode"]);
+    /// client.save_object(&mut score).await?; // sends only `cheatMode`
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn save_object(&self, object: &mut ParseObject) -> Result<(), ParseError> {
+        let Some(object_id) = object.object_id.clone() else {
+            let created = self.create_object(&object.class_name, &*object).await?;
+            object.object_id = Some(created.object_id);
+            object.updated_at = Some(created.created_at.clone());
+            object.created_at = Some(created.created_at);
+            object.dirty.clear();
+            return Ok(());
+        };
+        if !object.is_dirty() {
+            return Ok(());
+        }
+
+        let mut changes = serde_json::Map::new();
+        for key in &object.dirty {
+            let value = if key == "ACL" {
+                serde_json::to_value(&object.acl)?
+            } else {
+                object.fields.get(key).cloned().unwrap_or(Value::Null)
+            };
+            changes.insert(key.clone(), value);
+        }
+        let updated = self
+            .update_object(&object.class_name, &object_id, &changes)
+            .await?;
+        object.updated_at = Some(updated.updated_at);
+        object.dirty.clear();
+        Ok(())
     }
 
     pub async fn delete_object(&self, class_name: &str, object_id: &str) -> Result<(), ParseError> {
@@ -278,6 +1011,7 @@ impl Parse {
                     .to_string(),
             ));
         }
+        self.validate_object_id(object_id)?;
 
         let endpoint = format!("classes/{}/{}", class_name, object_id);
         let response_value: Value = self.delete::<Value>(&endpoint).await?;
@@ -294,3 +1028,323 @@ impl Parse {
         }
     }
 }
+
+// Checks that `partial` is a JSON object that names no reserved or internal fields.
+fn validate_partial_update(partial: &Value) -> Result<(), ParseError> {
+    let Value::Object(fields) = partial else {
+        return Err(ParseError::InvalidInput(format!(
+            "A partial update must be a JSON object, got: {}",
+            partial
+        )));
+    };
+    for key in fields.keys() {
+        let field = key.split('.').next().unwrap_or_default();
+        if field.is_empty()
+            || field.starts_with('_')
+            || field.starts_with('$')
+            || matches!(field, "objectId" | "createdAt" | "updatedAt")
+        {
+            return Err(ParseError::InvalidInput(format!(
+                "'{}' is a reserved field and cannot be set in a partial update",
+                key
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct Item {
+        name: String,
+        quantity: u32,
+    }
+
+    #[test]
+    fn test_dirty_keys_track_setters() {
+        let fetched =
+            ParseObject::from_json("GameScore", json!({ "score": 1, "cheatMode": false })).unwrap();
+        assert!(!fetched.is_dirty());
+        let deserialized: ParseObject =
+            serde_json::from_value(json!({ "objectId": "score00001", "score": 1 })).unwrap();
+        assert!(deserialized.dirty_keys().is_empty());
+
+        let mut score = fetched;
+        score.set("score", 2);
+        score.increment("plays", 1);
+        score.unset("cheatMode");
+        score.set_acl(ParseACL::new());
+        assert!(score.is_dirty());
+        assert_eq!(
+            score.dirty_keys(),
+            vec!["ACL", "cheatMode", "plays", "score"]
+        );
+    }
+
+    #[test]
+    fn test_increment_keeps_integer_amount() {
+        let mut object = ParseObject::new("Counter");
+        object.increment("hits", (1_i64 << 53) + 1);
+        let op = &object.fields["hits"];
+        assert_eq!(op["amount"].as_i64(), Some(9_007_199_254_740_993));
+        assert_eq!(
+            serde_json::to_string(op).unwrap(),
+            r#"{"__op":"Increment","amount":9007199254740993}"#
+        );
+    }
+
+    #[test]
+    fn test_get_nested() {
+        let object = RetrievedParseObject::try_from(json!({
+            "objectId": "obj0000001",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "updatedAt": "2024-01-01T00:00:00.000Z",
+            "stats": { "level": 12, "skills": { "sword": 3 } },
+            "items": [{ "name": "Potion", "quantity": 2 }, { "name": "Key", "quantity": 1 }]
+        }))
+        .unwrap();
+
+        assert_eq!(object.get_nested::<u32>("stats.level"), Some(12));
+        assert_eq!(object.get_nested::<u32>("stats.skills.sword"), Some(3));
+        assert_eq!(
+            object.get_nested::<String>("items.1.name").as_deref(),
+            Some("Key")
+        );
+        assert_eq!(
+            object.get_nested::<Item>("items.0"),
+            Some(Item {
+                name: "Potion".to_string(),
+                quantity: 2
+            })
+        );
+
+        assert_eq!(object.get_nested::<u32>("stats.missing.level"), None);
+        assert_eq!(object.get_nested::<u32>("missing.level"), None);
+        assert_eq!(object.get_nested::<u32>("items.5.quantity"), None);
+        assert_eq!(object.get_nested::<u32>("items.first"), None);
+        assert_eq!(object.get_nested::<u32>("stats.level.value"), None);
+        assert_eq!(object.get_nested::<String>("stats.level"), None);
+    }
+
+    #[test]
+    fn test_validate_partial_update() {
+        assert!(validate_partial_update(&json!({ "score": 1, "address.city": "Berlin" })).is_ok());
+        assert!(
+            validate_partial_update(&json!({ "score": { "__op": "Increment", "amount": 1 } }))
+                .is_ok()
+        );
+
+        for rejected in [
+            json!({ "score": 1, "objectId": "abc123XYZ0" }),
+            json!({ "createdAt": "2024-01-01T00:00:00.000Z" }),
+            json!({ "updatedAt.iso": "2024-01-01T00:00:00.000Z" }),
+            json!({ "_rperm": ["*"] }),
+            json!({ "$set": { "score": 1 } }),
+            json!(["score", 1]),
+        ] {
+            assert!(
+                matches!(
+                    validate_partial_update(&rejected),
+                    Err(ParseError::InvalidInput(_))
+                ),
+                "Expected {} to be rejected",
+                rejected
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_with_chained_setters() {
+        let object = ParseObject::new("GameScore")
+            .with("score", 1337)
+            .with("playerName", "Sean Plott")
+            .with("cheatMode", false);
+
+        assert_eq!(object.class_name, "GameScore");
+        assert_eq!(
+            serde_json::to_value(&object).unwrap(),
+            json!({ "score": 1337, "playerName": "Sean Plott", "cheatMode": false })
+        );
+    }
+
+    #[test]
+    fn test_from_json() {
+        let object = ParseObject::from_json(
+            "GameScore",
+            json!({
+                "objectId": "abc123XYZ0",
+                "score": 1337,
+                "ACL": { "*": { "read": true } }
+            }),
+        )
+        .unwrap();
+        assert_eq!(object.class_name, "GameScore");
+        assert_eq!(object.object_id.as_deref(), Some("abc123XYZ0"));
+        assert_eq!(object.get::<i64>("score"), Some(1337));
+        assert!(object.acl.is_some());
+        assert!(!object.fields.contains_key("ACL"));
+
+        assert!(matches!(
+            ParseObject::from_json("GameScore", json!([1, 2])),
+            Err(ParseError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_get_array_of_structs() {
+        let mut object = ParseObject::new("Order");
+        object.set(
+            "items",
+            json!([
+                { "name": "apple", "quantity": 3 },
+                { "name": "pear", "quantity": 1 }
+            ]),
+        );
+
+        let items: Vec<Item> = object.get_array("items").unwrap();
+        assert_eq!(
+            items,
+            vec![
+                Item {
+                    name: "apple".to_string(),
+                    quantity: 3
+                },
+                Item {
+                    name: "pear".to_string(),
+                    quantity: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_array_errors() {
+        let mut object = ParseObject::new("Order");
+        object.set(
+            "items",
+            json!([{ "name": "apple", "quantity": 3 }, { "name": "pear" }]),
+        );
+        object.set("title", "not an array");
+
+        let mismatched: Result<Vec<Item>, _> = object.get_array("items");
+        assert!(
+            matches!(mismatched, Err(ParseError::JsonDeserializationFailed(ref msg, _)) if msg.contains("Element 1"))
+        );
+        assert!(matches!(
+            object.get_array::<Item>("title"),
+            Err(ParseError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            object.get_array::<Item>("missing"),
+            Err(ParseError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_retrieved_object_acl() {
+        let mut object: RetrievedParseObject = serde_json::from_value(json!({
+            "objectId": "abc123XYZ0",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "updatedAt": "2024-01-01T00:00:00.000Z",
+            "ACL": { "*": { "read": true }, "user000001": { "read": true, "write": true } }
+        }))
+        .unwrap();
+
+        let acl = object.acl().expect("ACL should be parsed");
+        assert!(acl.get_public_read_access());
+        assert!(!acl.get_public_write_access());
+        assert!(acl.get_user_write_access("user000001"));
+        assert!(!object.fields.contains_key("ACL"));
+
+        let mut private = ParseACL::new();
+        private.set_user_read_access("user000001", true);
+        object.set_acl(private.clone());
+        assert_eq!(object.acl(), Some(private));
+    }
+
+    #[test]
+    fn test_geo_point_field_round_trip() {
+        let point = ParseGeoPoint::new(52.52, 13.405).unwrap();
+        let mut object = ParseObject::new("Place");
+        object.set_geo_point("location", &point);
+        object.set("name", "Berlin");
+
+        // Round-trip through JSON as the server would return it.
+        let mut stored = serde_json::to_value(&object).unwrap();
+        stored["objectId"] = json!("abc123XYZ0");
+        stored["createdAt"] = json!("2024-01-01T00:00:00.000Z");
+        stored["updatedAt"] = json!("2024-01-01T00:00:00.000Z");
+        assert_eq!(
+            stored["location"],
+            json!({ "__type": "GeoPoint", "latitude": 52.52, "longitude": 13.405 })
+        );
+        let retrieved: RetrievedParseObject = serde_json::from_value(stored).unwrap();
+
+        assert_eq!(retrieved.get_geo_point("location"), Some(point));
+        assert_eq!(retrieved.get_geo_point("name"), None);
+        assert_eq!(retrieved.get_geo_point("missing"), None);
+    }
+
+    #[test]
+    fn test_normalize_typed_fields() {
+        let object: RetrievedParseObject = serde_json::from_value(json!({
+            "objectId": "abc123XYZ0",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "updatedAt": "2024-01-02T00:00:00.000Z",
+            "owner": { "__type": "Pointer", "className": "_User", "objectId": "user000001" },
+            "members": { "__type": "Relation", "className": "_User" },
+            "dueAt": { "__type": "Date", "iso": "2024-03-04T05:06:07.000Z" },
+            "location": { "__type": "GeoPoint", "latitude": 40.0, "longitude": -30.0 },
+            "title": "plain"
+        }))
+        .unwrap();
+
+        let normalized = object.normalize();
+        assert_eq!(
+            normalized.get_pointer("owner"),
+            Some(&Pointer::new("_User", "user000001"))
+        );
+        assert_eq!(
+            normalized.get_relation("members"),
+            Some(&ParseRelation::new("_User"))
+        );
+        assert_eq!(
+            normalized.get_date("dueAt"),
+            Some(&ParseDate::new("2024-03-04T05:06:07.000Z"))
+        );
+        assert_eq!(
+            normalized.get_geo_point("location"),
+            Some(&ParseGeoPoint::new(40.0, -30.0).unwrap())
+        );
+        assert_eq!(normalized.get_value("title"), Some(&json!("plain")));
+        assert_eq!(normalized.get_pointer("title"), None);
+        assert_eq!(normalized.get_relation("owner"), None);
+    }
+
+    #[test]
+    fn test_normalize_included_object_as_pointer() {
+        let object: RetrievedParseObject = serde_json::from_value(json!({
+            "objectId": "abc123XYZ0",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "updatedAt": "2024-01-01T00:00:00.000Z",
+            "author": {
+                "__type": "Object",
+                "className": "Author",
+                "objectId": "author0001",
+                "name": "Ada"
+            },
+            "broken": { "__type": "Pointer", "className": "Author" }
+        }))
+        .unwrap();
+
+        let normalized = object.normalize();
+        assert_eq!(
+            normalized.get_pointer("author"),
+            Some(&Pointer::new("Author", "author0001"))
+        );
+        assert!(normalized.get_value("broken").is_some());
+    }
+}
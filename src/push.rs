@@ -0,0 +1,143 @@
+// src/push.rs
+
+use crate::client::{AuthType, Parse};
+use crate::error::ParseError;
+use crate::query::ParseQuery;
+use reqwest::Method;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Response header in which Parse Server returns the id of the `_PushStatus` object it creates
+/// for each push.
+pub const PUSH_STATUS_ID_HEADER: &str = "X-Parse-Push-Status-Id";
+
+/// A push notification to send with [`Parse::send_push`].
+///
+/// A push is targeted either at installations subscribed to some `channels`, or at the
+/// installations matching a `where` query (or both). `data` is the notification payload, e.g.
+/// `{"alert": "...", "badge": "Increment", "sound": "default"}`.
+///
+/// # Example
+///
+/// ```rust
+/// use parse_rs::{ParseQuery, PushPayload};
+/// use serde_json::json;
+///
+/// let to_channels = PushPayload::new(json!({ "alert": "The Giants won!" }))
+///     .channels(&["Giants", "Mets"]);
+///
+/// let mut ios = ParseQuery::new("_Installation");
+/// ios.equal_to("deviceType", "ios");
+/// let to_query = PushPayload::new(json!({ "alert": "Update available", "badge": "Increment" }))
+///     .where_query(&ios);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PushPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channels: Option<Vec<String>>,
+    #[serde(rename = "where", skip_serializing_if = "Option::is_none")]
+    where_clause: Option<Value>,
+    data: Value,
+}
+
+impl PushPayload {
+    /// Creates a push carrying `data`. Set a target with [`channels`](Self::channels) or
+    /// [`where_query`](Self::where_query) before sending it.
+    pub fn new(data: Value) -> Self {
+        Self {
+            channels: None,
+            where_clause: None,
+            data,
+        }
+    }
+
+    /// Targets installations subscribed to any of `channels`.
+    pub fn channels(mut self, channels: &[&str]) -> Self {
+        self.channels = Some(channels.iter().map(|c| c.to_string()).collect());
+        self
+    }
+
+    /// Targets the installations matching `query`, which should be a query on `_Installation`.
+    /// Only its constraints are used.
+    pub fn where_query(mut self, query: &ParseQuery) -> Self {
+        self.where_clause = query.to_json().get("where").cloned();
+        self
+    }
+
+    /// Targets the installations matching a raw `where` clause.
+    pub fn where_clause(mut self, where_clause: Value) -> Self {
+        self.where_clause = Some(where_clause);
+        self
+    }
+
+    /// Returns the notification payload.
+    pub fn data(&self) -> &Value {
+        &self.data
+    }
+
+    fn has_target(&self) -> bool {
+        self.channels.as_ref().is_some_and(|c| !c.is_empty())
+            || self.where_clause.as_ref().is_some_and(|w| !w.is_null())
+    }
+}
+
+impl Parse {
+    /// Sends a push notification through the `/push` endpoint, using the Master Key.
+    ///
+    /// Parse Server queues the push and returns immediately; delivery progress is recorded on a
+    /// `_PushStatus` object, whose `objectId` is returned (read from the
+    /// [`X-Parse-Push-Status-Id`](PUSH_STATUS_ID_HEADER) response header).
+    ///
+    /// Fails with `ParseError::InvalidInput` if the payload has neither `channels` nor a `where`
+    /// clause, with `ParseError::MasterKeyRequired` if the client has no Master Key, and with
+    /// `ParseError::UnexpectedResponse` if the server does not report a push status id (push
+    /// not configured, or a server version without push status tracking).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use parse_rs::{Parse, ParseError, PushPayload};
+    /// use serde_json::json;
+    ///
+    /// # async fn run(client: &Parse) -> Result<(), ParseError> {
+    /// let push = PushPayload::new(json!({ "alert": "The Giants won!" })).channels(&["Giants"]);
+    /// let status_id = client.send_push(&push).await?;
+    /// println!("Push queued, status: _PushStatus/{}", status_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_push(&self, payload: &PushPayload) -> Result<String, ParseError> {
+        if !payload.has_target() {
+            return Err(ParseError::InvalidInput(
+                "A push needs `channels` or a `where` clause to select its recipients".to_string(),
+            ));
+        }
+
+        let response = self
+            .raw_request(
+                Method::POST,
+                "push",
+                Some(serde_json::to_value(payload)?),
+                AuthType::MasterKey,
+            )
+            .await?;
+        let status = response.status();
+        let status_id = response
+            .headers()
+            .get(PUSH_STATUS_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        if !status.is_success() {
+            let body = response.text().await.map_err(ParseError::from)?;
+            let body = serde_json::from_str(&body).unwrap_or(Value::String(body));
+            return Err(ParseError::from_response(status.as_u16(), body));
+        }
+
+        status_id.ok_or_else(|| {
+            ParseError::UnexpectedResponse(format!(
+                "The push was accepted, but the response has no {} header",
+                PUSH_STATUS_ID_HEADER
+            ))
+        })
+    }
+}
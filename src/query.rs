@@ -1,9 +1,344 @@
 // src/query.rs
 
+use chrono::{DateTime, Utc};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::{json, Map, Value};
+use std::time::Duration;
+
+use crate::batch::{BatchOp, ParseBatch, MAX_BATCH_SIZE};
+use crate::geopoint::ParseGeoPoint;
+use crate::{client::Parse, error::ParseError, ParseDate, ParseSchema, Pointer, Results};
+
+/// A single typed sort key, used with [`ParseQuery::order_by`].
+///
+/// `Sort::desc("score")` renders as `-score`; `Sort::asc("name")` renders as `name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sort {
+    field: String,
+    descending: bool,
+}
+
+impl Sort {
+    /// Sorts by `field` in ascending order.
+    pub fn asc(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            descending: false,
+        }
+    }
+
+    /// Sorts by `field` in descending order.
+    pub fn desc(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            descending: true,
+        }
+    }
+
+    /// Returns the field name this key sorts by.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Returns `true` if this key sorts in descending order.
+    pub fn is_descending(&self) -> bool {
+        self.descending
+    }
+}
+
+impl std::fmt::Display for Sort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.descending {
+            write!(f, "-{}", self.field)
+        } else {
+            write!(f, "{}", self.field)
+        }
+    }
+}
+
+/// A composable query constraint, compiled into a `where` clause by [`ParseQuery::filter`].
+///
+/// This is an alternative to the chained constraint methods for filters that are built
+/// programmatically, e.g. from user input. `And` and `Or` nest arbitrarily:
+///
+/// ```rust
+/// use parse_rs::query::Constraint;
+/// use serde_json::json;
+///
+/// let filter = Constraint::And(vec![
+///     Constraint::eq("status", "active"),
+///     Constraint::Or(vec![
+///         Constraint::gt("score", 100),
+///         Constraint::regex("name", "^A", Some("i")),
+///     ]),
+/// ]);
+/// assert_eq!(
+///     filter.to_where(),
+///     json!({
+///         "status": "active",
+///         "$or": [
+///             { "score": { "$gt": 100 } },
+///             { "name": { "$regex": "^A", "$options": "i" } }
+///         ]
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constraint {
+    /// All of the constraints must match. Compiled into a single object where the keys do not
+    /// conflict, and into `$and` otherwise.
+    And(Vec<Constraint>),
+    /// At least one of the constraints must match (`$or`).
+    Or(Vec<Constraint>),
+    /// The field equals the value.
+    Eq(String, Value),
+    /// The field does not equal the value (`$ne`).
+    NotEq(String, Value),
+    /// The field is greater than the value (`$gt`).
+    Gt(String, Value),
+    /// The field is greater than or equal to the value (`$gte`).
+    Gte(String, Value),
+    /// The field is less than the value (`$lt`).
+    Lt(String, Value),
+    /// The field is less than or equal to the value (`$lte`).
+    Lte(String, Value),
+    /// The field equals one of the values (`$in`).
+    In(String, Vec<Value>),
+    /// The field equals none of the values (`$nin`).
+    NotIn(String, Vec<Value>),
+    /// The field is set (`$exists: true`).
+    Exists(String),
+    /// The field is not set (`$exists: false`).
+    DoesNotExist(String),
+    /// The string field matches a regular expression (`$regex`, with optional `$options`).
+    Regex {
+        key: String,
+        pattern: String,
+        options: Option<String>,
+    },
+}
+
+impl Constraint {
+    /// Shorthand for [`Constraint::Eq`].
+    pub fn eq(key: &str, value: impl Into<Value>) -> Self {
+        Constraint::Eq(key.to_string(), value.into())
+    }
+
+    /// Shorthand for [`Constraint::NotEq`].
+    pub fn not_eq(key: &str, value: impl Into<Value>) -> Self {
+        Constraint::NotEq(key.to_string(), value.into())
+    }
+
+    /// Shorthand for [`Constraint::Gt`].
+    pub fn gt(key: &str, value: impl Into<Value>) -> Self {
+        Constraint::Gt(key.to_string(), value.into())
+    }
+
+    /// Shorthand for [`Constraint::Gte`].
+    pub fn gte(key: &str, value: impl Into<Value>) -> Self {
+        Constraint::Gte(key.to_string(), value.into())
+    }
+
+    /// Shorthand for [`Constraint::Lt`].
+    pub fn lt(key: &str, value: impl Into<Value>) -> Self {
+        Constraint::Lt(key.to_string(), value.into())
+    }
+
+    /// Shorthand for [`Constraint::Lte`].
+    pub fn lte(key: &str, value: impl Into<Value>) -> Self {
+        Constraint::Lte(key.to_string(), value.into())
+    }
+
+    /// Shorthand for [`Constraint::In`].
+    pub fn contained_in<V: Into<Value>>(key: &str, values: impl IntoIterator<Item = V>) -> Self {
+        Constraint::In(
+            key.to_string(),
+            values.into_iter().map(Into::into).collect(),
+        )
+    }
+
+    /// Shorthand for [`Constraint::NotIn`].
+    pub fn not_contained_in<V: Into<Value>>(
+        key: &str,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        Constraint::NotIn(
+            key.to_string(),
+            values.into_iter().map(Into::into).collect(),
+        )
+    }
+
+    /// Shorthand for [`Constraint::Exists`].
+    pub fn exists(key: &str) -> Self {
+        Constraint::Exists(key.to_string())
+    }
+
+    /// Shorthand for [`Constraint::DoesNotExist`].
+    pub fn does_not_exist(key: &str) -> Self {
+        Constraint::DoesNotExist(key.to_string())
+    }
+
+    /// Shorthand for [`Constraint::Regex`].
+    pub fn regex(key: &str, pattern: &str, options: Option<&str>) -> Self {
+        Constraint::Regex {
+            key: key.to_string(),
+            pattern: pattern.to_string(),
+            options: options.map(str::to_string),
+        }
+    }
+
+    /// Compiles the constraint into a `where` clause object.
+    pub fn to_where(&self) -> Value {
+        Value::Object(self.compile())
+    }
+
+    fn compile(&self) -> Map<String, Value> {
+        let field = |key: &String, value: Value| {
+            let mut map = Map::new();
+            map.insert(key.clone(), value);
+            map
+        };
+        let operator = |key: &String, op: &str, value: Value| field(key, json!({ op: value }));
+
+        match self {
+            Constraint::And(constraints) => {
+                let compiled: Vec<Map<String, Value>> =
+                    constraints.iter().map(Constraint::compile).collect();
+                let mut merged = Map::new();
+                if compiled.iter().all(|c| merge_where(&mut merged, c)) {
+                    merged
+                } else {
+                    let clauses = compiled.into_iter().map(Value::Object).collect();
+                    field(&"$and".to_string(), Value::Array(clauses))
+                }
+            }
+            Constraint::Or(constraints) => {
+                let clauses = constraints
+                    .iter()
+                    .map(|c| Value::Object(c.compile()))
+                    .collect();
+                field(&"$or".to_string(), Value::Array(clauses))
+            }
+            Constraint::Eq(key, value) => field(key, value.clone()),
+            Constraint::NotEq(key, value) => operator(key, "$ne", value.clone()),
+            Constraint::Gt(key, value) => operator(key, "$gt", value.clone()),
+            Constraint::Gte(key, value) => operator(key, "$gte", value.clone()),
+            Constraint::Lt(key, value) => operator(key, "$lt", value.clone()),
+            Constraint::Lte(key, value) => operator(key, "$lte", value.clone()),
+            Constraint::In(key, values) => operator(key, "$in", Value::Array(values.clone())),
+            Constraint::NotIn(key, values) => operator(key, "$nin", Value::Array(values.clone())),
+            Constraint::Exists(key) => operator(key, "$exists", Value::Bool(true)),
+            Constraint::DoesNotExist(key) => operator(key, "$exists", Value::Bool(false)),
+            Constraint::Regex {
+                key,
+                pattern,
+                options,
+            } => {
+                let mut regex = json!({ "$regex": pattern });
+                if let Some(options) = options {
+                    regex["$options"] = Value::String(options.clone());
+                }
+                field(key, regex)
+            }
+        }
+    }
+}
+
+// Merges the `where` clause `from` into `into` if both can hold at the same time in one object:
+// keys must be distinct, except for field constraints made only of distinct `$` operators
+// (e.g. `$gt` and `$lt` on the same field). Returns `false`, leaving `into` untouched, otherwise.
+fn merge_where(into: &mut Map<String, Value>, from: &Map<String, Value>) -> bool {
+    let is_operator_object = |value: &Value| {
+        value
+            .as_object()
+            .is_some_and(|ops| !ops.is_empty() && ops.keys().all(|k| k.starts_with('$')))
+    };
+    let mergeable = from.iter().all(|(key, value)| match into.get(key) {
+        None => true,
+        Some(existing) => {
+            !key.starts_with('$')
+                && is_operator_object(existing)
+                && is_operator_object(value)
+                && value
+                    .as_object()
+                    .unwrap()
+                    .keys()
+                    .all(|op| !existing.as_object().unwrap().contains_key(op))
+        }
+    });
+    if !mergeable {
+        return false;
+    }
+    for (key, value) in from {
+        match into.get_mut(key) {
+            Some(Value::Object(existing)) => {
+                if let Value::Object(ops) = value {
+                    existing.extend(ops.clone());
+                }
+            }
+            _ => {
+                into.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    true
+}
 
-use crate::{client::Parse, error::ParseError, Pointer};
+// Merges `from` into `into` recursively: objects present on both sides are merged key by key,
+// and any other value in `from` replaces the one in `into`.
+fn deep_merge(into: &mut Map<String, Value>, from: Map<String, Value>) {
+    for (key, value) in from {
+        match (into.get_mut(&key), value) {
+            (Some(Value::Object(existing)), Value::Object(nested)) => deep_merge(existing, nested),
+            (_, value) => {
+                into.insert(key, value);
+            }
+        }
+    }
+}
+
+// A Parse `Date` value for `duration` before the current time, clamped to the earliest
+// representable date.
+fn date_before_now(duration: Duration) -> Value {
+    let now = Utc::now();
+    let cutoff = chrono::Duration::from_std(duration)
+        .ok()
+        .and_then(|duration| now.checked_sub_signed(duration))
+        .unwrap_or(DateTime::<Utc>::MIN_UTC);
+    json!(ParseDate::from_datetime(cutoff))
+}
+
+/// The default maximum number of objects [`ParseQuery::find_all`] loads before failing with
+/// `ParseError::TooManyResults`.
+pub const DEFAULT_FIND_ALL_CAP: usize = 10_000;
+
+/// Skips above this make [`ParseQuery::with_limit_and_skip`] log a warning: the server still has
+/// to walk past every skipped object, so deep pages get slow.
+pub const LARGE_SKIP_WARNING_THRESHOLD: usize = 10_000;
+
+// The largest integer Parse Server (a JavaScript process) can read exactly.
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+// The JSON form of a `ParseQuery` used by `to_json` / `from_json`. It has no master-key flag.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SerializedQuery {
+    class_name: String,
+    #[serde(rename = "where", default, skip_serializing_if = "Map::is_empty")]
+    conditions: Map<String, Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    limit: Option<isize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    skip: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    order: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    include: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    keys: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    exclude_keys: Option<String>,
+}
 
 /// Represents a query to be performed against a Parse Server class.
 #[derive(Debug, Clone)]
@@ -14,11 +349,13 @@ pub struct ParseQuery {
     skip: Option<usize>,
     order: Option<String>,
     include: Option<String>,
-    keys: Option<String>, // For selecting specific fields
+    keys: Option<String>,         // For selecting specific fields
+    exclude_keys: Option<String>, // For dropping specific fields
     // count_flag: bool, // To indicate if this is a count query, managed by the count() method call
     // read_preference: Option<String>, // For advanced MongoDB read preferences, future
     // include_all: bool, // Future
     use_master_key: bool, // Whether the query should be executed with the master key
+    force_post: bool,     // Always send as a POST with `_method=GET` (see `force_post`)
 }
 
 impl ParseQuery {
@@ -35,9 +372,176 @@ impl ParseQuery {
             order: None,
             include: None,
             keys: None,
+            exclude_keys: None,
             // count_flag: false,
             use_master_key: false, // Default to false
+            force_post: false,
+        }
+    }
+
+    /// Returns an owned copy of this query, ending a `&mut Self` chain.
+    ///
+    /// ```
+    /// use parse_rs::ParseQuery;
+    ///
+    /// let query = ParseQuery::new("GameScore").equal_to("score", 1).limit(10).build();
+    /// assert_eq!(query.class_name(), "GameScore");
+    /// ```
+    pub fn build(&self) -> ParseQuery {
+        self.clone()
+    }
+
+    // --- Owned builder variants ---
+    //
+    // Each `with_*` method applies the same constraint as its `&mut Self` counterpart but takes
+    // and returns the query by value, so a query can be built in a single expression and
+    // returned from a function.
+
+    /// Owned variant of [`equal_to`](Self::equal_to).
+    ///
+    /// ```
+    /// use parse_rs::ParseQuery;
+    ///
+    /// fn top_scores() -> ParseQuery {
+    ///     ParseQuery::new("GameScore")
+    ///         .with_equal_to("cheatMode", false)
+    ///         .with_order("-score")
+    ///         .with_limit(10)
+    /// }
+    /// assert_eq!(top_scores().class_name(), "GameScore");
+    /// ```
+    pub fn with_equal_to<V: Serialize>(mut self, key: &str, value: V) -> Self {
+        self.equal_to(key, value);
+        self
+    }
+
+    /// Owned variant of [`not_equal_to`](Self::not_equal_to).
+    pub fn with_not_equal_to<V: Serialize>(mut self, key: &str, value: V) -> Self {
+        self.not_equal_to(key, value);
+        self
+    }
+
+    /// Owned variant of [`greater_than`](Self::greater_than).
+    pub fn with_greater_than<V: Serialize>(mut self, key: &str, value: V) -> Self {
+        self.greater_than(key, value);
+        self
+    }
+
+    /// Owned variant of [`greater_than_or_equal_to`](Self::greater_than_or_equal_to).
+    pub fn with_greater_than_or_equal_to<V: Serialize>(mut self, key: &str, value: V) -> Self {
+        self.greater_than_or_equal_to(key, value);
+        self
+    }
+
+    /// Owned variant of [`less_than`](Self::less_than).
+    pub fn with_less_than<V: Serialize>(mut self, key: &str, value: V) -> Self {
+        self.less_than(key, value);
+        self
+    }
+
+    /// Owned variant of [`less_than_or_equal_to`](Self::less_than_or_equal_to).
+    pub fn with_less_than_or_equal_to<V: Serialize>(mut self, key: &str, value: V) -> Self {
+        self.less_than_or_equal_to(key, value);
+        self
+    }
+
+    /// Owned variant of [`contained_in`](Self::contained_in).
+    pub fn with_contained_in<V: Serialize>(mut self, key: &str, values: Vec<V>) -> Self {
+        self.contained_in(key, values);
+        self
+    }
+
+    /// Owned variant of [`exists`](Self::exists).
+    pub fn with_exists(mut self, key: &str) -> Self {
+        self.exists(key);
+        self
+    }
+
+    /// Owned variant of [`does_not_exist`](Self::does_not_exist).
+    pub fn with_does_not_exist(mut self, key: &str) -> Self {
+        self.does_not_exist(key);
+        self
+    }
+
+    /// Owned variant of [`filter`](Self::filter).
+    pub fn with_filter(mut self, constraint: Constraint) -> Self {
+        self.filter(constraint);
+        self
+    }
+
+    /// Owned variant of [`limit`](Self::limit).
+    pub fn with_limit(mut self, count: isize) -> Self {
+        self.limit(count);
+        self
+    }
+
+    /// Owned variant of [`skip`](Self::skip).
+    pub fn with_skip(mut self, count: usize) -> Self {
+        self.skip(count);
+        self
+    }
+
+    /// Sets both the limit and the skip after checking them, unlike [`limit`](Self::limit) and
+    /// [`skip`](Self::skip), which send any value as-is.
+    ///
+    /// `limit` may be positive, `0` (no results, as used when only counting) or `-1` (no limit).
+    /// Other negative limits, and skips too large for the server to read exactly, return
+    /// `ParseError::InvalidInput` and leave the query unchanged. Skips above
+    /// [`LARGE_SKIP_WARNING_THRESHOLD`] are accepted but logged as a warning, since the server
+    /// walks past every skipped object; for deep pagination, prefer a keyset constraint such as
+    /// `greater_than("objectId", last_seen_id)` with `order("objectId")`.
+    pub fn with_limit_and_skip(mut self, limit: isize, skip: usize) -> Result<Self, ParseError> {
+        if limit < -1 {
+            return Err(ParseError::InvalidInput(format!(
+                "Invalid query limit {}: use a positive limit, 0 to only count, or -1 for no limit",
+                limit
+            )));
+        }
+        if skip as u64 > MAX_SAFE_INTEGER {
+            return Err(ParseError::InvalidInput(format!(
+                "Invalid query skip {}: exceeds the largest skip the server can represent ({})",
+                skip, MAX_SAFE_INTEGER
+            )));
         }
+        if skip > LARGE_SKIP_WARNING_THRESHOLD {
+            log::warn!(
+                "Query on '{}' skips {} objects; consider keyset pagination (e.g. ordering by objectId and filtering past the last one seen) instead",
+                self.class_name,
+                skip
+            );
+        }
+        self.limit(limit).skip(skip);
+        Ok(self)
+    }
+
+    /// Owned variant of [`order`](Self::order).
+    pub fn with_order(mut self, field_names: &str) -> Self {
+        self.order(field_names);
+        self
+    }
+
+    /// Owned variant of [`order_by`](Self::order_by).
+    pub fn with_order_by(mut self, sorts: &[Sort]) -> Self {
+        self.order_by(sorts);
+        self
+    }
+
+    /// Owned variant of [`include`](Self::include).
+    pub fn with_include(mut self, keys_to_include: &[&str]) -> Self {
+        self.include(keys_to_include);
+        self
+    }
+
+    /// Owned variant of [`select`](Self::select).
+    pub fn with_select(mut self, keys_to_select: &[&str]) -> Self {
+        self.select(keys_to_select);
+        self
+    }
+
+    /// Owned variant of [`set_master_key`](Self::set_master_key).
+    pub fn with_master_key(mut self, use_key: bool) -> Self {
+        self.set_master_key(use_key);
+        self
     }
 
     /// Returns the class name this query targets.
@@ -56,18 +560,89 @@ impl ParseQuery {
         self
     }
 
+    /// Sets whether this query is always sent as a POST with `_method=GET` and the parameters in
+    /// the JSON body, instead of a GET with the parameters in the URL.
+    ///
+    /// Queries whose URL would be too long (e.g. a large `contained_in` list) are sent this way
+    /// automatically; this forces it regardless of size.
+    pub fn force_post(&mut self, enabled: bool) -> &mut Self {
+        self.force_post = enabled;
+        self
+    }
+
+    /// Returns `true` if this query is always sent as a POST with `_method=GET`.
+    pub fn forces_post(&self) -> bool {
+        self.force_post
+    }
+
+    /// Adds a [`Constraint`] tree to the query.
+    ///
+    /// The compiled constraint is combined with any existing conditions: merged into the same
+    /// `where` object where possible, and joined with `$and` otherwise, so every condition must
+    /// still match.
+    pub fn filter(&mut self, constraint: Constraint) -> &mut Self {
+        let compiled = constraint.compile();
+        if !merge_where(&mut self.conditions, &compiled) {
+            let existing = std::mem::take(&mut self.conditions);
+            self.conditions.insert(
+                "$and".to_string(),
+                json!([Value::Object(existing), Value::Object(compiled)]),
+            );
+        }
+        self
+    }
+
+    /// Merges a raw `where` fragment into the query's conditions, as an escape hatch for
+    /// operators without a typed method.
+    ///
+    /// The fragment is merged, not substituted: it is combined key by key with the existing
+    /// conditions, recursing into objects present on both sides, so `{"score": {"$mod": [5, 0]}}`
+    /// keeps an existing `$gt` on `score`. Where both sides hold a non-object value for the same
+    /// key, the fragment's wins. Fails with `ParseError::InvalidInput` if `fragment` is not a
+    /// JSON object. The fragment is sent as-is; Parse Server rejects operators it does not
+    /// support.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use parse_rs::ParseQuery;
+    /// use serde_json::json;
+    ///
+    /// let mut query = ParseQuery::new("GameScore");
+    /// query.equal_to("playerName", "Sean");
+    /// query.where_raw(json!({ "score": { "$mod": [5, 0] } })).unwrap();
+    /// ```
+    pub fn where_raw(&mut self, fragment: Value) -> Result<&mut Self, ParseError> {
+        let Value::Object(fragment) = fragment else {
+            return Err(ParseError::InvalidInput(
+                "A raw where fragment must be a JSON object".to_string(),
+            ));
+        };
+        deep_merge(&mut self.conditions, fragment);
+        Ok(self)
+    }
+
     // Helper to add a simple condition like "field": "value"
     fn add_simple_condition(&mut self, key: &str, value: Value) -> &mut Self {
         self.conditions.insert(key.to_string(), value);
         self
     }
 
-    // Helper to add an operator condition like "field": {"$op": "value"}
+    // Helper to add an operator condition like "field": {"$op": "value"}.
+    // Merges into the field's existing operator object (e.g. `$exists` alongside `$gt`); an
+    // existing plain value such as an equality match is replaced.
     fn add_operator_condition(&mut self, key: &str, operator: &str, value: Value) -> &mut Self {
-        let mut op_map = Map::new();
-        op_map.insert(operator.to_string(), value);
-        self.conditions
-            .insert(key.to_string(), Value::Object(op_map));
+        match self.conditions.get_mut(key) {
+            Some(Value::Object(op_map)) if op_map.keys().all(|k| k.starts_with('$')) => {
+                op_map.insert(operator.to_string(), value);
+            }
+            _ => {
+                let mut op_map = Map::new();
+                op_map.insert(operator.to_string(), value);
+                self.conditions
+                    .insert(key.to_string(), Value::Object(op_map));
+            }
+        }
         self
     }
 
@@ -82,6 +657,58 @@ impl ParseQuery {
         }
     }
 
+    /// Adds a constraint that a pointer field must point to the given object.
+    ///
+    /// The match is on both `className` and `objectId`. Use
+    /// [`check_pointer_classes`](Self::check_pointer_classes) with the class schema to catch
+    /// pointers built for the wrong target class.
+    pub fn equal_to_pointer(&mut self, key: &str, pointer: &Pointer) -> &mut Self {
+        self.equal_to(key, pointer)
+    }
+
+    /// Compares the pointer constraints of this query against a class schema.
+    ///
+    /// Returns (and logs as warnings) a message for every pointer whose `className` differs from
+    /// the `targetClass` the schema declares for that field. Such a query is valid but can never
+    /// match, which usually means the wrong class was supplied. Pass a schema you already hold
+    /// (e.g. from [`Parse::get_class_schema`](crate::Parse::get_class_schema)) to avoid an extra round-trip.
+    pub fn check_pointer_classes(&self, schema: &ParseSchema) -> Vec<String> {
+        fn pointer_classes(value: &Value) -> Vec<&str> {
+            match value {
+                Value::Object(map) if map.get("__type") == Some(&json!("Pointer")) => map
+                    .get("className")
+                    .and_then(Value::as_str)
+                    .into_iter()
+                    .collect(),
+                Value::Object(map) => map.values().flat_map(pointer_classes).collect(),
+                Value::Array(items) => items.iter().flat_map(pointer_classes).collect(),
+                _ => Vec::new(),
+            }
+        }
+
+        let mut warnings = Vec::new();
+        for (key, condition) in &self.conditions {
+            let Some(target_class) = schema
+                .fields
+                .get(key)
+                .and_then(|field| field.target_class.as_deref())
+            else {
+                continue;
+            };
+            for class_name in pointer_classes(condition) {
+                if class_name != target_class {
+                    let warning = format!(
+                        "Query on '{}' compares field '{}' to a pointer of class '{}', but the schema targets '{}'",
+                        self.class_name, key, class_name, target_class
+                    );
+                    log::warn!("{}", warning);
+                    warnings.push(warning);
+                }
+            }
+        }
+        warnings
+    }
+
     /// Adds a constraint to the query that a field must not be equal to a specified value.
     pub fn not_equal_to<V: Serialize>(&mut self, key: &str, value: V) -> &mut Self {
         match serde_json::to_value(value) {
@@ -91,16 +718,94 @@ impl ParseQuery {
     }
 
     /// Adds a constraint to the query that a field must exist.
+    ///
+    /// A field explicitly set to `null` exists, so it is matched too. Use
+    /// [`not_equal_to`](Self::not_equal_to) with `Value::Null` to also exclude `null` values.
     pub fn exists(&mut self, key: &str) -> &mut Self {
         self.add_operator_condition(key, "$exists", serde_json::Value::Bool(true))
     }
 
-    /// Adds a constraint to the query that a field must not exist.
+    /// Adds a constraint to the query that a field must not exist, i.e. was never set on the
+    /// object (or was removed with a `Delete` operation).
+    ///
+    /// Objects where the field is explicitly `null` are *not* matched; use
+    /// [`equal_to_null`](Self::equal_to_null) for those. Note that `equal_to(key, Value::Null)`
+    /// matches both cases, following MongoDB semantics.
     pub fn does_not_exist(&mut self, key: &str) -> &mut Self {
         self.add_operator_condition(key, "$exists", serde_json::Value::Bool(false))
     }
 
+    /// Adds a constraint to the query that a field must be explicitly set to `null`.
+    ///
+    /// Objects where the field is missing are *not* matched; use
+    /// [`does_not_exist`](Self::does_not_exist) for those. This produces
+    /// `{"key": {"$eq": null, "$exists": true}}`, whereas a plain `equal_to(key, Value::Null)`
+    /// matches both explicit `null` and missing fields.
+    ///
+    /// The distinction relies on MongoDB storing `null` and missing fields differently;
+    /// Postgres-backed servers store a missing field as `NULL` and cannot tell the two apart.
+    pub fn equal_to_null(&mut self, key: &str) -> &mut Self {
+        self.add_operator_condition(key, "$eq", Value::Null)
+            .add_operator_condition(key, "$exists", Value::Bool(true))
+    }
+
+    /// Adds a constraint that a pointer field must be set, i.e. point to an object.
+    ///
+    /// Unlike [`exists`](Self::exists), a pointer explicitly cleared to `null` does not count as
+    /// set. This produces `{"key": {"$exists": true, "$ne": null}}`.
+    pub fn pointer_exists(&mut self, key: &str) -> &mut Self {
+        self.add_operator_condition(key, "$exists", Value::Bool(true))
+            .add_operator_condition(key, "$ne", Value::Null)
+    }
+
+    /// Adds a constraint that a pointer field must be unset, e.g. to find comments without an
+    /// assigned moderator.
+    ///
+    /// Matches objects where the pointer was never set or removed as well as objects where it
+    /// was set to `null`, which [`does_not_exist`](Self::does_not_exist) would miss. This
+    /// produces `{"key": {"$eq": null}}`.
+    pub fn pointer_does_not_exist(&mut self, key: &str) -> &mut Self {
+        self.add_operator_condition(key, "$eq", Value::Null)
+    }
+
+    /// Adds a constraint for finding objects whose GeoPoint field lies inside the polygon with
+    /// the given vertices. This produces `{"key": {"$geoWithin": {"$polygon": [...]}}}`.
+    ///
+    /// The polygon is closed automatically by the server, so the first point does not need to be
+    /// repeated at the end. Returns `ParseError::InvalidInput` without modifying the query if
+    /// fewer than 3 points are given.
+    pub fn within_polygon(
+        &mut self,
+        key: &str,
+        points: Vec<ParseGeoPoint>,
+    ) -> Result<&mut Self, ParseError> {
+        if points.len() < 3 {
+            return Err(ParseError::InvalidInput(format!(
+                "A polygon needs at least 3 points, got {}",
+                points.len()
+            )));
+        }
+        let points = serde_json::to_value(points)?;
+        Ok(self.add_operator_condition(key, "$geoWithin", json!({ "$polygon": points })))
+    }
+
+    /// Adds a constraint for finding objects whose Polygon field contains `point`, e.g. to find
+    /// which delivery zone a location falls in. This is the inverse of
+    /// [`within_polygon`](Self::within_polygon) and produces
+    /// `{"key": {"$geoIntersects": {"$point": {...}}}}`.
+    pub fn polygon_contains(&mut self, key: &str, point: ParseGeoPoint) -> &mut Self {
+        match serde_json::to_value(point) {
+            Ok(json_val) => {
+                self.add_operator_condition(key, "$geoIntersects", json!({ "$point": json_val }))
+            }
+            Err(_) => self,
+        }
+    }
+
     /// Adds a constraint for finding objects where a field's value is greater than the provided value.
+    ///
+    /// Range operators on the same key accumulate, so `greater_than("age", 18)` followed by
+    /// `less_than("age", 65)` produces `{"age": {"$gt": 18, "$lt": 65}}`.
     pub fn greater_than<V: Serialize>(&mut self, key: &str, value: V) -> &mut Self {
         match serde_json::to_value(value) {
             Ok(json_val) => self.add_operator_condition(key, "$gt", json_val),
@@ -116,6 +821,23 @@ impl ParseQuery {
         }
     }
 
+    /// Adds a constraint for finding objects updated within `duration` of now, i.e. whose
+    /// `updatedAt` is at or after `now - duration`.
+    ///
+    /// The cutoff is computed once, from the local clock, when this is called: reusing the query
+    /// later keeps the same cutoff, and if the local clock is skewed from the server's, the window
+    /// is shifted by the same amount. Allow some slack in `duration` if that matters.
+    pub fn updated_within(&mut self, duration: Duration) -> &mut Self {
+        self.add_operator_condition("updatedAt", "$gte", date_before_now(duration))
+    }
+
+    /// Adds a constraint for finding objects created within `duration` of now, i.e. whose
+    /// `createdAt` is at or after `now - duration`. The same clock caveats as for
+    /// [`updated_within`](Self::updated_within) apply.
+    pub fn created_within(&mut self, duration: Duration) -> &mut Self {
+        self.add_operator_condition("createdAt", "$gte", date_before_now(duration))
+    }
+
     /// Adds a constraint for finding objects where a field's value is less than the provided value.
     pub fn less_than<V: Serialize>(&mut self, key: &str, value: V) -> &mut Self {
         match serde_json::to_value(value) {
@@ -156,6 +878,19 @@ impl ParseQuery {
         }
     }
 
+    /// Adds a constraint for finding objects where every element of an array field is one of the
+    /// provided values (`$containedBy`), the inverse of [`contains_all`](Self::contains_all).
+    ///
+    /// For example, with allowed tags `["news", "sports"]`, objects tagged `["news"]` or
+    /// `["news", "sports"]` match, but `["news", "politics"]` does not. Objects whose array is
+    /// empty also match.
+    pub fn contained_by<V: Serialize>(&mut self, key: &str, values: Vec<V>) -> &mut Self {
+        match serde_json::to_value(values) {
+            Ok(json_val_array) => self.add_operator_condition(key, "$containedBy", json_val_array),
+            Err(_) => self,
+        }
+    }
+
     /// Adds a constraint for finding objects where a string field starts with a given prefix.
     pub fn starts_with(&mut self, key: &str, prefix: &str) -> &mut Self {
         self.add_operator_condition(
@@ -186,11 +921,27 @@ impl ParseQuery {
 
     /// Adds a constraint for finding objects where a string field matches a given regex pattern.
     /// Modifiers can be 'i' for case-insensitive, 'm' for multiline, etc.
+    ///
+    /// The pattern is checked client-side, and `ParseError::InvalidInput` is returned without
+    /// modifying the query if it is malformed (an unclosed or unopened group or character class,
+    /// an inverted range such as `[z-a]`, or a trailing backslash). Features the server's regex
+    /// engine supports but Rust's does not, such as look-arounds and backreferences, are passed
+    /// through unchecked.
     pub fn matches_regex(
         &mut self,
         key: &str,
         regex_pattern: &str,
         modifiers: Option<&str>,
+    ) -> Result<&mut Self, ParseError> {
+        validate_regex(regex_pattern, modifiers)?;
+        Ok(self.insert_regex(key, regex_pattern, modifiers))
+    }
+
+    fn insert_regex(
+        &mut self,
+        key: &str,
+        regex_pattern: &str,
+        modifiers: Option<&str>,
     ) -> &mut Self {
         let mut regex_map = Map::new();
         regex_map.insert(
@@ -205,6 +956,18 @@ impl ParseQuery {
         self
     }
 
+    /// Adds a constraint for finding objects where a string field equals `value`, ignoring case.
+    ///
+    /// The value is escaped and anchored at both ends (`^value$` with the `i` option), so only
+    /// exact matches are returned and the server never falls back to a substring scan.
+    /// Note that MongoDB can only use a regular index efficiently for case-sensitive, prefix-anchored
+    /// regexes; for frequently-run case-insensitive lookups on large classes, consider storing a
+    /// lowercased copy of the field and querying it with `equal_to` instead.
+    pub fn equals_ignore_case_anchored(&mut self, key: &str, value: &str) -> &mut Self {
+        let pattern = format!("^{}$", regex::escape(value));
+        self.insert_regex(key, &pattern, Some("i"))
+    }
+
     /// Adds a constraint for full-text search on a field.
     /// Requires a text index to be configured on the field in MongoDB.
     ///
@@ -283,7 +1046,8 @@ impl ParseQuery {
 
     // --- Pagination and Sorting ---
 
-    /// Sets the maximum number of results to return.
+    /// Sets the maximum number of results to return. The value is not checked; see
+    /// [`with_limit_and_skip`](Self::with_limit_and_skip) for a validating variant.
     pub fn limit(&mut self, count: isize) -> &mut Self {
         self.limit = Some(count);
         self
@@ -341,6 +1105,49 @@ impl ParseQuery {
         self
     }
 
+    /// Sorts the results by creation time (`createdAt`). Replaces existing sort order.
+    ///
+    /// Ordering by a reserved timestamp gives a stable order for paginating with `skip`.
+    pub fn order_by_created_at(&mut self, descending: bool) -> &mut Self {
+        self.order = None;
+        self.append_order_key("createdAt", descending);
+        self
+    }
+
+    /// Sorts the results by last modification time (`updatedAt`). Replaces existing sort order.
+    pub fn order_by_updated_at(&mut self, descending: bool) -> &mut Self {
+        self.order = None;
+        self.append_order_key("updatedAt", descending);
+        self
+    }
+
+    /// Adds creation time (`createdAt`) as a sort key. Appends to existing sort order.
+    pub fn add_created_at_order(&mut self, descending: bool) -> &mut Self {
+        self.append_order_key("createdAt", descending);
+        self
+    }
+
+    /// Adds last modification time (`updatedAt`) as a sort key. Appends to existing sort order.
+    pub fn add_updated_at_order(&mut self, descending: bool) -> &mut Self {
+        self.append_order_key("updatedAt", descending);
+        self
+    }
+
+    /// Sets the order of the results from typed sort keys. Replaces any existing order.
+    ///
+    /// `order_by(&[Sort::desc("score"), Sort::asc("name")])` is equivalent to `order("-score,name")`.
+    /// Keys with an empty field name are ignored.
+    pub fn order_by(&mut self, sorts: &[Sort]) -> &mut Self {
+        let order = sorts
+            .iter()
+            .filter(|sort| !sort.field.trim().is_empty())
+            .map(|sort| sort.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        self.order = if order.is_empty() { None } else { Some(order) };
+        self
+    }
+
     /// Includes nested ParseObjects for the given pointer key(s).
     /// The included field's data will be fetched and returned with the main object.
     pub fn include(&mut self, keys_to_include: &[&str]) -> &mut Self {
@@ -368,14 +1175,78 @@ impl ParseQuery {
         self
     }
 
-    // --- Execution Methods ---
+    /// Excludes the given fields from all matching objects, returning every other field.
+    ///
+    /// Useful for dropping heavy fields (e.g. a large blob or geo data) from list views.
+    /// If `select` is also used, Parse Server applies `keys` first and then removes the
+    /// excluded fields from that selection.
+    pub fn exclude_keys(&mut self, keys_to_exclude: &[&str]) -> &mut Self {
+        let current_excluded = self.exclude_keys.take().unwrap_or_default();
+        let mut exclude_parts: Vec<&str> = current_excluded
+            .split(',')
+            .filter(|s| !s.is_empty())
+            .collect();
+        exclude_parts.extend(keys_to_exclude.iter().cloned());
+        exclude_parts.sort_unstable();
+        exclude_parts.dedup();
+        self.exclude_keys = Some(exclude_parts.join(","));
+        self
+    }
 
-    // Internal helper to build query parameters for reqwest
-    pub fn build_query_params(&self) -> Vec<(String, String)> {
-        let mut params = Vec::new();
-        if !self.conditions.is_empty() {
-            if let Ok(where_json) = serde_json::to_string(&self.conditions) {
-                params.push(("where".to_string(), where_json));
+    // --- Serialization ---
+
+    /// Serializes the query to JSON, e.g. to save a search or hand it to another service.
+    ///
+    /// The result holds the class name and, when set, the `where` conditions, `limit`, `skip`,
+    /// `order`, `include`, `keys` and `excludeKeys`, using the names of the REST API parameters.
+    /// The master-key flag is deliberately left out, so a query received from elsewhere never
+    /// runs with elevated privileges unless the receiver opts in with
+    /// [`set_master_key`](Self::set_master_key). The `force_post` setting is not included either.
+    pub fn to_json(&self) -> Value {
+        let serialized = SerializedQuery {
+            class_name: self.class_name.clone(),
+            conditions: self.conditions.clone(),
+            limit: self.limit,
+            skip: self.skip,
+            order: self.order.clone(),
+            include: self.include.clone(),
+            keys: self.keys.clone(),
+            exclude_keys: self.exclude_keys.clone(),
+        };
+        serde_json::to_value(serialized).unwrap_or(Value::Null)
+    }
+
+    /// Rebuilds a query from the JSON produced by [`to_json`](Self::to_json).
+    ///
+    /// The query does not use the master key. Returns `ParseError::InvalidInput` if `json` does
+    /// not have that shape.
+    pub fn from_json(json: Value) -> Result<Self, ParseError> {
+        let serialized: SerializedQuery = serde_json::from_value(json)
+            .map_err(|e| ParseError::InvalidInput(format!("Invalid serialized query: {}", e)))?;
+        Ok(Self {
+            conditions: serialized.conditions,
+            limit: serialized.limit,
+            skip: serialized.skip,
+            order: serialized.order,
+            include: serialized.include,
+            keys: serialized.keys,
+            exclude_keys: serialized.exclude_keys,
+            ..Self::new(&serialized.class_name)
+        })
+    }
+
+    // --- Execution Methods ---
+
+    /// Builds the URL parameters (`where`, `limit`, `skip`, `order`, ...) for this query.
+    ///
+    /// Parameters are sorted by name, and unset or empty ones are left out (a query without
+    /// constraints has no `where`), so the same query always produces the same URL.
+    pub fn build_query_params(&self) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+        if !self.conditions.is_empty() {
+            // `conditions` is ordered by key, so the serialized `where` is stable as well.
+            if let Ok(where_json) = serde_json::to_string(&self.conditions) {
+                params.push(("where".to_string(), where_json));
             }
         }
         if let Some(limit_val) = self.limit {
@@ -384,28 +1255,41 @@ impl ParseQuery {
         if let Some(skip_val) = self.skip {
             params.push(("skip".to_string(), skip_val.to_string()));
         }
-        if let Some(order_val) = &self.order {
-            params.push(("order".to_string(), order_val.clone()));
-        }
-        if let Some(include_val) = &self.include {
-            params.push(("include".to_string(), include_val.clone()));
-        }
-        if let Some(keys_val) = &self.keys {
-            params.push(("keys".to_string(), keys_val.clone()));
+        let text_params = [
+            ("order", &self.order),
+            ("include", &self.include),
+            ("keys", &self.keys),
+            ("excludeKeys", &self.exclude_keys),
+        ];
+        for (name, value) in text_params {
+            if let Some(value) = value.as_deref().filter(|v| !v.is_empty()) {
+                params.push((name.to_string(), value.to_string()));
+            }
         }
+        params.sort_by(|a, b| a.0.cmp(&b.0));
         params
     }
 
     async fn find_raw<T: DeserializeOwned + Send + Sync + 'static>(
         &self,
         client: &Parse,
-    ) -> Result<FindResponse<T>, ParseError> {
+    ) -> Result<Vec<T>, ParseError> {
         let endpoint = format!("classes/{}", self.class_name);
         let params = self.build_query_params();
-        let response_wrapper: FindResponse<T> = client
-            ._get_with_url_params(&endpoint, &params, self.use_master_key, None)
+        let response_wrapper: FindResponse<Value> = client
+            ._get_or_post_with_url_params(
+                &endpoint,
+                &params,
+                self.use_master_key,
+                None,
+                self.force_post,
+            )
             .await?;
-        Ok(response_wrapper)
+        response_wrapper
+            .results
+            .into_iter()
+            .map(|object| client.decode_object(&self.class_name, object))
+            .collect()
     }
 
     async fn first_raw<T: DeserializeOwned + Send + Sync + 'static>(
@@ -416,10 +1300,21 @@ impl ParseQuery {
         query_clone.limit(1);
         let endpoint = format!("classes/{}", query_clone.class_name);
         let params = query_clone.build_query_params();
-        let response_wrapper: FindResponse<T> = client
-            ._get_with_url_params(&endpoint, &params, self.use_master_key, None)
+        let response_wrapper: FindResponse<Value> = client
+            ._get_or_post_with_url_params(
+                &endpoint,
+                &params,
+                self.use_master_key,
+                None,
+                self.force_post,
+            )
             .await?;
-        Ok(response_wrapper.results.into_iter().next())
+        response_wrapper
+            .results
+            .into_iter()
+            .next()
+            .map(|object| client.decode_object(&self.class_name, object))
+            .transpose()
     }
 
     /// Retrieves a list of `ParseObject`s that match this query.
@@ -427,8 +1322,185 @@ impl ParseQuery {
         &self,
         client: &Parse,
     ) -> Result<Vec<T>, ParseError> {
-        let response_wrapper = self.find_raw(client).await?;
-        Ok(response_wrapper.results)
+        self.find_raw(client).await
+    }
+
+    /// Retrieves every object that matches this query, paging past the server's per-request limit.
+    ///
+    /// Equivalent to [`find_all_with_cap`](Self::find_all_with_cap) with
+    /// [`DEFAULT_FIND_ALL_CAP`] (10,000 objects).
+    pub async fn find_all<T: DeserializeOwned + Send + Sync + 'static>(
+        &self,
+        client: &Parse,
+    ) -> Result<Vec<T>, ParseError> {
+        self.find_all_with_cap(client, DEFAULT_FIND_ALL_CAP).await
+    }
+
+    /// Retrieves every object that matches this query, up to `cap` objects.
+    ///
+    /// A plain [`find`](Self::find) returns at most one page (100 objects unless `limit` is set,
+    /// and never more than the server's `maxLimit`). This method instead requests pages of up to
+    /// 1,000 objects, advancing `skip` by the number of objects received, until a page comes
+    /// back empty; pages cut short by the server's `maxLimit` are followed up. `objectId` is
+    /// added as the last sort key so that pages do not overlap or miss objects; any order
+    /// set on the query still applies first. If `limit` is set it bounds the total number of
+    /// objects returned, and `skip` sets the starting offset.
+    ///
+    /// The cap guards against unexpectedly large result sets being loaded into memory: if more
+    /// than `cap` objects match, no results are returned and the call fails with
+    /// `ParseError::TooManyResults(cap)`. Pass a larger `cap` to fetch bigger sets, or page
+    /// manually with `limit`/`skip` to process them incrementally.
+    pub async fn find_all_with_cap<T: DeserializeOwned + Send + Sync + 'static>(
+        &self,
+        client: &Parse,
+        cap: usize,
+    ) -> Result<Vec<T>, ParseError> {
+        const PAGE_SIZE: usize = 1000;
+
+        let mut page_query = self.clone();
+        let has_object_id_key = self.order.as_deref().is_some_and(|order| {
+            order
+                .split(',')
+                .any(|key| key.trim_start_matches('-') == "objectId")
+        });
+        if !has_object_id_key {
+            page_query.append_order_key("objectId", false);
+        }
+        let mut remaining = self
+            .limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+        let mut skip = self.skip.unwrap_or(0);
+
+        let mut results = Vec::new();
+        while remaining > 0 {
+            // Ask for one object past the cap, so exceeding it is detected without fetching more.
+            let page_size = PAGE_SIZE
+                .min(remaining)
+                .min(cap.saturating_add(1) - results.len());
+            page_query.limit(page_size as isize).skip(skip);
+            let page: Vec<T> = page_query.find(client).await?;
+            let page_len = page.len();
+            results.extend(page);
+            if results.len() > cap {
+                return Err(ParseError::TooManyResults(cap));
+            }
+            // A page may be shorter than requested when the server's `maxLimit` is lower, so
+            // only an empty page means the matches are exhausted.
+            if page_len == 0 {
+                break;
+            }
+            remaining = remaining.saturating_sub(page_len);
+            skip += page_len;
+        }
+        Ok(results)
+    }
+
+    /// Retrieves only the `objectId`s of the objects that match this query.
+    ///
+    /// Any fields selected via `select` are replaced with `objectId`, keeping the response
+    /// as small as possible. Useful for existence checks or feeding ids into bulk operations.
+    pub async fn find_ids(&self, client: &Parse) -> Result<Vec<String>, ParseError> {
+        #[derive(Deserialize)]
+        struct IdOnly {
+            #[serde(rename = "objectId")]
+            object_id: String,
+        }
+
+        let mut query_clone = self.clone();
+        query_clone.keys = Some("objectId".to_string());
+        let results: Vec<IdOnly> = query_clone.find_raw(client).await?;
+        Ok(results.into_iter().map(|item| item.object_id).collect())
+    }
+
+    /// Applies the same update to every object matching this query.
+    ///
+    /// Equivalent to [`update_all_with_progress`](Self::update_all_with_progress) without a
+    /// progress callback. Returns the number of objects updated.
+    pub async fn update_all<P: Serialize + Send + Sync>(
+        &self,
+        client: &Parse,
+        patch: &P,
+    ) -> Result<u64, ParseError> {
+        self.update_all_with_progress(client, patch, |_, _| {})
+            .await
+    }
+
+    /// Applies the same update to every object matching this query, reporting progress.
+    ///
+    /// The ids of all matching objects are collected first (honoring `limit`/`skip` if set,
+    /// otherwise paging through every match), so updates that change the queried fields do not
+    /// affect which objects are visited. The objects are then updated through the `/batch`
+    /// endpoint in chunks of 50, and `progress(updated, total)` is called after each chunk with
+    /// the number of objects updated so far. The query's master-key setting applies to both steps.
+    ///
+    /// Stops after the first chunk with a failed update and returns the first error of that
+    /// chunk. Objects updated before, including the other objects of that chunk, stay updated
+    /// and are included in the last progress report. Returns the number of objects updated.
+    pub async fn update_all_with_progress<P, F>(
+        &self,
+        client: &Parse,
+        patch: &P,
+        mut progress: F,
+    ) -> Result<u64, ParseError>
+    where
+        P: Serialize + Send + Sync,
+        F: FnMut(u64, u64),
+    {
+        const ID_PAGE_SIZE: usize = 1000;
+
+        let object_ids = if self.limit.is_some() {
+            self.find_ids(client).await?
+        } else {
+            let mut object_ids = Vec::new();
+            let mut page_query = self.clone();
+            page_query.order("objectId").limit(ID_PAGE_SIZE as isize);
+            let mut skip = self.skip.unwrap_or(0);
+            loop {
+                page_query.skip(skip);
+                let page = page_query.find_ids(client).await?;
+                // Pages may be cut short by the server's `maxLimit`; only an empty one is the end.
+                if page.is_empty() {
+                    break;
+                }
+                skip += page.len();
+                object_ids.extend(page);
+            }
+            object_ids
+        };
+
+        let patch_value = serde_json::to_value(patch)?;
+        let total = object_ids.len() as u64;
+        let mut updated: u64 = 0;
+        for chunk in object_ids.chunks(MAX_BATCH_SIZE) {
+            let mut batch = ParseBatch::new();
+            for object_id in chunk {
+                batch.add(BatchOp::Update {
+                    class: self.class_name.clone(),
+                    object_id: object_id.clone(),
+                    fields: patch_value.clone(),
+                });
+            }
+            let results = batch
+                .execute_with_master_key(client, self.use_master_key)
+                .await?;
+            // Sub-requests are applied independently, so the ones that succeeded count even if
+            // another one in the chunk failed.
+            let mut first_error = None;
+            for result in results {
+                match result {
+                    Ok(_) => updated += 1,
+                    Err(e) => {
+                        first_error.get_or_insert(e);
+                    }
+                }
+            }
+            progress(updated, total);
+            if let Some(e) = first_error {
+                return Err(e);
+            }
+        }
+        Ok(updated)
     }
 
     /// Retrieves the first `ParseObject` that matches this query.
@@ -445,25 +1517,126 @@ impl ParseQuery {
         &self,
         object_id: &str,
         client: &Parse,
+    ) -> Result<T, ParseError> {
+        if !client.decrypts_fields() {
+            return self.get_raw(object_id, client).await;
+        }
+        let object: Value = self.get_raw(object_id, client).await?;
+        client.decode_object(&self.class_name, object)
+    }
+
+    async fn get_raw<T: DeserializeOwned + Send + Sync + 'static>(
+        &self,
+        object_id: &str,
+        client: &Parse,
     ) -> Result<T, ParseError> {
         let endpoint = format!("classes/{}/{}", self.class_name, object_id);
         let params = self.build_query_params();
         client
-            ._get_with_url_params(&endpoint, &params, self.use_master_key, None)
+            ._get_or_post_with_url_params(
+                &endpoint,
+                &params,
+                self.use_master_key,
+                None,
+                self.force_post,
+            )
             .await
     }
 
+    /// Retrieves one page of results together with the total number of matching objects.
+    ///
+    /// The page is selected by the query's `limit` and `skip`, and the total is requested in the
+    /// same round trip (`count=1`). Use [`PaginatedResults::next_query`] to fetch the next page.
+    pub async fn find_with_count<T: DeserializeOwned + Send + Sync + 'static>(
+        &self,
+        client: &Parse,
+    ) -> Result<PaginatedResults<T>, ParseError> {
+        let endpoint = format!("classes/{}", self.class_name);
+        let mut params = self.build_query_params();
+        params.push(("count".to_string(), "1".to_string()));
+
+        let response: Results<Value> = client
+            ._get_or_post_with_url_params(
+                &endpoint,
+                &params,
+                self.use_master_key,
+                None,
+                self.force_post,
+            )
+            .await?;
+        let results = response
+            .results
+            .into_iter()
+            .map(|object| client.decode_object(&self.class_name, object))
+            .collect::<Result<_, _>>()?;
+        Ok(PaginatedResults::from_results(
+            Results {
+                results,
+                count: response.count,
+            },
+            self,
+        ))
+    }
+
     /// Counts the number of objects that match this query.
+    ///
+    /// Only the query's constraints are sent, together with `limit=0` so that the server returns
+    /// the count without any objects. `limit`, `skip`, `order`, `include` and key selections set
+    /// on the query are ignored.
     pub async fn count(&self, client: &Parse) -> Result<u64, ParseError> {
-        let mut query_clone = self.clone();
-        query_clone.limit(0); // Limit 0 is for count
+        self.fetch_count(client, &self.count_params()).await
+    }
 
-        let endpoint = format!("classes/{}", query_clone.class_name);
-        let mut params = query_clone.build_query_params();
+    /// Returns an estimate of the number of objects in the whole class.
+    ///
+    /// Exact counts ([`count`](Self::count)) scan the matching documents, which is slow on very
+    /// large collections. For an unconstrained count, Parse Server's MongoDB adapter instead uses
+    /// `estimatedDocumentCount`, which reads the collection's metadata: it is fast but may be
+    /// inaccurate, e.g. after an unclean shutdown or while documents are being written. The
+    /// estimate is only used when the request bypasses ACLs, so run the query with the master key
+    /// (see [`set_master_key`](Self::set_master_key)); otherwise the server adds read-permission
+    /// constraints and returns an exact count of the readable objects. Other database adapters
+    /// always return exact counts.
+    ///
+    /// Returns `ParseError::InvalidQuery` if the query has constraints, as filtered counts cannot
+    /// be estimated.
+    pub async fn estimated_count(&self, client: &Parse) -> Result<u64, ParseError> {
+        if !self.conditions.is_empty() {
+            return Err(ParseError::InvalidQuery(
+                "Estimated counts cover the whole class; use count() for a query with constraints"
+                    .to_string(),
+            ));
+        }
+        self.fetch_count(client, &self.count_params()).await
+    }
+
+    // The parameters of a count request: the constraints, `limit=0` and `count=1`.
+    fn count_params(&self) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+        if !self.conditions.is_empty() {
+            if let Ok(where_json) = serde_json::to_string(&self.conditions) {
+                params.push(("where".to_string(), where_json));
+            }
+        }
+        params.push(("limit".to_string(), "0".to_string()));
         params.push(("count".to_string(), "1".to_string()));
+        params
+    }
 
+    async fn fetch_count(
+        &self,
+        client: &Parse,
+        params: &[(String, String)],
+    ) -> Result<u64, ParseError> {
+        let endpoint = format!("classes/{}", self.class_name);
         let response_wrapper: CountResponse = client
-            ._get_with_url_params(&endpoint, &params, self.use_master_key, None)
+            ._get_or_post_with_url_params(
+                &endpoint,
+                params,
+                self.use_master_key,
+                None,
+                self.force_post,
+            )
             .await?;
         Ok(response_wrapper.count)
     }
@@ -476,27 +1649,14 @@ impl ParseQuery {
         field: &str,
     ) -> Result<Vec<T>, ParseError> {
         let endpoint = format!("aggregate/{}", self.class_name);
-
-        let mut pipeline: Vec<Value> = Vec::new();
-
-        // Add $match stage if there are 'where' conditions
-        if !self.conditions.is_empty() {
-            pipeline.push(json!({
-                "$match": self.conditions
-            }));
-        }
-
-        // Add $group stage for distinct operation
-        pipeline.push(json!({
-            "$group": { "_id": format!("${}", field) } // Use "_id" as the output field name
-        }));
+        let pipeline = self.group_by_pipeline(field);
 
         // Serialize the pipeline
         let pipeline_json = serde_json::to_string(&pipeline).map_err(|e| {
-            ParseError::SerializationError(format!(
-                "Failed to serialize pipeline for distinct query: {}",
-                e
-            ))
+            ParseError::SerializationError(
+                format!("Failed to serialize pipeline for distinct query: {}", e),
+                e,
+            )
         })?;
 
         let params = vec![("pipeline".to_string(), pipeline_json)];
@@ -523,6 +1683,91 @@ impl ParseQuery {
         Ok(distinct_values)
     }
 
+    /// Returns the number of unique values of `field` among the objects matching the query.
+    ///
+    /// This runs the same aggregation as [`distinct`](Self::distinct), followed by a `$count`
+    /// stage, so the server returns only the count instead of every value. Objects without the
+    /// field count as one `null` value, if any. Like `distinct`, it uses the Master Key.
+    pub async fn count_distinct(&self, client: &Parse, field: &str) -> Result<u64, ParseError> {
+        #[derive(serde::Deserialize, Debug)]
+        struct CountItem {
+            count: u64,
+        }
+
+        let mut pipeline = self.group_by_pipeline(field);
+        pipeline.push(json!({ "$count": "count" }));
+        let results: Vec<CountItem> = client
+            .execute_aggregate(&self.class_name, Value::Array(pipeline))
+            .await?;
+        // `$count` produces no document at all when nothing matches.
+        Ok(results.first().map_or(0, |item| item.count))
+    }
+
+    // A `$match` stage for the query's conditions, if any, then a `$group` on `field`.
+    fn group_by_pipeline(&self, field: &str) -> Vec<Value> {
+        let mut pipeline: Vec<Value> = Vec::new();
+
+        // Add $match stage if there are 'where' conditions
+        if !self.conditions.is_empty() {
+            pipeline.push(json!({
+                "$match": self.conditions
+            }));
+        }
+
+        // Add $group stage for distinct operation
+        pipeline.push(json!({
+            "$group": { "_id": format!("${}", field) } // Use "_id" as the output field name
+        }));
+        pipeline
+    }
+
+    /// Returns the value of `field` for every matching object, duplicates included (unlike
+    /// [`distinct`](Self::distinct)).
+    ///
+    /// Only `field` is selected, so the server does not send the rest of each object. The
+    /// query's constraints, `limit`, `skip` and `order` apply as for [`find`](Self::find), and
+    /// the values are returned in result order. `field` may be a dotted path into an object
+    /// field, e.g. `"stats.score"`. An object without the field yields `null`, so use an
+    /// `Option<T>` if the field is not always set.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use parse_rs::{Parse, ParseError, ParseQuery};
+    ///
+    /// # async fn run(client: &Parse) -> Result<(), ParseError> {
+    /// let mut query = ParseQuery::new("GameScore");
+    /// query.equal_to("playerName", "Sean").order("-score");
+    /// let scores: Vec<i64> = query.pluck(client, "score").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn pluck<T: DeserializeOwned + Send + Sync + 'static>(
+        &self,
+        client: &Parse,
+        field: &str,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut query = self.clone();
+        query.keys = Some(field.to_string());
+        query.exclude_keys = None;
+        let objects: Vec<Value> = query.find(client).await?;
+        objects
+            .iter()
+            .map(|object| {
+                let value = field
+                    .split('.')
+                    .try_fold(object, |value, key| value.get(key))
+                    .unwrap_or(&Value::Null);
+                T::deserialize(value).map_err(|e| {
+                    ParseError::JsonDeserializationFailed(
+                        format!("Value of '{}' has an unexpected shape: {}", field, e),
+                        e,
+                    )
+                })
+            })
+            .collect()
+    }
+
     /// Executes an aggregation query.
     ///
     /// The pipeline is a series of data aggregation steps. Refer to MongoDB aggregation pipeline documentation.
@@ -546,6 +1791,140 @@ impl ParseQuery {
     }
 }
 
+impl From<&ParseQuery> for ParseQuery {
+    fn from(query: &ParseQuery) -> Self {
+        query.clone()
+    }
+}
+
+impl From<&mut ParseQuery> for ParseQuery {
+    fn from(query: &mut ParseQuery) -> Self {
+        query.clone()
+    }
+}
+
+/// One page of query results, with the paging parameters that produced it.
+///
+/// Returned by [`ParseQuery::find_with_count`]. Iterating over it (by value or by reference)
+/// yields the objects on this page.
+#[derive(Debug, Clone)]
+pub struct PaginatedResults<T> {
+    /// The objects on this page.
+    pub results: Vec<T>,
+    /// The total number of objects matching the query, if the server reported it.
+    pub count: Option<u64>,
+    /// The number of matching objects skipped before this page.
+    pub skip: usize,
+    /// The page size requested, or `None` if the server default (100) applied.
+    pub limit: Option<usize>,
+    query: ParseQuery,
+}
+
+impl<T> PaginatedResults<T> {
+    // The page size Parse Server uses when a find request has no `limit`.
+    const DEFAULT_PAGE_SIZE: usize = 100;
+
+    /// Wraps a results response for the page of `query` it answers.
+    pub fn from_results(response: Results<T>, query: &ParseQuery) -> Self {
+        PaginatedResults {
+            results: response.results,
+            count: response.count.and_then(|count| u64::try_from(count).ok()),
+            skip: query.skip.unwrap_or(0),
+            limit: query.limit.and_then(|limit| usize::try_from(limit).ok()),
+            query: query.clone(),
+        }
+    }
+
+    /// Returns `true` if more matching objects follow this page.
+    ///
+    /// With a `count`, this compares it to the objects fetched so far. Without one, a full page
+    /// (as many results as the page size) is taken to mean more may follow.
+    pub fn has_more(&self) -> bool {
+        match self.count {
+            Some(count) => ((self.skip + self.results.len()) as u64) < count,
+            None => {
+                let page_size = self.limit.unwrap_or(Self::DEFAULT_PAGE_SIZE);
+                page_size > 0 && self.results.len() >= page_size
+            }
+        }
+    }
+
+    /// Returns the query for the page after this one, or `None` if there is no more data.
+    ///
+    /// The follow-up query has the same constraints, order and page size, with `skip` advanced
+    /// past this page.
+    pub fn next_query(&self) -> Option<ParseQuery> {
+        if !self.has_more() {
+            return None;
+        }
+        let mut next = self.query.clone();
+        next.skip(self.skip + self.results.len());
+        Some(next)
+    }
+
+    /// Returns an iterator over the objects on this page.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.results.iter()
+    }
+}
+
+impl<T> IntoIterator for PaginatedResults<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PaginatedResults<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.iter()
+    }
+}
+
+// Rejects regex patterns that are malformed in any dialect. Patterns are sent to the server's
+// (PCRE-style) engine, so look-arounds and backreferences, which `regex_syntax` does not
+// support, are replaced by plain groups and literals and the rest of the pattern is checked.
+// Other unsupported syntax ends the check without an error.
+fn validate_regex(pattern: &str, modifiers: Option<&str>) -> Result<(), ParseError> {
+    use regex_syntax::ast::{parse::ParserBuilder, ErrorKind};
+
+    let extended = modifiers.is_some_and(|mods| mods.contains('x'));
+    let mut checked = pattern.to_string();
+    loop {
+        let error = match ParserBuilder::new()
+            .ignore_whitespace(extended)
+            .build()
+            .parse(&checked)
+        {
+            Ok(_) => return Ok(()),
+            Err(error) => error,
+        };
+        let span = error.span();
+        let replacement = match error.kind() {
+            ErrorKind::UnsupportedLookAround => "(",
+            ErrorKind::UnsupportedBackreference => "x",
+            ErrorKind::ClassUnclosed
+            | ErrorKind::ClassRangeInvalid
+            | ErrorKind::GroupUnclosed
+            | ErrorKind::GroupUnopened
+            | ErrorKind::EscapeUnexpectedEof => {
+                return Err(ParseError::InvalidInput(format!(
+                    "Invalid regex pattern '{}': {}",
+                    pattern,
+                    error.kind()
+                )))
+            }
+            _ => return Ok(()),
+        };
+        checked.replace_range(span.start.offset..span.end.offset, replacement);
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct FindResponse<T> {
     results: Vec<T>,
@@ -558,5 +1937,636 @@ struct CountResponse {
 
 #[cfg(test)]
 mod tests {
-    // ... existing tests ...
+    use super::*;
+
+    fn param<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a str> {
+        params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn schema_with_pointer(field: &str, target_class: &str) -> ParseSchema {
+        serde_json::from_value(json!({
+            "className": "Comment",
+            "fields": {
+                field: { "type": "Pointer", "targetClass": target_class }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn where_clause(query: &ParseQuery) -> Value {
+        serde_json::from_str(param(&query.build_query_params(), "where").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_constraint_tree_compiles_nested_and_or() {
+        let constraint = Constraint::Or(vec![
+            Constraint::And(vec![
+                Constraint::eq("status", "active"),
+                Constraint::gte("score", 10),
+                Constraint::lt("score", 100),
+            ]),
+            Constraint::And(vec![
+                Constraint::contained_in("tier", ["gold", "platinum"]),
+                Constraint::Or(vec![
+                    Constraint::exists("referrer"),
+                    Constraint::regex("name", "^vip_", Some("i")),
+                ]),
+            ]),
+            Constraint::does_not_exist("deletedAt"),
+        ]);
+        assert_eq!(
+            constraint.to_where(),
+            json!({
+                "$or": [
+                    { "status": "active", "score": { "$gte": 10, "$lt": 100 } },
+                    {
+                        "tier": { "$in": ["gold", "platinum"] },
+                        "$or": [
+                            { "referrer": { "$exists": true } },
+                            { "name": { "$regex": "^vip_", "$options": "i" } }
+                        ]
+                    },
+                    { "deletedAt": { "$exists": false } }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_constraint_and_with_conflicting_keys_uses_and_operator() {
+        let constraint = Constraint::And(vec![
+            Constraint::Or(vec![Constraint::eq("a", 1), Constraint::eq("b", 2)]),
+            Constraint::Or(vec![Constraint::eq("c", 3), Constraint::not_eq("d", 4)]),
+        ]);
+        assert_eq!(
+            constraint.to_where(),
+            json!({
+                "$and": [
+                    { "$or": [{ "a": 1 }, { "b": 2 }] },
+                    { "$or": [{ "c": 3 }, { "d": { "$ne": 4 } }] }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_filter_combines_with_existing_conditions() {
+        let mut query = ParseQuery::new("GameScore");
+        query
+            .equal_to("playerName", "Sean")
+            .filter(Constraint::gt("score", 5));
+        assert_eq!(
+            where_clause(&query),
+            json!({ "playerName": "Sean", "score": { "$gt": 5 } })
+        );
+
+        query.filter(Constraint::eq("playerName", "Ann"));
+        assert_eq!(
+            where_clause(&query),
+            json!({
+                "$and": [
+                    { "playerName": "Sean", "score": { "$gt": 5 } },
+                    { "playerName": "Ann" }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_where_raw_merges_with_typed_constraints() {
+        let mut query = ParseQuery::new("GameScore");
+        query
+            .equal_to("playerName", "Sean")
+            .greater_than("score", 5);
+        query
+            .where_raw(json!({ "score": { "$mod": [5, 0] }, "level": { "$mod": [2, 1] } }))
+            .unwrap();
+        assert_eq!(
+            where_clause(&query),
+            json!({
+                "playerName": "Sean",
+                "score": { "$gt": 5, "$mod": [5, 0] },
+                "level": { "$mod": [2, 1] }
+            })
+        );
+
+        query.where_raw(json!({ "playerName": "Ann" })).unwrap();
+        assert_eq!(where_clause(&query)["playerName"], json!("Ann"));
+
+        assert!(matches!(
+            query.where_raw(json!([{ "score": 1 }])),
+            Err(ParseError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_exists_combines_with_other_operators() {
+        let mut query = ParseQuery::new("GameScore");
+        query.exists("score").greater_than("score", 5);
+        assert_eq!(
+            where_clause(&query),
+            json!({ "score": { "$exists": true, "$gt": 5 } })
+        );
+
+        let mut query = ParseQuery::new("GameScore");
+        query.not_equal_to("name", "x").does_not_exist("name");
+        assert_eq!(
+            where_clause(&query),
+            json!({ "name": { "$ne": "x", "$exists": false } })
+        );
+
+        // Repeating an operator replaces its value; an equality match is replaced outright.
+        let mut query = ParseQuery::new("GameScore");
+        query
+            .exists("score")
+            .does_not_exist("score")
+            .equal_to("name", "x")
+            .exists("name");
+        assert_eq!(
+            where_clause(&query),
+            json!({ "score": { "$exists": false }, "name": { "$exists": true } })
+        );
+    }
+
+    #[test]
+    fn test_range_operators_accumulate() {
+        let mut query = ParseQuery::new("Person");
+        query.greater_than("age", 18).less_than("age", 65);
+        let params = query.build_query_params();
+        assert_eq!(
+            param(&params, "where"),
+            Some(r#"{"age":{"$gt":18,"$lt":65}}"#)
+        );
+
+        let mut query = ParseQuery::new("Person");
+        query
+            .less_than_or_equal_to("age", 65)
+            .greater_than_or_equal_to("age", 18)
+            .not_equal_to("age", 30);
+        assert_eq!(
+            where_clause(&query),
+            json!({ "age": { "$gte": 18, "$lte": 65, "$ne": 30 } })
+        );
+
+        // Tightening a bound replaces it instead of adding a second one.
+        query.greater_than_or_equal_to("age", 21);
+        assert_eq!(where_clause(&query)["age"]["$gte"], json!(21));
+    }
+
+    #[test]
+    fn test_equal_to_pointer() {
+        let mut query = ParseQuery::new("Comment");
+        query.equal_to_pointer("post", &Pointer::new("Post", "abc123"));
+        let params = query.build_query_params();
+        let where_clause: Value = serde_json::from_str(param(&params, "where").unwrap()).unwrap();
+        assert_eq!(
+            where_clause,
+            json!({ "post": { "__type": "Pointer", "className": "Post", "objectId": "abc123" } })
+        );
+    }
+
+    #[test]
+    fn test_check_pointer_classes_warns_on_mismatch() {
+        let schema = schema_with_pointer("post", "Post");
+
+        let mut query = ParseQuery::new("Comment");
+        query.equal_to_pointer("post", &Pointer::new("Article", "abc123"));
+        let warnings = query.check_pointer_classes(&schema);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("'Article'"));
+        assert!(warnings[0].contains("'Post'"));
+
+        let mut matching = ParseQuery::new("Comment");
+        matching.equal_to_pointer("post", &Pointer::new("Post", "abc123"));
+        assert!(matching.check_pointer_classes(&schema).is_empty());
+    }
+
+    #[test]
+    fn test_exclude_keys_param() {
+        let mut query = ParseQuery::new("GameScore");
+        assert_eq!(param(&query.build_query_params(), "excludeKeys"), None);
+
+        query.exclude_keys(&["blob", "geoData"]);
+        query.exclude_keys(&["geoData", "avatar"]);
+        let params = query.build_query_params();
+        assert_eq!(param(&params, "excludeKeys"), Some("avatar,blob,geoData"));
+        assert_eq!(param(&params, "keys"), None);
+    }
+
+    #[test]
+    fn test_exclude_keys_with_select() {
+        let mut query = ParseQuery::new("GameScore");
+        query.select(&["score", "blob"]).exclude_keys(&["blob"]);
+        let params = query.build_query_params();
+        assert_eq!(param(&params, "keys"), Some("blob,score"));
+        assert_eq!(param(&params, "excludeKeys"), Some("blob"));
+    }
+
+    #[test]
+    fn test_equals_ignore_case_anchored() {
+        let mut query = ParseQuery::new("GameScore");
+        query.equals_ignore_case_anchored("player_name", "a.b");
+        let params = query.build_query_params();
+        let where_clause: Value = serde_json::from_str(param(&params, "where").unwrap()).unwrap();
+        assert_eq!(
+            where_clause,
+            json!({ "player_name": { "$regex": "^a\\.b$", "$options": "i" } })
+        );
+    }
+
+    fn class_and_params(query: impl Into<ParseQuery>) -> (String, Vec<(String, String)>) {
+        let query = query.into();
+        (query.class_name().to_string(), query.build_query_params())
+    }
+
+    #[test]
+    fn test_owned_query_passed_by_value() {
+        let query = ParseQuery::new("GameScore")
+            .equal_to("score", 1)
+            .limit(10)
+            .build();
+        let (class_name, params) = class_and_params(query);
+        assert_eq!(class_name, "GameScore");
+        assert_eq!(param(&params, "where"), Some(r#"{"score":1}"#));
+        assert_eq!(param(&params, "limit"), Some("10"));
+
+        // Borrowed and mutably-borrowed chains convert too.
+        let mut query = ParseQuery::new("GameScore");
+        let (_, params) = class_and_params(query.skip(5));
+        assert_eq!(param(&params, "skip"), Some("5"));
+        let (_, params) = class_and_params(&query);
+        assert_eq!(param(&params, "skip"), Some("5"));
+    }
+
+    #[test]
+    fn test_owned_with_methods_match_mutable_api() {
+        let owned = ParseQuery::new("GameScore")
+            .with_equal_to("playerName", "Sean")
+            .with_greater_than("score", 10)
+            .with_exists("cheatMode")
+            .with_order("-score")
+            .with_limit(10)
+            .with_skip(5)
+            .with_select(&["score"])
+            .with_master_key(true);
+
+        let mut mutable = ParseQuery::new("GameScore");
+        mutable
+            .equal_to("playerName", "Sean")
+            .greater_than("score", 10)
+            .exists("cheatMode")
+            .order("-score")
+            .limit(10)
+            .skip(5)
+            .select(&["score"])
+            .set_master_key(true);
+
+        assert_eq!(owned.build_query_params(), mutable.build_query_params());
+        assert!(owned.uses_master_key());
+    }
+
+    #[test]
+    fn test_matches_regex_validates_pattern() {
+        let mut query = ParseQuery::new("GameScore");
+        query
+            .matches_regex("playerName", "^Se+an$", Some("i"))
+            .unwrap();
+        assert_eq!(
+            query.conditions["playerName"],
+            json!({ "$regex": "^Se+an$", "$options": "i" })
+        );
+
+        // Look-arounds are valid on the server even though Rust's regex engine lacks them.
+        query.matches_regex("status", "^(?!banned)", None).unwrap();
+        query.matches_regex("status", "(a)\\1", None).unwrap();
+        query.matches_regex("status", "a++", None).unwrap();
+
+        let result = query.matches_regex("title", "[unterminated", None);
+        assert!(matches!(result, Err(ParseError::InvalidInput(_))));
+        for malformed in [
+            "(open",
+            "close)",
+            "[z-a]",
+            "trailing\\",
+            "(?<=@)[unclosed",
+            "\\9(open",
+        ] {
+            assert!(
+                query.matches_regex("title", malformed, None).is_err(),
+                "{:?} should be rejected",
+                malformed
+            );
+        }
+        assert!(!query.conditions.contains_key("title"));
+    }
+
+    #[test]
+    fn test_contained_by() {
+        let mut query = ParseQuery::new("Article");
+        query.contained_by("tags", vec!["news", "sports"]);
+        assert_eq!(
+            query.conditions["tags"],
+            json!({ "$containedBy": ["news", "sports"] })
+        );
+    }
+
+    #[test]
+    fn test_equal_to_null_vs_does_not_exist() {
+        let mut query = ParseQuery::new("Item");
+        query.equal_to_null("note").does_not_exist("archivedAt");
+        assert_eq!(
+            query.conditions["note"],
+            json!({ "$eq": null, "$exists": true })
+        );
+        assert_eq!(query.conditions["archivedAt"], json!({ "$exists": false }));
+    }
+
+    #[test]
+    fn test_pointer_exists_and_does_not_exist() {
+        let mut query = ParseQuery::new("Comment");
+        query
+            .pointer_exists("author")
+            .pointer_does_not_exist("moderator");
+        assert_eq!(
+            query.conditions["author"],
+            json!({ "$exists": true, "$ne": null })
+        );
+        assert_eq!(query.conditions["moderator"], json!({ "$eq": null }));
+    }
+
+    #[test]
+    fn test_within_polygon() {
+        let triangle = vec![
+            ParseGeoPoint::new(0.0, 0.0).unwrap(),
+            ParseGeoPoint::new(0.0, 10.0).unwrap(),
+            ParseGeoPoint::new(10.0, 0.0).unwrap(),
+        ];
+        let mut query = ParseQuery::new("Place");
+        query.within_polygon("location", triangle.clone()).unwrap();
+        assert_eq!(
+            query.conditions["location"],
+            json!({ "$geoWithin": { "$polygon": [
+                { "__type": "GeoPoint", "latitude": 0.0, "longitude": 0.0 },
+                { "__type": "GeoPoint", "latitude": 0.0, "longitude": 10.0 },
+                { "__type": "GeoPoint", "latitude": 10.0, "longitude": 0.0 }
+            ] } })
+        );
+
+        let mut query = ParseQuery::new("Place");
+        let result = query.within_polygon("location", triangle[..2].to_vec());
+        assert!(matches!(result, Err(ParseError::InvalidInput(_))));
+        assert!(query.conditions.is_empty());
+    }
+
+    #[test]
+    fn test_polygon_contains() {
+        let mut query = ParseQuery::new("Zone");
+        query.polygon_contains("area", ParseGeoPoint::new(2.5, -1.0).unwrap());
+        assert_eq!(
+            query.conditions["area"],
+            json!({ "$geoIntersects": { "$point": {
+                "__type": "GeoPoint", "latitude": 2.5, "longitude": -1.0
+            } } })
+        );
+    }
+
+    #[test]
+    fn test_with_limit_and_skip_accepts_valid_values() {
+        for (limit, skip) in [(10, 0), (0, 0), (-1, 0), (100, 20_000)] {
+            let query = ParseQuery::new("GameScore")
+                .with_limit_and_skip(limit, skip)
+                .unwrap();
+            let params = query.build_query_params();
+            assert!(params.contains(&("limit".to_string(), limit.to_string())));
+            assert!(params.contains(&("skip".to_string(), skip.to_string())));
+        }
+    }
+
+    #[test]
+    fn test_with_limit_and_skip_rejects_invalid_values() {
+        assert!(matches!(
+            ParseQuery::new("GameScore").with_limit_and_skip(-2, 0),
+            Err(ParseError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            ParseQuery::new("GameScore").with_limit_and_skip(10, usize::MAX),
+            Err(ParseError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_updated_and_created_within_use_cutoff_before_now() {
+        let mut query = ParseQuery::new("GameScore");
+        let before = Utc::now();
+        query
+            .updated_within(Duration::from_secs(24 * 60 * 60))
+            .created_within(Duration::from_secs(60));
+        let after = Utc::now();
+
+        for (key, window) in [("updatedAt", 24 * 60 * 60), ("createdAt", 60)] {
+            let constraint = &query.conditions[key]["$gte"];
+            assert_eq!(constraint["__type"], "Date");
+            let cutoff: ParseDate = serde_json::from_value(constraint.clone()).unwrap();
+            let cutoff = cutoff.to_datetime().unwrap();
+            let window = chrono::Duration::seconds(window);
+            // Millisecond precision in the ISO string may round the cutoff down.
+            assert!(cutoff >= before - window - chrono::Duration::milliseconds(1));
+            assert!(cutoff <= after - window);
+        }
+    }
+
+    #[test]
+    fn test_within_clamps_huge_durations() {
+        let mut query = ParseQuery::new("GameScore");
+        query.updated_within(Duration::MAX);
+        assert_eq!(query.conditions["updatedAt"]["$gte"]["__type"], "Date");
+    }
+
+    #[test]
+    fn test_query_json_round_trip() {
+        let mut query = ParseQuery::new("GameScore");
+        query
+            .equal_to("playerName", "Sean")
+            .greater_than("score", 100)
+            .less_than_or_equal_to("score", 500)
+            .contained_in("level", vec![1, 2, 3])
+            .exists("owner")
+            .order("-score,createdAt")
+            .limit(25)
+            .skip(50)
+            .include(&["owner", "team"])
+            .select(&["score", "playerName"])
+            .exclude_keys(&["blob"])
+            .set_master_key(true);
+
+        let json = query.to_json();
+        assert_eq!(json["className"], "GameScore");
+        assert_eq!(json["where"]["score"], json!({ "$gt": 100, "$lte": 500 }));
+        assert_eq!(json["limit"], 25);
+        assert_eq!(json["excludeKeys"], "blob");
+        let serialized = json.to_string();
+        assert!(!serialized.to_lowercase().contains("master"));
+
+        let restored = ParseQuery::from_json(serde_json::from_str(&serialized).unwrap()).unwrap();
+        assert_eq!(restored.class_name, "GameScore");
+        assert_eq!(restored.conditions, query.conditions);
+        assert_eq!(restored.build_query_params(), query.build_query_params());
+        assert!(!restored.use_master_key);
+        assert_eq!(restored.to_json(), json);
+    }
+
+    #[test]
+    fn test_build_query_params_is_sorted_and_omits_empties() {
+        let mut query = ParseQuery::new("GameScore");
+        query.limit(10);
+        query.order("-score");
+        query.include(&[]);
+        let params = query.build_query_params();
+        assert!(param(&params, "where").is_none());
+        assert!(param(&params, "include").is_none());
+        let names: Vec<&str> = params.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["limit", "order"]);
+
+        query
+            .equal_to("playerName", "Sean")
+            .greater_than("score", 10)
+            .skip(5);
+        let params = query.build_query_params();
+        let names: Vec<&str> = params.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["limit", "order", "skip", "where"]);
+        assert_eq!(
+            param(&params, "where"),
+            Some(r#"{"playerName":"Sean","score":{"$gt":10}}"#)
+        );
+        assert_eq!(query.build_query_params(), params);
+    }
+
+    #[test]
+    fn test_query_from_json_minimal_and_invalid() {
+        let query = ParseQuery::from_json(json!({ "className": "Item" })).unwrap();
+        assert!(query.build_query_params().is_empty());
+
+        assert!(matches!(
+            ParseQuery::from_json(json!({ "where": {} })),
+            Err(ParseError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            ParseQuery::from_json(json!({ "className": "Item", "limit": "ten" })),
+            Err(ParseError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_count_params_request_no_results() {
+        let mut query = ParseQuery::new("GameScore");
+        query
+            .equal_to("playerName", "Sean")
+            .order("-score")
+            .limit(10)
+            .skip(5)
+            .include(&["owner"])
+            .select(&["score"]);
+
+        let params = query.count_params();
+        assert_eq!(param(&params, "limit"), Some("0"));
+        assert_eq!(param(&params, "count"), Some("1"));
+        assert_eq!(param(&params, "where"), Some(r#"{"playerName":"Sean"}"#));
+        assert_eq!(params.len(), 3);
+    }
+
+    fn page(count: Option<i64>, len: usize, query: &ParseQuery) -> PaginatedResults<u32> {
+        let response: Results<u32> = serde_json::from_value(json!({
+            "results": (0..len as u32).collect::<Vec<_>>(),
+            "count": count,
+        }))
+        .unwrap();
+        PaginatedResults::from_results(response, query)
+    }
+
+    #[test]
+    fn test_paginated_results_has_more_with_count() {
+        let mut query = ParseQuery::new("GameScore");
+        query.limit(50).skip(150);
+        let middle = page(Some(250), 50, &query);
+        assert_eq!(
+            (middle.skip, middle.limit, middle.count),
+            (150, Some(50), Some(250))
+        );
+        assert!(middle.has_more());
+        let next = middle.next_query().unwrap();
+        assert_eq!(param(&next.build_query_params(), "skip"), Some("200"));
+        assert_eq!(param(&next.build_query_params(), "limit"), Some("50"));
+
+        // The last page ends exactly at the count.
+        query.skip(200);
+        let last = page(Some(250), 50, &query);
+        assert!(!last.has_more());
+        assert!(last.next_query().is_none());
+
+        assert!(!page(Some(0), 0, &ParseQuery::new("GameScore")).has_more());
+    }
+
+    #[test]
+    fn test_paginated_results_has_more_without_count() {
+        let mut query = ParseQuery::new("GameScore");
+        query.limit(10);
+        assert!(page(None, 10, &query).has_more());
+        assert!(!page(None, 9, &query).has_more());
+
+        // Without a limit, the server's default page size of 100 applies.
+        let unlimited = ParseQuery::new("GameScore");
+        assert!(page(None, 100, &unlimited).has_more());
+        assert!(!page(None, 99, &unlimited).has_more());
+
+        let results = page(None, 3, &query);
+        assert_eq!(results.iter().sum::<u32>(), 3);
+        assert_eq!(results.into_iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_order_by_typed_sort_keys() {
+        let mut query = ParseQuery::new("GameScore");
+        query.order_by(&[Sort::desc("score"), Sort::asc("name")]);
+        let params = query.build_query_params();
+        assert_eq!(param(&params, "order"), Some("-score,name"));
+    }
+
+    #[test]
+    fn test_order_by_replaces_existing_order() {
+        let mut query = ParseQuery::new("GameScore");
+        query.order("createdAt");
+        query.order_by(&[Sort::asc("name")]);
+        assert_eq!(param(&query.build_query_params(), "order"), Some("name"));
+
+        query.order_by(&[]);
+        assert_eq!(param(&query.build_query_params(), "order"), None);
+    }
+
+    #[test]
+    fn test_order_by_timestamps() {
+        let mut query = ParseQuery::new("GameScore");
+        query.order("score").order_by_created_at(false);
+        assert_eq!(
+            param(&query.build_query_params(), "order"),
+            Some("createdAt")
+        );
+
+        query.order_by_updated_at(true);
+        assert_eq!(
+            param(&query.build_query_params(), "order"),
+            Some("-updatedAt")
+        );
+
+        query
+            .order_by_descending("score")
+            .add_created_at_order(true)
+            .add_updated_at_order(false);
+        assert_eq!(
+            param(&query.build_query_params(), "order"),
+            Some("-score,-createdAt,updatedAt")
+        );
+    }
 }
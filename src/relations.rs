@@ -1,9 +1,8 @@
 use crate::error::ParseError;
 use crate::types::RelationOp; // For types not directly re-exported at crate root like RelationOp
-use crate::{ParseDate, Pointer};
+use crate::{ParseDate, Pointer, UpdateResponseData};
 
 use reqwest::Method;
-use serde::Deserialize;
 
 impl crate::Parse {
     /// Adds target objects to a relation field of a parent object.
@@ -24,46 +23,10 @@ impl crate::Parse {
         relation_key: &str,
         targets: &[Pointer],
     ) -> Result<ParseDate, ParseError> {
-        if targets.is_empty() {
-            return Err(ParseError::InvalidInput(
-                "targets cannot be empty for AddRelation operation.".to_string(),
-            ));
-        }
-        if parent_class_name.is_empty() || parent_object_id.is_empty() || relation_key.is_empty() {
-            return Err(ParseError::InvalidInput(
-                "parent_class_name, parent_object_id, and relation_key cannot be empty."
-                    .to_string(),
-            ));
-        }
-
-        let endpoint = format!("classes/{}/{}", parent_class_name, parent_object_id);
-        let relation_op = RelationOp::add(targets);
-        let body = serde_json::json!({ relation_key: relation_op });
-
-        let use_master_key = self.master_key.is_some();
-        let session_token_to_use = if use_master_key {
-            None
-        } else {
-            self.session_token.as_deref()
-        };
-
-        #[derive(Deserialize)]
-        struct UpdateResponse {
-            #[serde(rename = "updatedAt")]
-            updated_at: String,
-        }
-
-        let response: UpdateResponse = self
-            ._request(
-                Method::PUT,
-                &endpoint,
-                Some(&body),
-                use_master_key,
-                session_token_to_use,
-            )
+        let response = self
+            .add_relation(parent_class_name, parent_object_id, relation_key, targets)
             .await?;
-
-        Ok(ParseDate::new(response.updated_at))
+        Ok(response.updated_at)
     }
 
     /// Removes target objects from a relation field of a parent object.
@@ -84,20 +47,98 @@ impl crate::Parse {
         relation_key: &str,
         targets: &[Pointer],
     ) -> Result<ParseDate, ParseError> {
+        let response = self
+            .remove_relation(parent_class_name, parent_object_id, relation_key, targets)
+            .await?;
+        Ok(response.updated_at)
+    }
+
+    /// Adds target objects to a relation field on an object of any class.
+    ///
+    /// Sends an `AddRelation` operation for `relation_key`. This is the general form of the
+    /// role-specific helpers such as `add_users_to_role`.
+    ///
+    /// # Arguments
+    /// * `class_name`: The class name of the object holding the relation.
+    /// * `object_id`: The object ID of the object holding the relation.
+    /// * `relation_key`: The key (field name) of the relation.
+    /// * `targets`: A slice of `Pointer`s representing the objects to add to the relation.
+    ///
+    /// # Returns
+    /// A `Result` containing the server's `UpdateResponseData` or a `ParseError`.
+    /// This operation typically requires the Master Key or appropriate ACLs.
+    pub async fn add_relation(
+        &self,
+        class_name: &str,
+        object_id: &str,
+        relation_key: &str,
+        targets: &[Pointer],
+    ) -> Result<UpdateResponseData, ParseError> {
+        if targets.is_empty() {
+            return Err(ParseError::InvalidInput(
+                "targets cannot be empty for AddRelation operation.".to_string(),
+            ));
+        }
+        self._update_relation(
+            class_name,
+            object_id,
+            relation_key,
+            RelationOp::add(targets),
+        )
+        .await
+    }
+
+    /// Removes target objects from a relation field on an object of any class.
+    ///
+    /// Sends a `RemoveRelation` operation for `relation_key`.
+    ///
+    /// # Arguments
+    /// * `class_name`: The class name of the object holding the relation.
+    /// * `object_id`: The object ID of the object holding the relation.
+    /// * `relation_key`: The key (field name) of the relation.
+    /// * `targets`: A slice of `Pointer`s representing the objects to remove from the relation.
+    ///
+    /// # Returns
+    /// A `Result` containing the server's `UpdateResponseData` or a `ParseError`.
+    /// This operation typically requires the Master Key or appropriate ACLs.
+    pub async fn remove_relation(
+        &self,
+        class_name: &str,
+        object_id: &str,
+        relation_key: &str,
+        targets: &[Pointer],
+    ) -> Result<UpdateResponseData, ParseError> {
         if targets.is_empty() {
             return Err(ParseError::InvalidInput(
                 "targets cannot be empty for RemoveRelation operation.".to_string(),
             ));
         }
-        if parent_class_name.is_empty() || parent_object_id.is_empty() || relation_key.is_empty() {
+        self._update_relation(
+            class_name,
+            object_id,
+            relation_key,
+            RelationOp::remove(targets),
+        )
+        .await
+    }
+
+    // Sends a single relation operation for `relation_key` on the given object.
+    async fn _update_relation(
+        &self,
+        class_name: &str,
+        object_id: &str,
+        relation_key: &str,
+        relation_op: RelationOp<'_, Pointer>,
+    ) -> Result<UpdateResponseData, ParseError> {
+        if class_name.is_empty() || object_id.is_empty() || relation_key.is_empty() {
             return Err(ParseError::InvalidInput(
                 "parent_class_name, parent_object_id, and relation_key cannot be empty."
                     .to_string(),
             ));
         }
+        self.validate_object_id(object_id)?;
 
-        let endpoint = format!("classes/{}/{}", parent_class_name, parent_object_id);
-        let relation_op = RelationOp::remove(targets);
+        let endpoint = format!("classes/{}/{}", class_name, object_id);
         let body = serde_json::json!({ relation_key: relation_op });
 
         let use_master_key = self.master_key.is_some();
@@ -107,22 +148,13 @@ impl crate::Parse {
             self.session_token.as_deref()
         };
 
-        #[derive(Deserialize)]
-        struct UpdateResponse {
-            #[serde(rename = "updatedAt")]
-            updated_at: String,
-        }
-
-        let response: UpdateResponse = self
-            ._request(
-                Method::PUT,
-                &endpoint,
-                Some(&body),
-                use_master_key,
-                session_token_to_use,
-            )
-            .await?;
-
-        Ok(ParseDate::new(response.updated_at))
+        self._request(
+            Method::PUT,
+            &endpoint,
+            Some(&body),
+            use_master_key,
+            session_token_to_use,
+        )
+        .await
     }
 }
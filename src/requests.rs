@@ -1,6 +1,8 @@
 // use crate::acl::ParseACL; // Unused
+use crate::client::{AuthType, RequestOptions};
 use crate::error::ParseError;
 
+use reqwest::header::{HeaderMap, CONTENT_TYPE};
 use reqwest::{Method, Response as HttpResponse};
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
@@ -16,7 +18,7 @@ impl crate::Parse {
         let response_url = response.url().to_string(); // For logging
 
         // Try to get the body as text first for logging, then consume for JSON
-        let response_text = response.text().await.map_err(ParseError::ReqwestError)?;
+        let response_text = response.text().await.map_err(ParseError::from)?;
 
         if status.is_success() {
             if response_text.is_empty() || response_text == "{}" {
@@ -36,10 +38,13 @@ impl crate::Parse {
                     e,
                     &response_text // Log the problematic text
                 );
-                ParseError::JsonDeserializationFailed(format!(
-                    "Failed to deserialize successful response from '{}': {}. Body: {}",
-                    response_url, e, &response_text
-                ))
+                ParseError::JsonDeserializationFailed(
+                    format!(
+                        "Failed to deserialize successful response from '{}': {}. Body: {}",
+                        response_url, e, &response_text
+                    ),
+                    e,
+                )
             })
         } else {
             // Attempt to parse the error response body as JSON
@@ -101,4 +106,109 @@ impl crate::Parse {
         self._request(Method::DELETE, endpoint, None::<&Value>, false, None)
             .await
     }
+
+    /// Sends a request authenticated with the given master key instead of the one configured on
+    /// the client (if any).
+    ///
+    /// This lets a single client manage several Parse apps that share a server URL and app id
+    /// routing, without rebuilding the client for each app.
+    ///
+    /// # Security
+    ///
+    /// The master key bypasses all ACLs and Class-Level Permissions. The key passed here is only
+    /// held for the duration of the call, but callers are responsible for storing it securely and
+    /// for never sourcing it from untrusted input. The client's session token is not sent with
+    /// this request.
+    pub async fn request_with_master_key_override<
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned + Send + 'static,
+    >(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<&T>,
+        master_key: &str,
+    ) -> Result<R, ParseError> {
+        let options = RequestOptions {
+            use_master_key: true,
+            master_key_override: Some(master_key),
+            ..Default::default()
+        };
+        self._request_with_options(method, endpoint, body, options)
+            .await
+    }
+
+    /// Sends a request authenticated with exactly the credentials selected by `auth`.
+    ///
+    /// Other request methods choose credentials implicitly (session token, then the configured
+    /// key). This one sends only what `auth` asks for, which makes it possible, for example, to
+    /// exercise Class-Level Permissions with the REST API Key on a client that also holds the
+    /// Master Key.
+    ///
+    /// # Arguments
+    /// * `method`: The HTTP method to use.
+    /// * `endpoint`: The path relative to the `/parse` mount path, e.g. `"classes/GameScore"`.
+    /// * `body`: An optional body, serialized as JSON.
+    /// * `auth`: Which credentials to send. `AuthType::SessionToken` and `AuthType::MasterKey`
+    ///   return an error if the client has no session token or master key, respectively;
+    ///   `AuthType::RestApiKey` falls back to the JavaScript key if no REST API key is configured;
+    ///   `AuthType::NoAuth` sends only the Application ID.
+    ///
+    /// # Returns
+    /// A `Result` containing the deserialized response, or a `ParseError` if the request fails.
+    pub async fn request_with_auth<
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned + Send + 'static,
+    >(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<&T>,
+        auth: AuthType,
+    ) -> Result<R, ParseError> {
+        let options = RequestOptions {
+            auth: Some(auth),
+            ..Default::default()
+        };
+        self._request_with_options(method, endpoint, body, options)
+            .await
+    }
+
+    /// Sends a request to an arbitrary endpoint and returns the raw `reqwest::Response`.
+    ///
+    /// **Advanced.** This is an escape hatch for endpoints the SDK does not model (custom routes,
+    /// newer Parse Server features). The standard headers (application id, content type) and
+    /// the credentials selected by `auth` are applied, but the response status is *not* checked
+    /// and the body is not parsed: the caller is responsible for both.
+    ///
+    /// # Arguments
+    /// * `method`: The HTTP method to use.
+    /// * `endpoint`: The path relative to the `/parse` mount path, e.g. `"health"` or `"functions/hello"`.
+    /// * `body`: An optional JSON body.
+    /// * `auth`: Which credentials to send. `AuthType::SessionToken` and `AuthType::MasterKey`
+    ///   return an error if the client has no session token or master key, respectively;
+    ///   `AuthType::RestApiKey` falls back to the JavaScript key if no REST API key is configured.
+    ///
+    /// # Returns
+    /// A `Result` containing the unprocessed `reqwest::Response`, or a `ParseError` if the
+    /// request could not be built or sent.
+    pub async fn raw_request(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<Value>,
+        auth: AuthType,
+    ) -> Result<HttpResponse, ParseError> {
+        let url = self._api_url(endpoint)?;
+        let mut headers = HeaderMap::new();
+        self.insert_auth_type_headers(&mut headers, auth, None, None)?;
+
+        let mut request_builder = self.http_client.request(method, url).headers(headers);
+        if let Some(body) = body {
+            request_builder = request_builder
+                .header(CONTENT_TYPE, "application/json")
+                .body(serde_json::to_string(&body)?);
+        }
+        self.send_request(request_builder).await
+    }
 }
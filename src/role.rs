@@ -6,7 +6,7 @@ use crate::types::ParseDate; // Assuming ParseDate is in crate::types
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Represents a Parse Role object, used for grouping users and other roles to manage permissions.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -17,6 +17,7 @@ pub struct ParseRole {
     /// The timestamp when the role was created.
     #[serde(
         rename = "createdAt",
+        default,
         deserialize_with = "deserialize_string_to_option_parse_date",
         skip_serializing_if = "Option::is_none"
     )]
@@ -24,6 +25,7 @@ pub struct ParseRole {
     /// The timestamp when the role was last updated.
     #[serde(
         rename = "updatedAt",
+        default,
         deserialize_with = "deserialize_string_to_option_parse_date",
         skip_serializing_if = "Option::is_none"
     )]
@@ -44,6 +46,17 @@ pub struct ParseRole {
     pub other_fields: HashMap<String, Value>,
 }
 
+impl TryFrom<Value> for ParseRole {
+    type Error = ParseError;
+
+    /// Converts a JSON object (e.g. embedded in a Cloud function result) into a `ParseRole`.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        serde_json::from_value(value).map_err(|e| {
+            ParseError::JsonDeserializationFailed(format!("Invalid ParseRole: {}", e), e)
+        })
+    }
+}
+
 /// Represents the data required to create a new Parse Role.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct NewParseRole {
@@ -116,6 +129,7 @@ impl crate::Parse {
     /// A `Result` containing the `ParseRole` or a `ParseError`.
     /// Note: The `users` and `roles` relations are not populated by this call.
     pub async fn get_role(&self, object_id: &str) -> Result<ParseRole, ParseError> {
+        self.validate_object_id(object_id)?;
         let endpoint = format!("roles/{}", object_id);
         // Reading a role might be allowed with different auth types depending on ACL.
         // Defaulting to standard auth (session token if present, or JS/REST key).
@@ -141,6 +155,7 @@ impl crate::Parse {
     /// A `Result` indicating success (`Ok(())`) or a `ParseError`.
     /// This operation typically requires the Master Key or appropriate user permissions.
     pub async fn delete_role(&self, object_id: &str) -> Result<(), ParseError> {
+        self.validate_object_id(object_id)?;
         let endpoint = format!("roles/{}", object_id);
         // Deleting roles typically requires Master Key or specific user permissions.
         // Prioritize Master Key if available.
@@ -185,6 +200,7 @@ impl crate::Parse {
                 "user_ids cannot be empty for AddRelation.".to_string(),
             ));
         }
+        self.validate_object_id(role_id)?;
         let endpoint = format!("roles/{}", role_id);
         let pointers: Vec<Pointer> = user_ids
             .iter()
@@ -244,6 +260,7 @@ impl crate::Parse {
                 "user_ids cannot be empty for RemoveRelation.".to_string(),
             ));
         }
+        self.validate_object_id(role_id)?;
         let endpoint = format!("roles/{}", role_id);
         let pointers: Vec<Pointer> = user_ids
             .iter()
@@ -298,6 +315,7 @@ impl crate::Parse {
                 "child_role_ids cannot be empty for AddRelation.".to_string(),
             ));
         }
+        self.validate_object_id(role_id)?;
         let endpoint = format!("roles/{}", role_id);
         let pointers: Vec<Pointer> = child_role_ids
             .iter()
@@ -347,6 +365,7 @@ impl crate::Parse {
         role_id: &str,
         child_role_ids: &[&str],
     ) -> Result<ParseDate, ParseError> {
+        self.validate_object_id(role_id)?;
         let endpoint = format!("roles/{}", role_id);
         let pointers: Vec<Pointer> = child_role_ids
             .iter()
@@ -374,4 +393,51 @@ impl crate::Parse {
             .await?;
         Ok(ParseDate::new(response.updated_at))
     }
+
+    /// Returns every role a user has, directly or through role inheritance.
+    ///
+    /// Starts with the roles whose `users` relation contains the user, then repeatedly adds the
+    /// parent roles, i.e. roles whose `roles` relation contains a role already found (see
+    /// [`add_child_roles_to_role`](Self::add_child_roles_to_role)); members of a child role get
+    /// the permissions of its parents. Each role is returned once, and cycles in the hierarchy
+    /// are handled. Roles are queried with the master key if the client has one, otherwise only
+    /// roles readable by the current user are found.
+    ///
+    /// Direct roles come first, followed by each further level of parents.
+    pub async fn get_effective_roles_for_user(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<ParseRole>, ParseError> {
+        self.validate_object_id(user_id)?;
+        let use_master_key = self.master_key.is_some();
+
+        let mut query = self.query_roles();
+        query
+            .equal_to("users", Pointer::new("_User", user_id))
+            .set_master_key(use_master_key);
+        let mut level: Vec<ParseRole> = query.find_all(self).await?;
+
+        let mut roles = Vec::new();
+        let mut seen = HashSet::new();
+        loop {
+            let mut new_ids = Vec::new();
+            for role in level {
+                let Some(id) = role.object_id.clone() else {
+                    continue;
+                };
+                if seen.insert(id.clone()) {
+                    new_ids.push(Pointer::new("_Role", id));
+                    roles.push(role);
+                }
+            }
+            if new_ids.is_empty() {
+                return Ok(roles);
+            }
+            let mut parents = self.query_roles();
+            parents
+                .contained_in("roles", new_ids)
+                .set_master_key(use_master_key);
+            level = parents.find_all(self).await?;
+        }
+    }
 }
@@ -3,7 +3,16 @@ use serde_json::Value;
 use std::collections::HashMap;
 
 /// Represents the possible data types for a field in a Parse class schema.
+///
+/// Each variant (de)serializes to Parse Server's type string, which is the variant name
+/// (`"GeoPoint"`, `"Polygon"`, ...). Parse does not record element types for `Array` fields,
+/// nor the target class inside the type; `Pointer` and `Relation` fields carry it in
+/// [`FieldSchema::target_class`].
+///
+/// A type string this version does not know, e.g. one added by a newer Parse Server, becomes
+/// [`FieldType::Unknown`] instead of failing the whole schema, and serializes back unchanged.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
 pub enum FieldType {
     String,
     Number,
@@ -18,7 +27,60 @@ pub enum FieldType {
     ACL,
     Bytes,
     Polygon,
-    // Note: Add other types if Parse Server supports more that are relevant for schema definition.
+    /// A type not listed above, holding the raw type string sent by the server.
+    Unknown(String),
+}
+
+impl FieldType {
+    /// Returns the Parse type string for this field type.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FieldType::String => "String",
+            FieldType::Number => "Number",
+            FieldType::Boolean => "Boolean",
+            FieldType::Date => "Date",
+            FieldType::Object => "Object",
+            FieldType::Array => "Array",
+            FieldType::Pointer => "Pointer",
+            FieldType::Relation => "Relation",
+            FieldType::File => "File",
+            FieldType::GeoPoint => "GeoPoint",
+            FieldType::ACL => "ACL",
+            FieldType::Bytes => "Bytes",
+            FieldType::Polygon => "Polygon",
+            FieldType::Unknown(type_name) => type_name,
+        }
+    }
+}
+
+impl From<String> for FieldType {
+    fn from(type_name: String) -> Self {
+        match type_name.as_str() {
+            "String" => FieldType::String,
+            "Number" => FieldType::Number,
+            "Boolean" => FieldType::Boolean,
+            "Date" => FieldType::Date,
+            "Object" => FieldType::Object,
+            "Array" => FieldType::Array,
+            "Pointer" => FieldType::Pointer,
+            "Relation" => FieldType::Relation,
+            "File" => FieldType::File,
+            "GeoPoint" => FieldType::GeoPoint,
+            "ACL" => FieldType::ACL,
+            "Bytes" => FieldType::Bytes,
+            "Polygon" => FieldType::Polygon,
+            _ => FieldType::Unknown(type_name),
+        }
+    }
+}
+
+impl From<FieldType> for String {
+    fn from(field_type: FieldType) -> Self {
+        match field_type {
+            FieldType::Unknown(type_name) => type_name,
+            known => known.as_str().to_string(),
+        }
+    }
 }
 
 /// Represents the schema definition for a single field within a Parse class.
@@ -92,3 +154,83 @@ pub struct ParseSchema {
 pub struct GetAllSchemasResponse {
     pub results: Vec<ParseSchema>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_field_types_round_trip_parse_type_strings() {
+        let schema_json = json!({
+            "className": "Venue",
+            "fields": {
+                "objectId": { "type": "String" },
+                "capacity": { "type": "Number" },
+                "isOpen": { "type": "Boolean" },
+                "openedAt": { "type": "Date" },
+                "metadata": { "type": "Object" },
+                "tags": { "type": "Array" },
+                "owner": { "type": "Pointer", "targetClass": "_User" },
+                "staff": { "type": "Relation", "targetClass": "_User" },
+                "floorPlan": { "type": "File" },
+                "location": { "type": "GeoPoint" },
+                "ACL": { "type": "ACL" },
+                "thumbnail": { "type": "Bytes" },
+                "area": { "type": "Polygon" }
+            }
+        });
+        let schema: ParseSchema = serde_json::from_value(schema_json.clone()).unwrap();
+
+        let expected = [
+            ("objectId", FieldType::String),
+            ("capacity", FieldType::Number),
+            ("isOpen", FieldType::Boolean),
+            ("openedAt", FieldType::Date),
+            ("metadata", FieldType::Object),
+            ("tags", FieldType::Array),
+            ("owner", FieldType::Pointer),
+            ("staff", FieldType::Relation),
+            ("floorPlan", FieldType::File),
+            ("location", FieldType::GeoPoint),
+            ("ACL", FieldType::ACL),
+            ("thumbnail", FieldType::Bytes),
+            ("area", FieldType::Polygon),
+        ];
+        assert_eq!(schema.fields.len(), expected.len());
+        for (name, field_type) in expected {
+            assert_eq!(schema.fields[name].field_type, field_type, "field {}", name);
+        }
+        assert_eq!(
+            schema.fields["owner"].target_class.as_deref(),
+            Some("_User")
+        );
+
+        assert_eq!(serde_json::to_value(&schema).unwrap(), schema_json);
+    }
+
+    #[test]
+    fn test_unknown_field_type_falls_back_instead_of_failing() {
+        let response: GetAllSchemasResponse = serde_json::from_value(json!({
+            "results": [{
+                "className": "Venue",
+                "fields": {
+                    "name": { "type": "String" },
+                    "footprint": { "type": "Hologram" }
+                }
+            }]
+        }))
+        .unwrap();
+
+        let fields = &response.results[0].fields;
+        assert_eq!(fields["name"].field_type, FieldType::String);
+        assert_eq!(
+            fields["footprint"].field_type,
+            FieldType::Unknown("Hologram".to_string())
+        );
+        assert_eq!(
+            serde_json::to_value(&fields["footprint"]).unwrap(),
+            json!({ "type": "Hologram" })
+        );
+    }
+}
@@ -4,7 +4,8 @@ use serde_json::Value;
 
 use crate::error::ParseError;
 use crate::object::{deserialize_string_to_option_parse_date, deserialize_string_to_parse_date};
-use crate::types::ParseDate;
+use crate::types::{ParseDate, Pointer};
+use crate::user::ParseUser;
 use crate::Parse;
 
 /// Represents a Parse Server Session object, detailing an active user session.
@@ -16,7 +17,8 @@ use crate::Parse;
 ///
 /// The `user` field is a `serde_json::Value` because its content can vary. If the query
 /// fetching the session includes `include=user`, this field will contain the full `ParseUser` object.
-/// Otherwise, it might be a pointer or a more minimal representation.
+/// Otherwise, it might be a pointer or a more minimal representation. Use
+/// [`user_pointer`](Self::user_pointer) and [`user_object`](Self::user_object) to read it.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ParseSession {
     #[serde(rename = "objectId")]
@@ -54,6 +56,46 @@ pub struct ParseSession {
     pub other_fields: std::collections::HashMap<String, Value>,
 }
 
+impl ParseSession {
+    /// Returns a pointer to the session's user.
+    ///
+    /// Works for both shapes of the `user` field: a `Pointer` (the default) and a full user
+    /// object (when the session was fetched with `include=user`). Returns `None` if the field
+    /// holds neither, e.g. when it is missing.
+    pub fn user_pointer(&self) -> Option<Pointer> {
+        let user = self.user.as_object()?;
+        if !matches!(
+            user.get("__type").and_then(Value::as_str),
+            Some("Pointer") | Some("Object")
+        ) {
+            return None;
+        }
+        let class_name = user.get("className")?.as_str()?;
+        let object_id = user.get("objectId")?.as_str()?;
+        Some(Pointer::new(class_name, object_id))
+    }
+
+    /// Returns the session's user, if it was included in the response (by fetching the session
+    /// with `include=user`). Returns `None` if `user` is only a pointer.
+    pub fn user_object(&self) -> Option<ParseUser> {
+        if self.user.get("__type").and_then(Value::as_str) != Some("Object") {
+            return None;
+        }
+        ParseUser::try_from(self.user.clone()).ok()
+    }
+}
+
+impl TryFrom<Value> for ParseSession {
+    type Error = ParseError;
+
+    /// Converts a JSON object (e.g. embedded in a Cloud function result) into a `ParseSession`.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        serde_json::from_value(value).map_err(|e| {
+            ParseError::JsonDeserializationFailed(format!("Invalid ParseSession: {}", e), e)
+        })
+    }
+}
+
 /// Represents the successful response from a session update operation.
 ///
 /// When a session is updated via the API (e.g., using `ParseSessionHandle::update_by_object_id`),
@@ -160,6 +202,50 @@ impl<'a> ParseSessionHandle<'a> {
             .await
     }
 
+    /// Re-reads the current session from the server (`GET /sessions/me`), e.g. to check its
+    /// current [`expires_at`](ParseSession::expires_at).
+    ///
+    /// This does not extend the session: Parse Server has no endpoint for that. Whether a
+    /// session's expiry moves with use depends only on the server's `extendSessionOnUse` setting.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the current [`ParseSession`](crate::session::ParseSession), or a
+    /// `ParseError`:
+    /// * `ParseError::SessionTokenMissing` if the client has no session token.
+    /// * `ParseError::InvalidSessionToken` if the session is no longer valid.
+    /// * `ParseError::Unsupported` if the server's sessions do not expire (no `expiresAt`).
+    pub async fn refresh(&self) -> Result<ParseSession, ParseError> {
+        let session = self.me().await?;
+        if session.expires_at.is_none() {
+            return Err(ParseError::Unsupported(
+                "session refresh: the server did not report an expiry for the current session"
+                    .to_string(),
+            ));
+        }
+        Ok(session)
+    }
+
+    /// Checks whether the client's current session token is still accepted by the server.
+    ///
+    /// This makes a single `GET /sessions/me` request, which is enough to verify a cached token on
+    /// startup without fetching the user.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` if the server accepts the session token.
+    /// * `Ok(false)` if the server rejects it as invalid or expired (code 209).
+    /// * `Err(ParseError::SessionTokenMissing)` if the client has no session token.
+    /// * `Err(_)` for any other failure, e.g. a network error, so that an unreachable server is
+    ///   not mistaken for a revoked session.
+    pub async fn is_valid(&self) -> Result<bool, ParseError> {
+        match self.me().await {
+            Ok(_) => Ok(true),
+            Err(ParseError::InvalidSessionToken(_)) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Retrieves a specific session by its `objectId`.
     ///
     /// This method makes a GET request to the `/sessions/:objectId` endpoint. It requires the
@@ -208,6 +294,7 @@ impl<'a> ParseSessionHandle<'a> {
     /// # }
     /// ```
     pub async fn get_by_object_id(&self, object_id: &str) -> Result<ParseSession, ParseError> {
+        self.client.validate_object_id(object_id)?;
         let endpoint = format!("sessions/{}", object_id);
         self.client
             ._request(Method::GET, &endpoint, None::<&Value>, true, None) // true for use_master_key
@@ -260,6 +347,7 @@ impl<'a> ParseSessionHandle<'a> {
     /// # }
     /// ```
     pub async fn delete_by_object_id(&self, object_id: &str) -> Result<(), ParseError> {
+        self.client.validate_object_id(object_id)?;
         let endpoint = format!("sessions/{}", object_id);
         // Expect serde_json::Value to consume the empty {} response, then map to Ok(()).
         // The master key is required for this operation.
@@ -344,6 +432,7 @@ impl<'a> ParseSessionHandle<'a> {
         object_id: &str,
         session_data: &T,
     ) -> Result<SessionUpdateResponse, ParseError> {
+        self.client.validate_object_id(object_id)?;
         let endpoint = format!("sessions/{}", object_id);
         self.client
             ._request(Method::PUT, &endpoint, Some(session_data), true, None) // true for use_master_key
@@ -437,3 +526,61 @@ impl<'a> ParseSessionHandle<'a> {
         Ok(response.results)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn session_with_user(user: Value) -> ParseSession {
+        serde_json::from_value(json!({
+            "objectId": "sess000001",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "sessionToken": "r:abc",
+            "user": user,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_user_pointer_shape() {
+        let session = session_with_user(json!({
+            "__type": "Pointer",
+            "className": "_User",
+            "objectId": "user000001"
+        }));
+        assert_eq!(
+            session.user_pointer(),
+            Some(Pointer::new("_User", "user000001"))
+        );
+        assert!(session.user_object().is_none());
+    }
+
+    #[test]
+    fn test_included_user_shape() {
+        let session = session_with_user(json!({
+            "__type": "Object",
+            "className": "_User",
+            "objectId": "user000001",
+            "username": "alice",
+            "email": "alice@example.com",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "updatedAt": "2024-01-02T00:00:00.000Z"
+        }));
+        assert_eq!(
+            session.user_pointer(),
+            Some(Pointer::new("_User", "user000001"))
+        );
+        let user = session.user_object().expect("user should be included");
+        assert_eq!(user.object_id.as_deref(), Some("user000001"));
+        assert_eq!(user.username, "alice");
+        assert_eq!(user.email.as_deref(), Some("alice@example.com"));
+    }
+
+    #[test]
+    fn test_missing_user() {
+        let session = session_with_user(Value::Null);
+        assert!(session.user_pointer().is_none());
+        assert!(session.user_object().is_none());
+    }
+}
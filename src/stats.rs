@@ -0,0 +1,64 @@
+// src/stats.rs
+
+use crate::error::ParseError;
+use reqwest::Response;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A snapshot of the request counters of a [`Parse`](crate::Parse) client, returned by
+/// [`Parse::stats`](crate::Parse::stats).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseStats {
+    /// Requests sent, including those that failed before a response was received.
+    pub requests: u64,
+    /// Requests answered with a 2xx status.
+    pub successes: u64,
+    /// Requests answered with any other status, or that got no response at all.
+    pub failures: u64,
+    /// Request body bytes sent.
+    pub bytes_sent: u64,
+    /// Response body bytes received, as announced by the `Content-Length` header. Responses
+    /// without one (e.g. chunked ones) are not counted.
+    pub bytes_received: u64,
+}
+
+// Lock-free counters behind `ParseStats`, updated once per request by `Parse::send_request`.
+#[derive(Debug, Default)]
+pub(crate) struct RequestCounters {
+    requests: AtomicU64,
+    successes: AtomicU64,
+    failures: AtomicU64,
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+}
+
+impl RequestCounters {
+    pub(crate) fn record(&self, bytes_sent: u64, result: &Result<Response, ParseError>) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        self.bytes_sent.fetch_add(bytes_sent, Ordering::Relaxed);
+        match result {
+            Ok(response) => {
+                let outcome = if response.status().is_success() {
+                    &self.successes
+                } else {
+                    &self.failures
+                };
+                outcome.fetch_add(1, Ordering::Relaxed);
+                self.bytes_received
+                    .fetch_add(response.content_length().unwrap_or(0), Ordering::Relaxed);
+            }
+            Err(_) => {
+                self.failures.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> ParseStats {
+        ParseStats {
+            requests: self.requests.load(Ordering::Relaxed),
+            successes: self.successes.load(Ordering::Relaxed),
+            failures: self.failures.load(Ordering::Relaxed),
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+        }
+    }
+}
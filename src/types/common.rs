@@ -167,8 +167,11 @@ pub struct Results<T> {
 /// Represents common data in response to update operations.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct UpdateResponseData {
-    #[serde(rename = "updatedAt")]
-    pub updated_at: String, // ISO 8601 format
+    #[serde(
+        rename = "updatedAt",
+        deserialize_with = "crate::object::deserialize_string_to_parse_date"
+    )]
+    pub updated_at: super::date::ParseDate,
     // Some update operations might return objectId, e.g., if it's a create-or-update
     #[serde(rename = "objectId", skip_serializing_if = "Option::is_none")]
     pub object_id: Option<String>,
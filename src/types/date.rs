@@ -1,17 +1,39 @@
 // src/types/date.rs
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 use std::fmt;
 
 /// Represents a Parse Date type, which includes timezone information.
 /// Parse stores dates in UTC.
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+///
+/// A `ParseDate` always serializes as Parse's Date envelope, `{"__type": "Date", "iso": "..."}`,
+/// so it can be put directly into an object field (e.g. with `ParseObject::set` or in a
+/// `json!` body) and is stored as a Date rather than a string. A `chrono::DateTime<Utc>`
+/// serializes as a bare string instead; convert it with `ParseDate::from` first.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct ParseDate {
     #[serde(rename = "__type")]
     pub __type: String, // Should always be "Date"
     pub iso: String, // ISO 8601 format, e.g., "YYYY-MM-DDTHH:MM:SS.MMMZ"
 }
 
+impl Serialize for ParseDate {
+    // Always writes the "Date" type tag, whatever `__type` holds.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut envelope = serializer.serialize_struct("ParseDate", 2)?;
+        envelope.serialize_field("__type", "Date")?;
+        envelope.serialize_field("iso", &self.iso)?;
+        envelope.end()
+    }
+}
+
+impl From<DateTime<Utc>> for ParseDate {
+    fn from(dt: DateTime<Utc>) -> Self {
+        Self::from_datetime(dt)
+    }
+}
+
 impl fmt::Display for ParseDate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.iso)
@@ -46,3 +68,28 @@ impl ParseDate {
         DateTime::parse_from_rfc3339(&self.iso).map(|dt| dt.with_timezone(&Utc))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_serializes_as_date_envelope() {
+        let mut date = ParseDate::new("2024-03-01T12:30:00.000Z");
+        date.__type = String::new();
+        assert_eq!(
+            serde_json::to_value(&date).unwrap(),
+            json!({ "__type": "Date", "iso": "2024-03-01T12:30:00.000Z" })
+        );
+
+        let from_chrono: ParseDate = DateTime::parse_from_rfc3339("2024-03-01T12:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+            .into();
+        assert_eq!(
+            serde_json::to_value(json!({ "dueAt": from_chrono })).unwrap(),
+            json!({ "dueAt": { "__type": "Date", "iso": "2024-03-01T12:30:00.000Z" } })
+        );
+    }
+}
@@ -1,16 +1,24 @@
 // src/user.rs
 
+use crate::client::RequestOptions;
 use crate::object::{deserialize_string_to_option_parse_date, deserialize_string_to_parse_date};
+use crate::session::ParseSession;
 use crate::types::ParseDate;
 use crate::ParseError;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+// How many sessions are listed per request when revoking a user's sessions.
+const SESSION_PAGE_SIZE: usize = 100;
 
 /// Represents a Parse Server User object.
 ///
 /// This struct contains standard fields for a user, such as `objectId`, `username`, `email`,
-/// `emailVerified`, `sessionToken`, `createdAt`, and `updatedAt`.
+/// `emailVerified`, `sessionToken`, `createdAt`, and `updatedAt`. Any additional custom fields
+/// are collected in `other_fields`.
 /// It is used to deserialize user data received from the Parse Server and can also be
 /// (though less commonly for `ParseUser` itself) used for creating or updating user objects.
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -35,6 +43,20 @@ pub struct ParseUser {
         skip_serializing_if = "Option::is_none"
     )]
     pub updated_at: Option<ParseDate>,
+    /// Any custom fields set on the user (e.g. fields added at signup), keyed by field name.
+    #[serde(flatten, default, skip_serializing_if = "HashMap::is_empty")]
+    pub other_fields: HashMap<String, Value>,
+}
+
+impl TryFrom<Value> for ParseUser {
+    type Error = ParseError;
+
+    /// Converts a JSON object (e.g. embedded in a Cloud function result) into a `ParseUser`.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        serde_json::from_value(value).map_err(|e| {
+            ParseError::JsonDeserializationFailed(format!("Invalid ParseUser: {}", e), e)
+        })
+    }
 }
 
 // New struct for signup response
@@ -91,6 +113,109 @@ pub struct PasswordResetRequest<'a> {
     pub email: &'a str,
 }
 
+/// Changes to apply to the current user with [`ParseUserHandle::update`].
+///
+/// The password is write-only: it is sent to the server but never stored in the [`ParseUser`]
+/// returned by the update, shown by this type's `Debug` output, or written in full to the
+/// client's debug logs.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_rs::user::UserUpdate;
+///
+/// let update = UserUpdate::new()
+///     .email("sean@example.com")
+///     .password("n3w-s3cret")
+///     .set("nickname", "Sean");
+/// assert!(!format!("{:?}", update).contains("n3w-s3cret"));
+/// ```
+#[derive(Clone, Default)]
+pub struct UserUpdate {
+    fields: serde_json::Map<String, Value>,
+    password: Option<String>,
+}
+
+impl UserUpdate {
+    /// Creates an empty update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a new username.
+    pub fn username(self, username: &str) -> Self {
+        self.set("username", username)
+    }
+
+    /// Sets a new email address.
+    pub fn email(self, email: &str) -> Self {
+        self.set("email", email)
+    }
+
+    /// Sets a new password.
+    pub fn password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Sets any other field. Values that fail to serialize are ignored, and a `password` field
+    /// is treated like [`password`](Self::password).
+    pub fn set<V: Serialize>(mut self, key: &str, value: V) -> Self {
+        match serde_json::to_value(value) {
+            Ok(Value::String(password)) if key == "password" => self.password = Some(password),
+            Ok(_) if key == "password" => {}
+            Ok(value) => {
+                self.fields.insert(key.to_string(), value);
+            }
+            Err(_) => {}
+        }
+        self
+    }
+
+    /// Returns `true` if no field or password is set.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.password.is_none()
+    }
+}
+
+impl std::fmt::Debug for UserUpdate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UserUpdate")
+            .field("fields", &self.fields)
+            .field("password", &self.password.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
+
+impl Serialize for UserUpdate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+        for (key, value) in &self.fields {
+            map.serialize_entry(key, value)?;
+        }
+        if let Some(password) = &self.password {
+            map.serialize_entry("password", password)?;
+        }
+        map.end()
+    }
+}
+
+// Builds the user created by a signup from the submitted fields and the server's response, for
+// `Parse::current_user`. Returns `None` if the fields do not describe a user (e.g. no username).
+fn signed_up_user<T: Serialize>(user_data: &T, response: &SignupResponse) -> Option<ParseUser> {
+    let Ok(Value::Object(mut fields)) = serde_json::to_value(user_data) else {
+        return None;
+    };
+    fields.remove("password");
+    fields.insert("objectId".to_string(), json!(response.object_id));
+    fields.insert("sessionToken".to_string(), json!(response.session_token));
+    fields.insert("createdAt".to_string(), json!(response.created_at.iso));
+    fields.insert("updatedAt".to_string(), json!(response.created_at.iso));
+    ParseUser::try_from(Value::Object(fields)).ok()
+}
+
 /// Provides methods for managing user authentication and user-specific operations.
 ///
 /// An instance of `ParseUserHandle` is obtained by calling the [`user()`](crate::Parse::user)
@@ -200,12 +325,146 @@ impl ParseUserHandle<'_> {
             Ok(response) => {
                 // Assuming SignupResponse contains a session_token field
                 self.client.session_token = Some(response.session_token.clone());
+                self.client.current_user = signed_up_user(user_data, &response);
                 Ok(response)
             }
             Err(e) => Err(e),
         }
     }
 
+    /// Signs up a new user and attributes the created session to the given installation.
+    ///
+    /// Behaves like [`signup`](Self::signup), but sends `installation_id` as the
+    /// `X-Parse-Installation-Id` header so the server records it on the new session.
+    /// This is what Parse uses to target push notifications and manage sessions per device.
+    pub async fn signup_with_installation_id<T: Serialize + Send + Sync>(
+        &mut self,
+        user_data: &T,
+        installation_id: &str,
+    ) -> Result<SignupResponse, ParseError> {
+        let options = RequestOptions {
+            installation_id: Some(installation_id),
+            ..Default::default()
+        };
+        let response: SignupResponse = self
+            .client
+            ._request_with_options(Method::POST, "users", Some(user_data), options)
+            .await?;
+        self.client.session_token = Some(response.session_token.clone());
+        self.client.current_user = signed_up_user(user_data, &response);
+        Ok(response)
+    }
+
+    /// Creates an anonymous user and makes it the current user.
+    ///
+    /// Sends a random UUID as anonymous `authData` to the `/users` endpoint. Parse Server creates a
+    /// user with a random username for it and returns a session token, which is stored in the
+    /// `Parse` instance like after [`signup`](Self::signup). This is useful for guest flows where
+    /// an account is only created later; see [`upgrade_anonymous`](Self::upgrade_anonymous).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use parse_rs::{Parse, ParseError};
+    ///
+    /// # async fn run(client: &mut Parse) -> Result<(), ParseError> {
+    /// let guest = client.user().login_anonymous().await?;
+    /// println!("Guest user: {}", guest.object_id);
+    ///
+    /// // Later, once the guest picks credentials:
+    /// let user = client.user().upgrade_anonymous("sean", "s3cret").await?;
+    /// assert_eq!(user.object_id.as_deref(), Some(guest.object_id.as_str()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn login_anonymous(&mut self) -> Result<SignupResponse, ParseError> {
+        self.login_anonymous_with_options(RequestOptions::default())
+            .await
+    }
+
+    /// Creates an anonymous user and attributes the created session to the given installation.
+    ///
+    /// Behaves like [`login_anonymous`](Self::login_anonymous), but sends `installation_id` as
+    /// the `X-Parse-Installation-Id` header so the server records it on the new session.
+    pub async fn login_anonymous_with_installation_id(
+        &mut self,
+        installation_id: &str,
+    ) -> Result<SignupResponse, ParseError> {
+        self.login_anonymous_with_options(RequestOptions {
+            installation_id: Some(installation_id),
+            ..Default::default()
+        })
+        .await
+    }
+
+    async fn login_anonymous_with_options(
+        &mut self,
+        options: RequestOptions<'_>,
+    ) -> Result<SignupResponse, ParseError> {
+        let body = json!({
+            "authData": { "anonymous": { "id": Uuid::new_v4().to_string() } }
+        });
+        let user: Value = self
+            .client
+            ._request_with_options(Method::POST, "users", Some(&body), options)
+            .await?;
+        let response = SignupResponse::deserialize(&user).map_err(|e| {
+            ParseError::JsonDeserializationFailed(format!("Invalid signup response: {}", e), e)
+        })?;
+        self.client.session_token = Some(response.session_token.clone());
+        self.client.current_user = ParseUser::try_from(user).ok();
+        Ok(response)
+    }
+
+    /// Turns the current anonymous user into a regular account with the given credentials.
+    ///
+    /// Sets `username` and `password` on the current user and unlinks its anonymous `authData`
+    /// with a `PUT` to `/users/{objectId}`, so the user keeps its `objectId` and all data that
+    /// points to it. Afterwards the user can log in with the new credentials. If the server
+    /// issues a new session token for the update, it replaces the stored one.
+    ///
+    /// Returns the upgraded user. Fails with `ParseError::SessionTokenMissing` if there is no
+    /// current user, and with `ParseError::UsernameTaken` if the username is already in use.
+    pub async fn upgrade_anonymous(
+        &mut self,
+        username: &str,
+        password: &str,
+    ) -> Result<ParseUser, ParseError> {
+        let mut user = self.me().await?;
+        let object_id = user.object_id.clone().ok_or_else(|| {
+            ParseError::UnexpectedResponse("Current user has no objectId".to_string())
+        })?;
+        let body = json!({
+            "username": username,
+            "password": password,
+            "authData": { "anonymous": null },
+        });
+        let response: Value = self
+            .client
+            ._request(
+                Method::PUT,
+                &format!("users/{}", object_id),
+                Some(&body),
+                false,
+                None,
+            )
+            .await?;
+
+        if let Some(token) = response.get("sessionToken").and_then(Value::as_str) {
+            self.client.session_token = Some(token.to_string());
+        }
+        user.username = username.to_string();
+        user.session_token = self.client.session_token.clone();
+        if let Some(updated_at) = response.get("updatedAt").and_then(Value::as_str) {
+            user.updated_at = Some(ParseDate::new(updated_at));
+        }
+        if let Some(Value::Object(auth_data)) = user.other_fields.get_mut("authData") {
+            auth_data.remove("anonymous");
+        }
+        self.client.current_user = Some(user.clone());
+        Ok(user)
+    }
+
     /// Logs in an existing user with the Parse Server.
     ///
     /// This method sends the provided user credentials (typically username and password) to the `/login` endpoint.
@@ -271,12 +530,35 @@ impl ParseUserHandle<'_> {
         {
             Ok(user_response) => {
                 self.client.session_token = user_response.session_token.clone();
+                self.client.current_user = Some(user_response.clone());
                 Ok(user_response)
             }
             Err(e) => Err(e),
         }
     }
 
+    /// Logs in an existing user and attributes the created session to the given installation.
+    ///
+    /// Behaves like [`login`](Self::login), but sends `installation_id` as the
+    /// `X-Parse-Installation-Id` header so the server records it on the new session.
+    pub async fn login_with_installation_id<T: Serialize + Send + Sync>(
+        &mut self,
+        user_data: &T,
+        installation_id: &str,
+    ) -> Result<ParseUser, ParseError> {
+        let options = RequestOptions {
+            installation_id: Some(installation_id),
+            ..Default::default()
+        };
+        let user_response: ParseUser = self
+            .client
+            ._request_with_options(Method::POST, "login", Some(user_data), options)
+            .await?;
+        self.client.session_token = user_response.session_token.clone();
+        self.client.current_user = Some(user_response.clone());
+        Ok(user_response)
+    }
+
     // GET /users/me - requires session token
     /// Fetches the details of the currently authenticated user.
     ///
@@ -336,6 +618,72 @@ impl ParseUserHandle<'_> {
             .await
     }
 
+    /// Updates the current user with a `PUT` to `/users/{objectId}`.
+    ///
+    /// Returns the current user with the update applied. The password, if the update sets one,
+    /// is never part of the returned user. Parse Server keeps the current session valid when the
+    /// password changes (and may revoke the user's other sessions); if it issues a new session
+    /// token, that token replaces the stored one.
+    ///
+    /// Fails with `ParseError::SessionTokenMissing` if there is no current user, and with
+    /// `ParseError::InvalidInput` if the update is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use parse_rs::{Parse, ParseError, user::UserUpdate};
+    ///
+    /// # async fn run(client: &mut Parse) -> Result<(), ParseError> {
+    /// let update = UserUpdate::new().email("sean@example.com").password("n3w-s3cret");
+    /// let user = client.user().update(&update).await?;
+    /// assert_eq!(user.email.as_deref(), Some("sean@example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update(&mut self, update: &UserUpdate) -> Result<ParseUser, ParseError> {
+        if update.is_empty() {
+            return Err(ParseError::InvalidInput(
+                "A user update must set at least one field".to_string(),
+            ));
+        }
+        let user = self.me().await?;
+        let object_id = user.object_id.clone().ok_or_else(|| {
+            ParseError::UnexpectedResponse("Current user has no objectId".to_string())
+        })?;
+        let response: Value = self
+            .client
+            ._request(
+                Method::PUT,
+                &format!("users/{}", object_id),
+                Some(update),
+                false,
+                None,
+            )
+            .await?;
+        if let Some(token) = response.get("sessionToken").and_then(Value::as_str) {
+            self.client.session_token = Some(token.to_string());
+        }
+
+        let mut updated = user;
+        for (key, value) in &update.fields {
+            match (key.as_str(), value) {
+                ("username", Value::String(username)) => updated.username = username.clone(),
+                ("email", Value::String(email)) => updated.email = Some(email.clone()),
+                ("email", Value::Null) => updated.email = None,
+                _ => {
+                    updated.other_fields.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        if let Some(updated_at) = response.get("updatedAt").and_then(Value::as_str) {
+            updated.updated_at = Some(ParseDate::new(updated_at));
+        }
+        updated.session_token = self.client.session_token.clone();
+        updated.other_fields.remove("password");
+        self.client.current_user = Some(updated.clone());
+        Ok(updated)
+    }
+
     // POST /logout - requires session token
     /// Logs out the currently authenticated user.
     ///
@@ -403,12 +751,105 @@ impl ParseUserHandle<'_> {
             Ok(_value) => {
                 // Parse server returns an empty JSON object {} on successful logout
                 self.client.session_token = None;
+                self.client.current_user = None;
                 Ok(())
             }
             Err(e) => Err(e),
         }
     }
 
+    /// Signs the current user out of every device by deleting all of their `_Session` objects,
+    /// including the current one, then clears the client's session token like
+    /// [`logout`](Self::logout).
+    ///
+    /// Returns the number of sessions deleted. Requires the Master Key, since sessions of other
+    /// installations can only be listed and deleted with it; fails with
+    /// `ParseError::MasterKeyRequired` without one and with `ParseError::SessionTokenMissing`
+    /// if there is no current user.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use parse_rs::{Parse, ParseError};
+    ///
+    /// # async fn run(client: &mut Parse) -> Result<(), ParseError> {
+    /// let revoked = client.user().logout_all().await?;
+    /// println!("Signed out of {} sessions", revoked);
+    /// assert!(!client.is_authenticated());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn logout_all(&mut self) -> Result<usize, ParseError> {
+        let (current, sessions) = self.current_user_sessions().await?;
+        let session_handle = self.client.session();
+        let mut revoked = 0;
+        // The current session goes last, so a failure part-way leaves this client usable.
+        for session in sessions.iter().filter(|s| s.object_id != current.object_id) {
+            session_handle
+                .delete_by_object_id(&session.object_id)
+                .await?;
+            revoked += 1;
+        }
+        session_handle
+            .delete_by_object_id(&current.object_id)
+            .await?;
+        self.client.session_token = None;
+        self.client.current_user = None;
+        Ok(revoked + 1)
+    }
+
+    /// Deletes all of the current user's `_Session` objects except the current one, signing the
+    /// user out of every other device while staying logged in here.
+    ///
+    /// Returns the number of sessions deleted. Has the same requirements as
+    /// [`logout_all`](Self::logout_all).
+    pub async fn revoke_other_sessions(&self) -> Result<usize, ParseError> {
+        let (current, sessions) = self.current_user_sessions().await?;
+        let session_handle = self.client.session();
+        let mut revoked = 0;
+        for session in sessions.iter().filter(|s| s.object_id != current.object_id) {
+            session_handle
+                .delete_by_object_id(&session.object_id)
+                .await?;
+            revoked += 1;
+        }
+        Ok(revoked)
+    }
+
+    // Returns the current session and every session of its user, listed a page at a time with
+    // the Master Key.
+    async fn current_user_sessions(&self) -> Result<(ParseSession, Vec<ParseSession>), ParseError> {
+        if self.client.master_key.is_none() {
+            return Err(ParseError::MasterKeyRequired(
+                "Master key is required to list and delete the sessions of a user.".to_string(),
+            ));
+        }
+        let session_handle = self.client.session();
+        let current = session_handle.me().await?;
+        let user = current.user_pointer().ok_or_else(|| {
+            ParseError::UnexpectedResponse(
+                "The current session does not reference a user".to_string(),
+            )
+        })?;
+        let where_clause = json!({ "user": user }).to_string();
+
+        let mut sessions = Vec::new();
+        loop {
+            let query_string = url::form_urlencoded::Serializer::new(String::new())
+                .append_pair("where", &where_clause)
+                .append_pair("order", "createdAt")
+                .append_pair("limit", &SESSION_PAGE_SIZE.to_string())
+                .append_pair("skip", &sessions.len().to_string())
+                .finish();
+            let page = session_handle.get_all_sessions(Some(&query_string)).await?;
+            // Pages may be cut short by the server's `maxLimit`; only an empty one is the end.
+            if page.is_empty() {
+                return Ok((current, sessions));
+            }
+            sessions.extend(page);
+        }
+    }
+
     // POST /requestPasswordReset - public, no session token needed
     /// Requests a password reset email to be sent to the user associated with the given email address.
     ///
@@ -474,6 +915,75 @@ impl ParseUserHandle<'_> {
         }
     }
 
+    // POST /apps/:appId/request_password_reset - public pages route, form-encoded
+    /// Completes a password reset started with [`request_password_reset`](Self::request_password_reset).
+    ///
+    /// The reset email links to a page carrying the user's `username` and a one-time `token`.
+    /// This method submits the new password with that token directly, so clients without a
+    /// browser (e.g. a mobile app that intercepts the link) can finish the flow.
+    ///
+    /// The request goes to Parse Server's public pages route
+    /// (`POST {server_url}/apps/{app_id}/request_password_reset`) rather than the REST API, as a
+    /// form with the `X-Requested-With: XMLHttpRequest` header so the server answers with a status
+    /// code instead of redirecting to its HTML pages. Parse Server 5.x and later answer this XHR
+    /// form on both the default public API router and the opt-in `pages` router; older servers
+    /// may redirect instead, which cannot be told apart from success. The server must also be
+    /// configured with `publicServerURL` and an email adapter to issue reset tokens at all.
+    /// No API key or session token is sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `username`: The username from the reset link.
+    /// * `new_password`: The password to set.
+    /// * `token`: The reset token from the reset link.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the password was changed, or a `ParseError` if the token is invalid or expired,
+    /// the new password violates the server's password policy, or the request fails.
+    pub async fn reset_password_with_token(
+        &self,
+        username: &str,
+        new_password: &str,
+        token: &str,
+    ) -> Result<(), ParseError> {
+        if username.is_empty() || new_password.is_empty() || token.is_empty() {
+            return Err(ParseError::InvalidInput(
+                "Username, new password and token are required to reset a password".to_string(),
+            ));
+        }
+
+        let endpoint = format!("apps/{}/request_password_reset", self.client.app_id);
+        let url = self.client._api_url(&endpoint)?;
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("username", username)
+            .append_pair("token", token)
+            .append_pair("new_password", new_password)
+            .finish();
+
+        log::debug!("Preparing request: Method=POST, URL={}", url.as_str());
+        let request_builder = self
+            .client
+            .http_client
+            .post(url)
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                "application/x-www-form-urlencoded",
+            )
+            .header("X-Requested-With", "XMLHttpRequest")
+            .body(body);
+        let response = self.client.send_request(request_builder).await?;
+
+        // The success body is plain text ("Password successfully reset"), not JSON.
+        if response.status().is_success() {
+            return Ok(());
+        }
+        self.client
+            ._send_and_process_response::<Value>(response, &endpoint)
+            .await
+            .map(|_| ())
+    }
+
     // GET /users/me - but with a different session token to become that user
     // This is a tricky one. The `become` operation itself is a GET to /users/me, but authenticated with the *target* session token.
     // The client's current session token is replaced upon success.
@@ -560,11 +1070,12 @@ impl ParseUserHandle<'_> {
 
         match self
             .client
-            ._request(Method::GET, "users/me", None::<&Value>, false, None)
+            ._request::<_, ParseUser>(Method::GET, "users/me", None::<&Value>, false, None)
             .await
         {
             Ok(user_data) => {
                 // self.session_token is already set to session_token_to_become, so this is correct.
+                self.client.current_user = Some(user_data.clone());
                 Ok(user_data)
             }
             Err(e) => {
@@ -163,3 +163,51 @@ async fn test_set_and_retrieve_user_specific_acl() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_acl_accessor_and_update_object_acl() {
+    let client = setup_client_with_master_key();
+    let class_name = "ACLTestObjectAccessor";
+
+    let mut acl = ParseACL::new();
+    acl.set_role_read_access("Moderators", true);
+    acl.set_role_write_access("Moderators", true);
+
+    let mut obj_to_create = ParseObject::new(class_name);
+    obj_to_create.set("name", "ObjectWithRoleAcl");
+    obj_to_create.set_acl(acl.clone());
+    let created = client
+        .create_object(class_name, &obj_to_create)
+        .await
+        .expect("Failed to create object with ACL");
+
+    let retrieved = client
+        .retrieve_object(class_name, &created.object_id)
+        .await
+        .expect("Failed to retrieve object with ACL");
+    assert_eq!(retrieved.acl(), Some(acl.clone()));
+
+    // Make it public and save only the ACL.
+    let mut public_acl = retrieved.acl().unwrap();
+    public_acl.set_public_read_access(true);
+    client
+        .update_object_acl(class_name, &created.object_id, &public_acl)
+        .await
+        .expect("Failed to update object ACL");
+
+    let updated = client
+        .retrieve_object(class_name, &created.object_id)
+        .await
+        .expect("Failed to retrieve object after ACL update");
+    assert_eq!(updated.acl(), Some(public_acl));
+    assert_eq!(
+        updated.fields.get("name"),
+        Some(&json!("ObjectWithRoleAcl"))
+    );
+
+    let endpoint = format!("classes/{}/{}", class_name, created.object_id);
+    client
+        .delete_object_with_master_key(&endpoint)
+        .await
+        .expect("Failed to cleanup object");
+}
@@ -0,0 +1,105 @@
+use parse_rs::{AggregatePipeline, Parse, ParseError};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod aggregate_paging_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct PlayerTotal {
+        #[serde(rename = "objectId")]
+        player: String,
+        total: i64,
+    }
+
+    // Answers with five grouped totals, sorted by total, applying the trailing `$skip` and
+    // `$limit` stages of the pipeline.
+    fn respond(req: &RecordedRequest) -> MockResponse {
+        let pipeline: Vec<Value> = serde_json::from_str(&req.query_params()["pipeline"]).unwrap();
+        let skip = pipeline
+            .iter()
+            .find_map(|stage| stage["$skip"].as_u64())
+            .unwrap_or(0) as usize;
+        let limit = pipeline
+            .iter()
+            .find_map(|stage| stage["$limit"].as_u64())
+            .unwrap() as usize;
+        let results: Vec<Value> = [("ana", 90), ("bo", 70), ("cy", 50), ("di", 30), ("ed", 10)]
+            .iter()
+            .skip(skip)
+            .take(limit)
+            .map(|(player, total)| json!({ "objectId": player, "total": total }))
+            .collect();
+        MockResponse::json(200, json!({ "results": results }))
+    }
+
+    #[tokio::test]
+    async fn test_pages_through_grouped_results_in_chunks_of_two() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+        let leaderboard = AggregatePipeline::new()
+            .group(json!({ "_id": "$playerName", "total": { "$sum": "$score" } }))
+            .sort(json!({ "total": -1 }));
+
+        let mut pages = Vec::new();
+        let mut skip = Some(0);
+        while let Some(current) = skip {
+            let page = client
+                .execute_aggregate_paged::<PlayerTotal>(
+                    "GameScore",
+                    leaderboard.to_value(),
+                    current,
+                    2,
+                )
+                .await
+                .unwrap();
+            skip = page.next_skip();
+            pages.push(
+                page.results
+                    .iter()
+                    .map(|row| format!("{}:{}", row.player, row.total))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        assert_eq!(
+            pages,
+            vec![
+                vec!["ana:90", "bo:70"],
+                vec!["cy:50", "di:30"],
+                vec!["ed:10"]
+            ]
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        let second: Value = serde_json::from_str(&requests[1].query_params()["pipeline"]).unwrap();
+        assert_eq!(
+            second,
+            json!([
+                { "$group": { "_id": "$playerName", "total": { "$sum": "$score" } } },
+                { "$sort": { "total": -1 } },
+                { "$skip": 2 },
+                { "$limit": 3 }
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_paging_arguments_are_rejected() {
+        let client =
+            Parse::new("http://localhost:1/parse", "appId", None, None, Some("mk")).unwrap();
+        let not_an_array = client
+            .execute_aggregate_paged::<Value>("GameScore", json!({ "$limit": 1 }), 0, 2)
+            .await;
+        assert!(matches!(not_an_array, Err(ParseError::InvalidInput(_))));
+
+        let empty_page = client
+            .execute_aggregate_paged::<Value>("GameScore", json!([]), 0, 0)
+            .await;
+        assert!(matches!(empty_page, Err(ParseError::InvalidInput(_))));
+    }
+}
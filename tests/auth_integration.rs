@@ -1,5 +1,5 @@
 use parse_rs::user::{LoginRequest, PasswordResetRequest, SignupRequest};
-use parse_rs::ParseError;
+use parse_rs::{ParseError, ParseUser};
 use uuid::Uuid;
 
 mod query_test_utils;
@@ -506,4 +506,168 @@ mod auth_tests {
             "Client should not be authenticated after final logout"
         );
     }
+
+    #[tokio::test]
+    async fn test_login_and_signup_with_installation_id() {
+        let mut client = setup_client();
+        let username = generate_unique_username();
+        let password = "testpassword123";
+        let signup_installation_id = Uuid::new_v4().to_string();
+
+        let signup_request = SignupRequest {
+            username: &username,
+            password,
+            email: None,
+        };
+        client
+            .user()
+            .signup_with_installation_id(&signup_request, &signup_installation_id)
+            .await
+            .expect("Signup with installation id failed");
+        let signup_session = client
+            .session()
+            .me()
+            .await
+            .expect("Failed to fetch session after signup");
+        assert_eq!(
+            signup_session.installation_id.as_deref(),
+            Some(signup_installation_id.as_str())
+        );
+
+        let login_installation_id = Uuid::new_v4().to_string();
+        let login_request = LoginRequest {
+            username: &username,
+            password,
+        };
+        client
+            .user()
+            .login_with_installation_id(&login_request, &login_installation_id)
+            .await
+            .expect("Login with installation id failed");
+        let login_session = client
+            .session()
+            .me()
+            .await
+            .expect("Failed to fetch session after login");
+        assert_eq!(
+            login_session.installation_id.as_deref(),
+            Some(login_installation_id.as_str())
+        );
+
+        client.user().logout().await.expect("Logout failed");
+    }
+
+    #[tokio::test]
+    async fn test_signup_custom_fields_survive_me() {
+        let mut client = setup_client();
+        let username = generate_unique_username();
+        let signup_data = serde_json::json!({
+            "username": username,
+            "password": "testpassword123",
+            "customField": "customValue",
+            "age": 30
+        });
+        client
+            .user()
+            .signup(&signup_data)
+            .await
+            .expect("Signup with custom fields failed");
+
+        let me = client
+            .user()
+            .me()
+            .await
+            .expect("Failed to fetch current user");
+        assert_eq!(me.username, username);
+        assert!(me.object_id.is_some());
+        assert!(me.created_at.is_some());
+        assert_eq!(
+            me.other_fields.get("customField").and_then(|v| v.as_str()),
+            Some("customValue")
+        );
+        assert_eq!(
+            me.other_fields.get("age").and_then(|v| v.as_i64()),
+            Some(30)
+        );
+        // Typed fields are not duplicated into other_fields.
+        assert!(!me.other_fields.contains_key("username"));
+        assert!(!me.other_fields.contains_key("objectId"));
+
+        client.user().logout().await.expect("Logout failed");
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_user_upgrade_and_login() {
+        let mut client = setup_client();
+        let guest = client
+            .user()
+            .login_anonymous()
+            .await
+            .expect("Anonymous login failed");
+
+        let username = generate_unique_username();
+        let upgraded = client
+            .user()
+            .upgrade_anonymous(&username, "testpassword123")
+            .await
+            .expect("Upgrading the anonymous user failed");
+        assert_eq!(
+            upgraded.object_id.as_deref(),
+            Some(guest.object_id.as_str())
+        );
+        assert_eq!(upgraded.username, username);
+
+        client.user().logout().await.expect("Logout failed");
+        let user = client
+            .user()
+            .login(&LoginRequest {
+                username: &username,
+                password: "testpassword123",
+            })
+            .await
+            .expect("Login with the upgraded credentials failed");
+        assert_eq!(user.object_id.as_deref(), Some(guest.object_id.as_str()));
+
+        client.user().logout().await.expect("Logout failed");
+    }
+
+    #[tokio::test]
+    async fn test_query_and_delete_users_with_master_key() {
+        let mut client = setup_client();
+        let admin = super::query_test_utils::shared::setup_client_with_master_key();
+        let marker = format!("bulk_{}", Uuid::new_v4().simple());
+
+        let mut user_ids = Vec::new();
+        for _ in 0..2 {
+            let username = format!("{}_{}", marker, Uuid::new_v4().simple());
+            let signup_request = SignupRequest {
+                username: &username,
+                password: "testpassword123",
+                email: None,
+            };
+            let signup = client
+                .user()
+                .signup(&signup_request)
+                .await
+                .expect("Signup failed");
+            user_ids.push(signup.object_id);
+        }
+
+        let mut query = admin.query_users();
+        query.starts_with("username", &marker);
+        let users: Vec<ParseUser> = query.find(&admin).await.expect("User query failed");
+        let mut found_ids: Vec<String> = users.into_iter().filter_map(|u| u.object_id).collect();
+        found_ids.sort();
+        let mut expected_ids = user_ids.clone();
+        expected_ids.sort();
+        assert_eq!(found_ids, expected_ids);
+
+        for user_id in &user_ids {
+            admin
+                .delete_user(user_id)
+                .await
+                .expect("delete_user failed");
+        }
+        assert_eq!(query.count(&admin).await.expect("User count failed"), 0);
+    }
 }
@@ -0,0 +1,123 @@
+use parse_rs::{BatchOp, Parse, ParseBatch, ParseError};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod batch_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    // Applies the Increment sub-requests of a batch to `scores` and answers like Parse Server,
+    // with the new field value in each success entry. Unknown objects produce an error entry.
+    fn respond(scores: &Mutex<HashMap<String, i64>>, req: &RecordedRequest) -> MockResponse {
+        let body: Value = serde_json::from_str(&req.body).unwrap();
+        let mut scores = scores.lock().unwrap();
+        let results: Vec<Value> = body["requests"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|request| {
+                let object_id = request["path"]
+                    .as_str()
+                    .unwrap()
+                    .rsplit('/')
+                    .next()
+                    .unwrap();
+                let amount = request["body"]["score"]["amount"].as_i64().unwrap();
+                match scores.get_mut(object_id) {
+                    Some(score) => {
+                        *score += amount;
+                        json!({ "success": {
+                            "score": *score,
+                            "updatedAt": "2024-01-01T00:00:00.000Z"
+                        } })
+                    }
+                    None => json!({ "error": { "code": 101, "error": "Object not found." } }),
+                }
+            })
+            .collect();
+        MockResponse::json(200, json!(results))
+    }
+
+    fn start_scores() -> Arc<Mutex<HashMap<String, i64>>> {
+        Arc::new(Mutex::new(HashMap::from([
+            ("alice00001".to_string(), 10),
+            ("bob0000001".to_string(), 20),
+            ("carol00001".to_string(), 30),
+        ])))
+    }
+
+    #[tokio::test]
+    async fn test_batch_increments_on_three_objects() {
+        let scores = start_scores();
+        let state = scores.clone();
+        let server = MockServer::start(move |req| respond(&state, req)).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let mut batch = ParseBatch::new();
+        batch
+            .increment("GameScore", "alice00001", "score", 5)
+            .increment("GameScore", "bob0000001", "score", -3)
+            .add(BatchOp::Increment {
+                class: "GameScore".to_string(),
+                object_id: "carol00001".to_string(),
+                field: "score".to_string(),
+                amount: 100,
+            });
+        assert_eq!(batch.len(), 3);
+
+        let results = batch.execute(&client).await.unwrap();
+        let new_scores: Vec<i64> = results
+            .into_iter()
+            .map(|result| result.unwrap()["score"].as_i64().unwrap())
+            .collect();
+        assert_eq!(new_scores, vec![15, 17, 130]);
+        assert_eq!(scores.lock().unwrap()["carol00001"], 130);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path_only(), "/parse/batch");
+        let body: Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(
+            body["requests"][0],
+            json!({
+                "method": "PUT",
+                "path": "/parse/classes/GameScore/alice00001",
+                "body": { "score": { "__op": "Increment", "amount": 5 } }
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_batch_reports_failed_operations_individually() {
+        let scores = start_scores();
+        let server = MockServer::start(move |req| respond(&scores, req)).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let mut batch = ParseBatch::new();
+        batch
+            .increment("GameScore", "missing001", "score", 1)
+            .increment("GameScore", "alice00001", "score", 1);
+        let results = batch.execute(&client).await.unwrap();
+
+        assert!(matches!(results[0], Err(ParseError::ObjectNotFound(_))));
+        assert_eq!(results[1].as_ref().unwrap()["score"], json!(11));
+    }
+
+    #[tokio::test]
+    async fn test_oversized_batch_is_rejected() {
+        let client = Parse::new("http://localhost:1/parse", "appId", None, None, None).unwrap();
+        let mut batch = ParseBatch::new();
+        for i in 0..51 {
+            batch.increment("GameScore", &format!("obj{:07}", i), "score", 1);
+        }
+        assert!(matches!(
+            batch.execute(&client).await,
+            Err(ParseError::InvalidInput(_))
+        ));
+    }
+}
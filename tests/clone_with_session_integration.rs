@@ -0,0 +1,76 @@
+use parse_rs::{Parse, ParseError};
+use serde_json::json;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod clone_with_session_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    // Answers `users/me` with the user owning the request's session token.
+    fn respond(req: &RecordedRequest) -> MockResponse {
+        let username = match req.header("X-Parse-Session-Token") {
+            Some("r:alice") => "alice",
+            Some("r:bob") => "bob",
+            _ => {
+                return MockResponse::json(
+                    400,
+                    json!({ "code": 209, "error": "Invalid session token" }),
+                )
+            }
+        };
+        MockResponse::json(
+            200,
+            json!({
+                "objectId": format!("{}000000", username),
+                "username": username,
+                "createdAt": "2024-01-01T00:00:00.000Z",
+                "updatedAt": "2024-01-01T00:00:00.000Z"
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_cloned_clients_use_their_own_session_tokens() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let mut alice = client.clone_with_session(Some("r:alice".to_string()));
+        let mut bob = client.clone_with_session(Some("r:bob".to_string()));
+        assert_eq!(alice.session_token(), Some("r:alice"));
+        assert_eq!(bob.session_token(), Some("r:bob"));
+        assert_eq!(client.session_token(), None);
+
+        let (alice_handle, bob_handle) = (alice.user(), bob.user());
+        let (alice_user, bob_user) = tokio::join!(alice_handle.me(), bob_handle.me());
+        assert_eq!(alice_user.unwrap().username, "alice");
+        assert_eq!(bob_user.unwrap().username, "bob");
+
+        let mut tokens: Vec<String> = server
+            .requests()
+            .iter()
+            .map(|req| req.header("X-Parse-Session-Token").unwrap().to_string())
+            .collect();
+        tokens.sort();
+        assert_eq!(tokens, vec!["r:alice", "r:bob"]);
+        for request in server.requests() {
+            assert_eq!(request.header("X-Parse-REST-API-Key"), Some("restKey"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_clone_without_session() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+        let mut signed_in = client.clone_with_session(Some("r:alice".to_string()));
+
+        let mut anonymous = signed_in.clone_with_session(None);
+        assert_eq!(anonymous.session_token(), None);
+        assert!(matches!(
+            anonymous.user().me().await,
+            Err(ParseError::SessionTokenMissing)
+        ));
+        assert_eq!(signed_in.user().me().await.unwrap().username, "alice");
+    }
+}
@@ -0,0 +1,59 @@
+use parse_rs::{Parse, ParseError};
+use serde_json::json;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod cloud_auth_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    fn respond(_req: &RecordedRequest) -> MockResponse {
+        MockResponse::json(200, json!({ "result": "ok" }))
+    }
+
+    #[tokio::test]
+    async fn test_run_sends_session_token() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey"))
+            .unwrap()
+            .clone_with_session(Some("r:alice".to_string()));
+
+        let result: String = client.cloud().run("whoAmI", &json!({})).await.unwrap();
+        assert_eq!(result, "ok");
+
+        let request = &server.requests()[0];
+        assert_eq!(request.path_only(), "/parse/functions/whoAmI");
+        assert_eq!(request.header("X-Parse-Session-Token"), Some("r:alice"));
+        assert_eq!(request.header("X-Parse-Master-Key"), None);
+    }
+
+    #[tokio::test]
+    async fn test_run_as_master_sends_master_key_without_session() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey"))
+            .unwrap()
+            .clone_with_session(Some("r:alice".to_string()));
+
+        let result: String = client
+            .cloud()
+            .run_as_master("whoAmI", &json!({}))
+            .await
+            .unwrap();
+        assert_eq!(result, "ok");
+
+        let request = &server.requests()[0];
+        assert_eq!(request.header("X-Parse-Master-Key"), Some("masterKey"));
+        assert_eq!(request.header("X-Parse-Session-Token"), None);
+    }
+
+    #[tokio::test]
+    async fn test_run_as_master_requires_master_key() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let result: Result<String, _> = client.cloud().run_as_master("whoAmI", &json!({})).await;
+        assert!(matches!(result, Err(ParseError::MasterKeyRequired(_))));
+        assert!(server.requests().is_empty());
+    }
+}
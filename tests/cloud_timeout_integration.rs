@@ -0,0 +1,67 @@
+use parse_rs::{Parse, ParseError};
+use serde_json::json;
+use std::time::Duration;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod cloud_timeout_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    // "slowReport" takes a second to respond; every other function answers immediately.
+    fn respond(req: &RecordedRequest) -> MockResponse {
+        let response = MockResponse::json(200, json!({ "result": "done" }));
+        if req.path_only() == "/parse/functions/slowReport" {
+            response.with_delay(Duration::from_secs(1))
+        } else {
+            response
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_with_timeout_expires_for_slow_function() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let result: Result<String, _> = client
+            .cloud()
+            .run_with_timeout("slowReport", &json!({}), Duration::from_millis(100))
+            .await;
+        assert!(
+            matches!(result, Err(ParseError::Timeout(_))),
+            "Expected a timeout error, got {:?}",
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_with_timeout_succeeds_within_limit() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let quick: String = client
+            .cloud()
+            .run_with_timeout(
+                "hello",
+                &json!({ "name": "World" }),
+                Duration::from_millis(500),
+            )
+            .await
+            .expect("Quick function should finish within its timeout");
+        assert_eq!(quick, "done");
+
+        // A longer per-call timeout lets the slow function finish.
+        let slow: String = client
+            .cloud()
+            .run_with_timeout("slowReport", &json!({}), Duration::from_secs(5))
+            .await
+            .expect("Slow function should finish within the longer timeout");
+        assert_eq!(slow, "done");
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(body, json!({ "name": "World" }));
+    }
+}
@@ -0,0 +1,79 @@
+use parse_rs::{Parse, ParseDate, ParseError};
+use serde_json::json;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod conditional_update_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    const CURRENT_UPDATED_AT: &str = "2024-05-01T12:00:00.000Z";
+
+    fn respond(req: &RecordedRequest) -> MockResponse {
+        match req.method.as_str() {
+            "GET" => MockResponse::json(
+                200,
+                json!({
+                    "objectId": "abcDEF1234",
+                    "createdAt": "2024-01-01T00:00:00.000Z",
+                    "updatedAt": CURRENT_UPDATED_AT
+                }),
+            ),
+            _ => MockResponse::json(200, json!({ "updatedAt": "2024-05-02T00:00:00.000Z" })),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stale_update_is_rejected_without_writing() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let result = client
+            .update_object_if_unchanged(
+                "Doc",
+                "abcDEF1234",
+                &json!({ "title": "stale" }),
+                &ParseDate::new("2024-04-30T08:00:00.000Z"),
+            )
+            .await;
+        assert!(
+            matches!(result, Err(ParseError::Conflict(_))),
+            "Expected Conflict, got {:?}",
+            result
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path_only(), "/parse/classes/Doc/abcDEF1234");
+        assert_eq!(
+            requests[0].query_params().get("keys").map(String::as_str),
+            Some("objectId")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_object_is_updated() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        // The same instant in a different but equivalent format still matches.
+        let response = client
+            .update_object_if_unchanged(
+                "Doc",
+                "abcDEF1234",
+                &json!({ "title": "fresh" }),
+                &ParseDate::new("2024-05-01T12:00:00Z"),
+            )
+            .await
+            .expect("update_object_if_unchanged failed");
+        assert_eq!(response.updated_at.iso, "2024-05-02T00:00:00.000Z");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, "PUT");
+        let body: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+        assert_eq!(body, json!({ "title": "fresh" }));
+    }
+}
@@ -0,0 +1,98 @@
+use parse_rs::{Parse, ParseError};
+use serde::Deserialize;
+use serde_json::json;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod create_and_fetch_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct GameScore {
+        #[serde(rename = "objectId")]
+        object_id: String,
+        #[serde(rename = "createdAt")]
+        created_at: String,
+        #[serde(rename = "updatedAt")]
+        updated_at: String,
+        score: i64,
+        #[serde(rename = "playerName")]
+        player_name: String,
+        rank: String,
+    }
+
+    #[tokio::test]
+    async fn test_returns_the_object_with_server_set_fields() {
+        let server = MockServer::start(|req| match req.method.as_str() {
+            "POST" => MockResponse::json(
+                201,
+                json!({ "objectId": "score00001", "createdAt": "2024-01-01T00:00:00.000Z" }),
+            ),
+            // A beforeSave trigger filled in `rank`.
+            _ => MockResponse::json(
+                200,
+                json!({
+                    "objectId": "score00001",
+                    "createdAt": "2024-01-01T00:00:00.000Z",
+                    "updatedAt": "2024-01-01T00:00:00.000Z",
+                    "score": 1337,
+                    "playerName": "Sean Plott",
+                    "rank": "gold"
+                }),
+            ),
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let score: GameScore = client
+            .create_object_and_fetch(
+                "GameScore",
+                &json!({ "score": 1337, "playerName": "Sean Plott" }),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            score,
+            GameScore {
+                object_id: "score00001".to_string(),
+                created_at: "2024-01-01T00:00:00.000Z".to_string(),
+                updated_at: "2024-01-01T00:00:00.000Z".to_string(),
+                score: 1337,
+                player_name: "Sean Plott".to_string(),
+                rank: "gold".to_string(),
+            }
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[1].method, "GET");
+        assert_eq!(
+            requests[1].path_only(),
+            "/parse/classes/GameScore/score00001"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unexpected_shape_is_a_deserialization_error() {
+        let server = MockServer::start(|req| match req.method.as_str() {
+            "POST" => MockResponse::json(
+                201,
+                json!({ "objectId": "score00001", "createdAt": "2024-01-01T00:00:00.000Z" }),
+            ),
+            _ => MockResponse::json(200, json!({ "objectId": "score00001", "score": "high" })),
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let result = client
+            .create_object_and_fetch::<GameScore, _>("GameScore", &json!({ "score": "high" }))
+            .await;
+        assert!(matches!(
+            result,
+            Err(ParseError::JsonDeserializationFailed(..))
+        ));
+    }
+}
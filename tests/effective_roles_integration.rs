@@ -0,0 +1,113 @@
+use parse_rs::{Parse, ParseError, ParseRole};
+use serde_json::{json, Value};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod effective_roles_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    // (objectId, name, user ids in `users`, role ids in `roles`). Admin contains Editor, which
+    // contains Viewer; Auditor and Editor contain each other, forming a cycle.
+    const ROLES: [(&str, &str, &[&str], &[&str]); 4] = [
+        ("admin00001", "Admin", &[], &["editor0001"]),
+        (
+            "editor0001",
+            "Editor",
+            &["editorUser"],
+            &["viewer0001", "auditor001"],
+        ),
+        ("viewer0001", "Viewer", &["viewerUser"], &[]),
+        ("auditor001", "Auditor", &[], &["editor0001"]),
+    ];
+
+    // Answers `_Role` queries constrained by `users` (a user pointer) or `roles` (`$in` role
+    // pointers), like Parse Server does for relation fields, honoring `skip` and `limit`.
+    fn respond(req: &RecordedRequest) -> MockResponse {
+        let params = req.query_params();
+        let where_clause: Value = serde_json::from_str(&params["where"]).unwrap();
+        let skip: usize = params.get("skip").map_or(0, |s| s.parse().unwrap());
+        let limit: usize = params.get("limit").map_or(100, |s| s.parse().unwrap());
+        let results: Vec<Value> = ROLES
+            .iter()
+            .filter(|(_, _, users, roles)| {
+                if let Some(user) = where_clause["users"]["objectId"].as_str() {
+                    users.contains(&user)
+                } else {
+                    where_clause["roles"]["$in"]
+                        .as_array()
+                        .unwrap()
+                        .iter()
+                        .any(|pointer| roles.contains(&pointer["objectId"].as_str().unwrap()))
+                }
+            })
+            .skip(skip)
+            .take(limit)
+            .map(|(id, name, _, _)| json!({ "objectId": id, "name": name, "ACL": {} }))
+            .collect();
+        MockResponse::json(200, json!({ "results": results }))
+    }
+
+    fn role_names(roles: &[ParseRole]) -> Vec<&str> {
+        roles.iter().map(|role| role.name.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_viewer_inherits_editor_and_admin() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+
+        let roles = client
+            .get_effective_roles_for_user("viewerUser")
+            .await
+            .unwrap();
+        let mut names = role_names(&roles);
+        assert_eq!(names[0], "Viewer");
+        names.sort_unstable();
+        assert_eq!(names, vec!["Admin", "Auditor", "Editor", "Viewer"]);
+
+        let requests = server.requests();
+        assert!(requests
+            .iter()
+            .all(|req| req.path_only() == "/parse/classes/_Role"
+                && req.header("X-Parse-Master-Key") == Some("masterKey")));
+    }
+
+    #[tokio::test]
+    async fn test_role_cycle_terminates_without_duplicates() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+
+        let roles = client
+            .get_effective_roles_for_user("editorUser")
+            .await
+            .unwrap();
+        let mut names = role_names(&roles);
+        names.sort_unstable();
+        assert_eq!(names, vec!["Admin", "Auditor", "Editor"]);
+    }
+
+    #[tokio::test]
+    async fn test_user_without_roles() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+
+        let roles = client
+            .get_effective_roles_for_user("nobody0001")
+            .await
+            .unwrap();
+        assert!(roles.is_empty());
+        assert_eq!(server.requests().len(), 1);
+
+        let validating = Parse::builder(&server.url, "appId")
+            .master_key("masterKey")
+            .validate_object_ids(true)
+            .build()
+            .unwrap();
+        assert!(matches!(
+            validating.get_effective_roles_for_user("bad id").await,
+            Err(ParseError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,60 @@
+use parse_rs::Parse;
+use serde_json::{json, Value};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod fetch_by_ids_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fetch_objects_by_ids_chunks_large_id_lists() {
+        // Echo back one object per requested id.
+        let server = MockServer::start(|req| {
+            let where_clause: Value = serde_json::from_str(&req.query_params()["where"]).unwrap();
+            let results: Vec<Value> = where_clause["objectId"]["$in"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|id| json!({ "objectId": id }))
+                .collect();
+            MockResponse::json(200, json!({ "results": results }))
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let ids: Vec<String> = (0..150).map(|i| format!("obj{:07}", i)).collect();
+        let id_refs: Vec<&str> = ids.iter().map(String::as_str).collect();
+        let fetched: Vec<Value> = client
+            .fetch_objects_by_ids("Item", &id_refs)
+            .await
+            .expect("fetch_objects_by_ids failed");
+        assert_eq!(fetched.len(), 150);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        let chunk_sizes: Vec<(String, String)> = requests
+            .iter()
+            .map(|r| {
+                let params = r.query_params();
+                let where_clause: Value = serde_json::from_str(&params["where"]).unwrap();
+                (
+                    where_clause["objectId"]["$in"]
+                        .as_array()
+                        .unwrap()
+                        .len()
+                        .to_string(),
+                    params["limit"].clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            chunk_sizes,
+            vec![
+                ("100".to_string(), "100".to_string()),
+                ("50".to_string(), "50".to_string())
+            ]
+        );
+    }
+}
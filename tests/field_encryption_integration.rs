@@ -0,0 +1,234 @@
+#![cfg(feature = "encryption")]
+
+use parse_rs::object::RetrievedParseObject;
+use parse_rs::{BatchOp, FieldCipher, Parse, ParseBatch, ParseQuery};
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod field_encryption_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    const KEY: [u8; 32] = [7u8; 32];
+
+    fn patient(ssn: Value) -> Value {
+        json!({
+            "objectId": "aB3dE6gH9j",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "updatedAt": "2024-01-01T00:00:00.000Z",
+            "name": "Alice",
+            "ssn": ssn
+        })
+    }
+
+    // Serves `object` as the only `Patient`, and answers batch requests with one success entry
+    // per sub-request.
+    fn respond(object: &Value, req: &RecordedRequest) -> MockResponse {
+        match (req.method.as_str(), req.path_only()) {
+            ("GET", "/parse/classes/Patient") => {
+                let skip: usize = req
+                    .query_params()
+                    .get("skip")
+                    .map_or(0, |s| s.parse().unwrap());
+                let results = if skip == 0 {
+                    vec![object.clone()]
+                } else {
+                    Vec::new()
+                };
+                MockResponse::json(200, json!({ "results": results, "count": 1 }))
+            }
+            ("GET", _) => MockResponse::json(200, object.clone()),
+            ("POST", "/parse/batch") => {
+                let body: Value = serde_json::from_str(&req.body).unwrap();
+                let results: Vec<Value> = body["requests"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|_| json!({ "success": { "updatedAt": "2024-01-02T00:00:00.000Z" } }))
+                    .collect();
+                MockResponse::json(200, json!(results))
+            }
+            _ => MockResponse::json(404, json!({ "error": "unexpected request" })),
+        }
+    }
+
+    async fn patient_server(ssn: Value) -> (MockServer, Parse) {
+        let object = patient(ssn);
+        let server = MockServer::start(move |req| respond(&object, req)).await;
+        let mut client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+        client.set_field_cipher("ssn", FieldCipher::aes_256_gcm(&KEY));
+        (server, client)
+    }
+
+    // The `ssn` values sent in the sub-requests of the recorded batch requests.
+    fn batched_ssns(server: &MockServer) -> Vec<Value> {
+        server
+            .requests()
+            .iter()
+            .filter(|req| req.path_only() == "/parse/batch")
+            .flat_map(|req| {
+                let body: Value = serde_json::from_str(&req.body).unwrap();
+                body["requests"].as_array().unwrap().clone()
+            })
+            .map(|request| request["body"]["ssn"].clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_field_round_trip() {
+        // Stores the created object as sent and returns it on fetch, like a real server would.
+        let stored: Arc<Mutex<Value>> = Arc::new(Mutex::new(Value::Null));
+        let store = stored.clone();
+        let server = MockServer::start(move |req| {
+            if req.method == "POST" {
+                let mut object: Value = serde_json::from_str(&req.body).unwrap();
+                object["objectId"] = json!("aB3dE6gH9j");
+                object["createdAt"] = json!("2024-01-01T00:00:00.000Z");
+                object["updatedAt"] = json!("2024-01-01T00:00:00.000Z");
+                *store.lock().unwrap() = object;
+                MockResponse::json(
+                    201,
+                    json!({ "objectId": "aB3dE6gH9j", "createdAt": "2024-01-01T00:00:00.000Z" }),
+                )
+            } else {
+                MockResponse::json(200, store.lock().unwrap().clone())
+            }
+        })
+        .await;
+
+        let mut client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+        client.set_field_cipher("ssn", FieldCipher::aes_256_gcm(&[7u8; 32]));
+
+        let created = client
+            .create_object(
+                "Patient",
+                &json!({ "name": "Alice", "ssn": "123-45-6789", "notes": null }),
+            )
+            .await
+            .expect("create_object failed");
+
+        let on_server = stored.lock().unwrap().clone();
+        assert_eq!(on_server["name"], "Alice");
+        let ssn_on_server = on_server["ssn"].as_str().unwrap();
+        assert!(ssn_on_server.starts_with("enc:v1:"));
+        assert!(!ssn_on_server.contains("123-45-6789"));
+        assert_eq!(on_server["notes"], Value::Null);
+
+        let fetched: RetrievedParseObject = client
+            .retrieve_object("Patient", &created.object_id)
+            .await
+            .expect("retrieve_object failed");
+        assert_eq!(fetched.fields["ssn"], json!("123-45-6789"));
+        assert_eq!(fetched.fields["name"], json!("Alice"));
+
+        // A client with a different key cannot read the field.
+        let mut other = Parse::new(&server.url, "appId", None, None, None).unwrap();
+        other.set_field_cipher("ssn", FieldCipher::aes_256_gcm(&[8u8; 32]));
+        assert!(other
+            .retrieve_object("Patient", &created.object_id)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_query_reads_decrypt_fields() {
+        let cipher = FieldCipher::aes_256_gcm(&KEY);
+        let ssn = cipher
+            .encrypt("Patient", "ssn", &json!("123-45-6789"))
+            .unwrap();
+        let (_server, client) = patient_server(ssn).await;
+        let query = ParseQuery::new("Patient");
+        let decrypted = |object: &Value| object["ssn"] == json!("123-45-6789");
+
+        let found: Vec<Value> = query.find(&client).await.unwrap();
+        assert!(decrypted(&found[0]));
+        let all: Vec<Value> = query.find_all(&client).await.unwrap();
+        assert!(decrypted(&all[0]));
+        let first: Option<Value> = query.first(&client).await.unwrap();
+        assert!(decrypted(&first.unwrap()));
+        let got: Value = query.get("aB3dE6gH9j", &client).await.unwrap();
+        assert!(decrypted(&got));
+        let page = query.find_with_count::<Value>(&client).await.unwrap();
+        assert!(decrypted(&page.results[0]));
+        let by_id: Vec<Value> = client
+            .fetch_objects_by_ids("Patient", &["aB3dE6gH9j"])
+            .await
+            .unwrap();
+        assert!(decrypted(&by_id[0]));
+        let fields: Value = client
+            .fetch_object_fields("Patient", "aB3dE6gH9j", &["ssn"])
+            .await
+            .unwrap();
+        assert!(decrypted(&fields));
+    }
+
+    #[tokio::test]
+    async fn test_batch_and_update_all_encrypt_fields() {
+        let cipher = FieldCipher::aes_256_gcm(&KEY);
+        let ssn = cipher.encrypt("Patient", "ssn", &json!("old")).unwrap();
+        let (server, client) = patient_server(ssn).await;
+
+        let mut batch = ParseBatch::new();
+        batch
+            .add(BatchOp::Create {
+                class: "Patient".to_string(),
+                fields: json!({ "name": "Bob", "ssn": "111-11-1111" }),
+            })
+            .add(BatchOp::Update {
+                class: "Patient".to_string(),
+                object_id: "aB3dE6gH9j".to_string(),
+                fields: json!({ "ssn": "222-22-2222" }),
+            });
+        batch.execute(&client).await.unwrap();
+        ParseQuery::new("Patient")
+            .update_all(&client, &json!({ "ssn": "333-33-3333" }))
+            .await
+            .unwrap();
+
+        let sent = batched_ssns(&server);
+        assert_eq!(sent.len(), 3);
+        for (value, expected) in sent
+            .iter()
+            .zip(["111-11-1111", "222-22-2222", "333-33-3333"])
+        {
+            assert!(value.as_str().unwrap().starts_with("enc:v1:"));
+            assert_eq!(
+                cipher.decrypt("Patient", "ssn", value).unwrap(),
+                json!(expected)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unencrypted_value_in_ciphered_field_is_rejected() {
+        let (_server, client) = patient_server(json!("123-45-6789")).await;
+
+        assert!(client
+            .retrieve_object("Patient", "aB3dE6gH9j")
+            .await
+            .is_err());
+        assert!(ParseQuery::new("Patient")
+            .find::<Value>(&client)
+            .await
+            .is_err());
+
+        // An unset field is not an error.
+        let (_server, client) = patient_server(Value::Null).await;
+        let found: Vec<Value> = ParseQuery::new("Patient").find(&client).await.unwrap();
+        assert_eq!(found[0]["ssn"], Value::Null);
+    }
+
+    #[test]
+    fn test_ciphertext_is_bound_to_class_and_field() {
+        let cipher = FieldCipher::aes_256_gcm(&KEY);
+        let value = json!({ "number": "123-45-6789" });
+        let encrypted = cipher.encrypt("Patient", "ssn", &value).unwrap();
+
+        assert_eq!(cipher.decrypt("Patient", "ssn", &encrypted).unwrap(), value);
+        assert!(cipher.decrypt("Patient", "notes", &encrypted).is_err());
+        assert!(cipher.decrypt("Doctor", "ssn", &encrypted).is_err());
+    }
+}
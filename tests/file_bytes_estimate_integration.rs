@@ -0,0 +1,134 @@
+use parse_rs::interceptor::{async_trait, RequestInterceptor, RequestParts, ResponseParts};
+use parse_rs::{Parse, ProxyConfig};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod file_bytes_estimate_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    // Records the URL of every request the client sends.
+    #[derive(Default)]
+    struct UrlRecorder(Mutex<Vec<String>>);
+
+    #[async_trait]
+    impl RequestInterceptor for UrlRecorder {
+        async fn before_request(&self, request: &mut RequestParts) {
+            self.0.lock().unwrap().push(request.url.to_string());
+        }
+
+        async fn after_response(&self, _response: &ResponseParts) {}
+    }
+
+    #[tokio::test]
+    async fn test_foreign_file_hosts_do_not_receive_client_headers() {
+        let cdn =
+            MockServer::start(|_| MockResponse::empty(200).with_header("Content-Length", "300"))
+                .await;
+        let cdn_url = format!("{}/files/photo.jpg", cdn.base_url);
+        let expected_cdn_url = cdn_url.clone();
+
+        let server = MockServer::start(move |req| match (req.method.as_str(), req.path_only()) {
+            ("GET", "/parse/classes/Album") => {
+                let photo =
+                    |url: &str| json!({ "__type": "File", "name": "photo.jpg", "url": url });
+                let local_url = format!(
+                    "http://{}/parse/files/appId/cover.jpg",
+                    req.header("host").unwrap()
+                );
+                MockResponse::json(
+                    200,
+                    json!({
+                        "results": [
+                            { "objectId": "album00001", "cover": photo(&local_url) },
+                            { "objectId": "album00002", "cover": photo(&cdn_url) }
+                        ]
+                    }),
+                )
+            }
+            ("HEAD", "/parse/files/appId/cover.jpg") => {
+                MockResponse::empty(200).with_header("Content-Length", "200")
+            }
+            _ => MockResponse::json(404, json!({ "error": "unexpected request" })),
+        })
+        .await;
+        let recorder = Arc::new(UrlRecorder::default());
+        let client = Parse::builder(&server.url, "appId")
+            .master_key("masterKey")
+            .interceptor(recorder.clone())
+            .build()
+            .unwrap();
+
+        let total = client
+            .estimate_class_file_bytes("Album", &["cover"])
+            .await
+            .unwrap();
+        assert_eq!(total, 500);
+
+        let local_head = server
+            .requests()
+            .into_iter()
+            .find(|req| req.method == "HEAD")
+            .expect("HEAD request to the server");
+        assert_eq!(local_head.header("x-parse-application-id"), Some("appId"));
+
+        let cdn_requests = cdn.requests();
+        assert_eq!(cdn_requests.len(), 1);
+        assert_eq!(cdn_requests[0].method, "HEAD");
+        assert_eq!(cdn_requests[0].header("x-parse-application-id"), None);
+        assert_eq!(cdn_requests[0].header("x-parse-master-key"), None);
+        // The CDN request still goes through the interceptor and is counted in the stats.
+        assert!(recorder.0.lock().unwrap().contains(&expected_cdn_url));
+        assert_eq!(client.stats().requests, 3);
+    }
+
+    #[tokio::test]
+    async fn test_foreign_file_hosts_are_reached_through_the_proxy() {
+        // The mock server plays the proxy for both the Parse server and the CDN.
+        let proxy = MockServer::start(|req| {
+            if req
+                .path
+                .starts_with("http://parse.example.invalid/parse/classes/Album")
+            {
+                MockResponse::json(
+                    200,
+                    json!({
+                        "results": [{
+                            "objectId": "album00001",
+                            "cover": {
+                                "__type": "File",
+                                "name": "photo.jpg",
+                                "url": "http://cdn.example.invalid/photo.jpg"
+                            }
+                        }]
+                    }),
+                )
+            } else {
+                MockResponse::empty(200).with_header("Content-Length", "300")
+            }
+        })
+        .await;
+        let client = Parse::builder("http://parse.example.invalid/parse", "appId")
+            .master_key("masterKey")
+            .proxy(ProxyConfig::http(&proxy.base_url))
+            .build()
+            .unwrap();
+
+        let total = client
+            .estimate_class_file_bytes("Album", &["cover"])
+            .await
+            .unwrap();
+        assert_eq!(total, 300);
+
+        let head = proxy
+            .requests()
+            .into_iter()
+            .find(|req| req.method == "HEAD")
+            .expect("HEAD request through the proxy");
+        assert_eq!(head.path, "http://cdn.example.invalid/photo.jpg");
+        assert_eq!(head.header("x-parse-application-id"), None);
+    }
+}
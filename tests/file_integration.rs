@@ -4,7 +4,9 @@ use serde_json::json;
 
 mod query_test_utils;
 
-use query_test_utils::shared::setup_client_with_master_key;
+use query_test_utils::shared::{
+    cleanup_test_class, generate_unique_classname, setup_client_with_master_key,
+};
 
 #[tokio::test]
 async fn test_upload_file_and_associate_with_object() {
@@ -28,19 +30,24 @@ async fn test_upload_file_and_associate_with_object() {
         upload_response.name,
         file_name
     );
+    let uploaded_url = upload_response
+        .url()
+        .expect("Uploaded file should have a URL")
+        .to_string();
     assert!(
-        upload_response.url.starts_with(client.server_url.as_str()),
+        uploaded_url.starts_with(client.server_url.as_str()),
         "Uploaded file URL should start with server URL"
     );
     assert!(
-        upload_response.url.contains(file_name),
+        uploaded_url.contains(file_name),
         "Uploaded file URL should contain file name"
     );
+    assert_eq!(upload_response.mime_type(), mime_type);
+    assert_eq!(upload_response.size(), "Hello, Parse File world!".len());
 
     // 3. Create a ParseObject and associate the uploaded file
     let mut object_to_create = ParseObject::new(class_name);
-    let file_field = FileField::new(upload_response.name.clone(), upload_response.url.clone());
-    object_to_create.set("myTestFile", json!(file_field));
+    object_to_create.set("myTestFile", &upload_response);
 
     let create_response = client
         .create_object(class_name, &object_to_create.fields)
@@ -63,7 +70,7 @@ async fn test_upload_file_and_associate_with_object() {
         .expect("Failed to deserialize FileField from retrieved object");
 
     assert_eq!(retrieved_file_field.name, upload_response.name);
-    assert_eq!(retrieved_file_field.url, upload_response.url);
+    assert_eq!(retrieved_file_field.url, uploaded_url);
     assert_eq!(retrieved_file_field._type, "File");
 
     // Clean up: Delete the object
@@ -76,3 +83,38 @@ async fn test_upload_file_and_associate_with_object() {
     // or manually if direct file deletion API is available and implemented.
     // For this test, we only ensure the object referencing it is deleted.
 }
+
+#[tokio::test]
+async fn test_estimate_class_file_bytes() {
+    let client = setup_client_with_master_key();
+    let class_name = &generate_unique_classname("TestFileBytes");
+
+    let first_content = b"0123456789".to_vec(); // 10 bytes
+    let second_content = b"Hello, Parse!".to_vec(); // 13 bytes
+    let first = client
+        .upload_file("first.txt", first_content.clone(), "text/plain")
+        .await
+        .expect("Failed to upload first file");
+    let second = client
+        .upload_file("second.txt", second_content.clone(), "text/plain")
+        .await
+        .expect("Failed to upload second file");
+
+    let data = json!({
+        "avatar": first,
+        "attachment": second,
+        "title": "not a file",
+    });
+    client
+        .create_object(class_name, &data)
+        .await
+        .expect("Failed to create object with files");
+
+    let total = client
+        .estimate_class_file_bytes(class_name, &["avatar", "attachment", "title"])
+        .await
+        .expect("Failed to estimate file bytes");
+    assert_eq!(total, (first_content.len() + second_content.len()) as u64);
+
+    cleanup_test_class(&client, class_name).await;
+}
@@ -0,0 +1,181 @@
+use parse_rs::{Parse, ParseError, ParseFile, ParseObject};
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod file_metadata_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    fn respond(req: &RecordedRequest) -> MockResponse {
+        match req.path_only() {
+            "/parse/files/report.pdf" => MockResponse::json(
+                201,
+                json!({
+                    "name": "abc123_report.pdf",
+                    "url": "http://files.example.com/abc123_report.pdf"
+                }),
+            ),
+            _ => MockResponse::json(
+                201,
+                json!({ "objectId": "obj1234567", "createdAt": "2024-01-01T00:00:00.000Z" }),
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upload_file_reports_mime_type_and_size() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+
+        let data = vec![7u8; 1234];
+        let file = client
+            .upload_file("report.pdf", data, "application/pdf")
+            .await
+            .unwrap();
+
+        assert_eq!(file.name(), "abc123_report.pdf");
+        assert_eq!(
+            file.url(),
+            Some("http://files.example.com/abc123_report.pdf")
+        );
+        assert_eq!(file.mime_type(), "application/pdf");
+        assert_eq!(file.size(), 1234);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].header("Content-Type"), Some("application/pdf"));
+    }
+
+    #[tokio::test]
+    async fn test_uploaded_file_serializes_as_file_reference() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+
+        let file = client
+            .upload_file("report.pdf", b"%PDF-1.7".to_vec(), "application/pdf")
+            .await
+            .unwrap();
+        let object = ParseObject::new("Report").with("attachment", &file);
+        client.create_object("Report", &object).await.unwrap();
+
+        let body: serde_json::Value = serde_json::from_str(&server.requests()[1].body).unwrap();
+        assert_eq!(
+            body["attachment"],
+            json!({
+                "__type": "File",
+                "name": "abc123_report.pdf",
+                "url": "http://files.example.com/abc123_report.pdf"
+            })
+        );
+    }
+
+    #[test]
+    fn test_file_without_url_fails_to_serialize() {
+        let file = ParseFile::new(
+            "local.txt".to_string(),
+            b"hello".to_vec(),
+            "text/plain".to_string(),
+        );
+        assert_eq!(file.size(), 5);
+        assert!(file.url().is_none());
+        assert!(serde_json::to_value(&file).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_save_object_with_file_round_trips_file_url() {
+        // Stores the created object and returns it on retrieval, like the server would.
+        let stored = Arc::new(Mutex::new(Value::Null));
+        let state = stored.clone();
+        let server = MockServer::start(move |req| match (req.method.as_str(), req.path_only()) {
+            ("POST", "/parse/files/avatar.png") => MockResponse::json(
+                201,
+                json!({
+                    "name": "f00d_avatar.png",
+                    "url": "http://files.example.com/f00d_avatar.png"
+                }),
+            ),
+            ("POST", "/parse/classes/PlayerProfile") => {
+                let mut object: Value = serde_json::from_str(&req.body).unwrap();
+                object["objectId"] = json!("prof000001");
+                object["createdAt"] = json!("2024-01-01T00:00:00.000Z");
+                object["updatedAt"] = json!("2024-01-01T00:00:00.000Z");
+                *state.lock().unwrap() = object;
+                MockResponse::json(
+                    201,
+                    json!({ "objectId": "prof000001", "createdAt": "2024-01-01T00:00:00.000Z" }),
+                )
+            }
+            _ => MockResponse::json(200, state.lock().unwrap().clone()),
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let profile = ParseObject::new("PlayerProfile").with("playerName", "Sean");
+        let (created, avatar) = client
+            .save_object_with_file(
+                "PlayerProfile",
+                &profile,
+                "avatar",
+                "avatar.png",
+                vec![0x89, b'P', b'N', b'G'],
+                "image/png",
+            )
+            .await
+            .unwrap();
+        assert_eq!(created.object_id, "prof000001");
+        assert_eq!(avatar.size(), 4);
+
+        let fetched = client
+            .retrieve_object("PlayerProfile", "prof000001")
+            .await
+            .unwrap();
+        assert_eq!(fetched.fields["playerName"], "Sean");
+        assert_eq!(fetched.fields["avatar"]["__type"], "File");
+        assert_eq!(fetched.fields["avatar"]["url"].as_str(), avatar.url());
+        assert_eq!(fetched.fields["avatar"]["name"], "f00d_avatar.png");
+    }
+
+    #[tokio::test]
+    async fn test_save_object_with_file_creates_nothing_if_upload_fails() {
+        let server = MockServer::start(|req| match req.path_only() {
+            "/parse/files/avatar.png" => MockResponse::json(
+                400,
+                json!({ "code": 130, "error": "Could not store file." }),
+            ),
+            _ => MockResponse::json(201, json!({ "objectId": "prof000001" })),
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let profile = json!({ "playerName": "Sean" });
+        let result = client
+            .save_object_with_file(
+                "PlayerProfile",
+                &profile,
+                "avatar",
+                "avatar.png",
+                vec![1, 2, 3],
+                "image/png",
+            )
+            .await;
+        assert!(result.is_err());
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path_only(), "/parse/files/avatar.png");
+
+        let not_an_object = client
+            .save_object_with_file(
+                "PlayerProfile",
+                &json!([1]),
+                "avatar",
+                "a.png",
+                vec![1],
+                "image/png",
+            )
+            .await;
+        assert!(matches!(not_an_object, Err(ParseError::InvalidInput(_))));
+        assert_eq!(server.requests().len(), 1);
+    }
+}
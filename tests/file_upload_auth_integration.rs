@@ -0,0 +1,142 @@
+use parse_rs::client::AuthType;
+use parse_rs::{Parse, ParseError};
+use serde_json::json;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod file_upload_auth_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    const KEY_HEADERS: [&str; 4] = [
+        "X-Parse-Master-Key",
+        "X-Parse-Javascript-Key",
+        "X-Parse-REST-API-Key",
+        "X-Parse-Session-Token",
+    ];
+
+    fn respond(_req: &RecordedRequest) -> MockResponse {
+        MockResponse::json(
+            201,
+            json!({ "name": "abc_a.txt", "url": "http://files.example.com/abc_a.txt" }),
+        )
+    }
+
+    // Uploads a file and returns the credential headers the server received.
+    async fn upload_and_collect_keys(client: &Parse, server: &MockServer) -> Vec<(String, String)> {
+        client
+            .upload_file("a.txt", b"hello".to_vec(), "text/plain")
+            .await
+            .expect("upload failed");
+        credential_headers(&server.requests()[0])
+    }
+
+    fn credential_headers(request: &RecordedRequest) -> Vec<(String, String)> {
+        KEY_HEADERS
+            .iter()
+            .filter_map(|name| {
+                request
+                    .header(name)
+                    .map(|value| (name.to_string(), value.to_string()))
+            })
+            .collect()
+    }
+
+    fn pairs(headers: &[(&str, &str)]) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_upload_with_rest_api_key_only() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+        assert_eq!(
+            upload_and_collect_keys(&client, &server).await,
+            pairs(&[("X-Parse-REST-API-Key", "restKey")])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upload_with_javascript_key_only() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", Some("jsKey"), None, None).unwrap();
+        assert_eq!(
+            upload_and_collect_keys(&client, &server).await,
+            pairs(&[("X-Parse-Javascript-Key", "jsKey")])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upload_with_master_key() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(
+            &server.url,
+            "appId",
+            None,
+            Some("restKey"),
+            Some("masterKey"),
+        )
+        .unwrap();
+        assert_eq!(
+            upload_and_collect_keys(&client, &server).await,
+            pairs(&[("X-Parse-Master-Key", "masterKey")])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upload_with_session_token() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None)
+            .unwrap()
+            .clone_with_session(Some("r:alice".to_string()));
+        assert_eq!(
+            upload_and_collect_keys(&client, &server).await,
+            pairs(&[
+                ("X-Parse-REST-API-Key", "restKey"),
+                ("X-Parse-Session-Token", "r:alice")
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upload_with_explicit_auth() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(
+            &server.url,
+            "appId",
+            None,
+            Some("restKey"),
+            Some("masterKey"),
+        )
+        .unwrap();
+
+        client
+            .upload_file_with_auth(
+                "a.txt",
+                b"hello".to_vec(),
+                "text/plain",
+                AuthType::RestApiKey,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            credential_headers(&server.requests()[0]),
+            pairs(&[("X-Parse-REST-API-Key", "restKey")])
+        );
+
+        let result = client
+            .upload_file_with_auth(
+                "a.txt",
+                b"hello".to_vec(),
+                "text/plain",
+                AuthType::SessionToken,
+            )
+            .await;
+        assert!(matches!(result, Err(ParseError::SessionTokenMissing)));
+        assert_eq!(server.requests().len(), 1);
+    }
+}
@@ -0,0 +1,185 @@
+use parse_rs::{Parse, ParseError};
+use serde_json::json;
+use std::time::Duration;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod health_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_server_health_ok() {
+        let server = MockServer::start(|req| match req.path_only() {
+            "/parse/health" => MockResponse::json(200, json!({ "status": "ok" })),
+            _ => MockResponse::json(404, json!({ "error": "not found" })),
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let health = client.server_health().await.expect("Health check failed");
+        assert!(health.is_ok());
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path_only(), "/parse/health");
+    }
+
+    #[tokio::test]
+    async fn test_ping_measures_round_trip() {
+        let server = MockServer::start(|req| match req.path_only() {
+            "/parse/health" => MockResponse::json(200, json!({ "status": "ok" }))
+                .with_delay(Duration::from_millis(50)),
+            _ => MockResponse::json(404, json!({ "error": "not found" })),
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let elapsed = client.ping().await.expect("Ping failed");
+        assert!(elapsed >= Duration::from_millis(50), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(5), "{:?}", elapsed);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path_only(), "/parse/health");
+        assert_eq!(requests[0].header("X-Parse-Application-Id"), Some("appId"));
+        assert_eq!(requests[0].header("X-Parse-REST-API-Key"), Some("restKey"));
+    }
+
+    #[tokio::test]
+    async fn test_ping_unavailable_is_err() {
+        let server =
+            MockServer::start(|_| MockResponse::json(503, json!({ "status": "initialized" })))
+                .await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        assert!(client.ping().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_server_health_unavailable_is_err() {
+        let server =
+            MockServer::start(|_| MockResponse::json(503, json!({ "status": "initialized" })))
+                .await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let result = client.server_health().await;
+        assert!(
+            matches!(result, Err(ParseError::InternalServerError(_))),
+            "Expected an error for a 503 health response, got {:?}",
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn test_server_info_uses_master_key() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(
+                200,
+                json!({
+                    "parseServerVersion": "6.2.0",
+                    "features": { "schemas": { "addField": true } }
+                }),
+            )
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+
+        let info = client.server_info().await.expect("serverInfo failed");
+        assert_eq!(info.parse_server_version, "6.2.0");
+        assert!(info.features.contains_key("schemas"));
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path_only(), "/parse/serverInfo");
+        assert_eq!(requests[0].header("X-Parse-Master-Key"), Some("masterKey"));
+    }
+
+    #[tokio::test]
+    async fn test_server_info_requires_master_key() {
+        let client = Parse::new("http://127.0.0.1:1/parse", "appId", None, None, None).unwrap();
+        let result = client.server_info().await;
+        assert!(matches!(result, Err(ParseError::MasterKeyRequired(_))));
+    }
+
+    #[tokio::test]
+    async fn test_health_detailed_boolean_response() {
+        let server = MockServer::start(|_| MockResponse::json(200, json!(true))).await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let report = client.health_detailed().await.expect("Health check failed");
+        assert!(report.healthy);
+        assert_eq!(report.status, None);
+        assert!(report.components.is_empty());
+        assert_eq!(server.requests()[0].path_only(), "/parse/health");
+    }
+
+    #[tokio::test]
+    async fn test_health_detailed_object_response() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(
+                200,
+                json!({ "status": "ok", "database": "ok", "cache": { "status": "degraded" } }),
+            )
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let report = client.health_detailed().await.expect("Health check failed");
+        assert!(report.healthy);
+        assert_eq!(report.status.as_deref(), Some("ok"));
+        assert_eq!(report.components.len(), 2);
+        assert_eq!(report.components.get("database"), Some(&json!("ok")));
+        assert_eq!(
+            report.components.get("cache"),
+            Some(&json!({ "status": "degraded" }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_detailed_unhealthy_shapes() {
+        let server =
+            MockServer::start(|_| MockResponse::json(200, json!({ "status": "initialized" })))
+                .await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let report = client.health_detailed().await.expect("Health check failed");
+        assert!(!report.healthy);
+        assert_eq!(report.status.as_deref(), Some("initialized"));
+    }
+
+    #[tokio::test]
+    async fn test_verify_master_key() {
+        let server = MockServer::start(|req| {
+            if req.header("X-Parse-Master-Key") == Some("masterKey") {
+                MockResponse::json(
+                    200,
+                    json!({ "parseServerVersion": "6.2.0", "features": {} }),
+                )
+            } else {
+                MockResponse::json(
+                    403,
+                    json!({ "error": "unauthorized: master key is required" }),
+                )
+            }
+        })
+        .await;
+
+        let valid = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+        assert!(valid.verify_master_key().await.expect("verify failed"));
+
+        let invalid = Parse::new(&server.url, "appId", None, None, Some("wrongKey")).unwrap();
+        assert!(!invalid.verify_master_key().await.expect("verify failed"));
+    }
+
+    #[tokio::test]
+    async fn test_verify_master_key_propagates_other_errors() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(500, json!({ "code": 1, "error": "Internal server error" }))
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+        assert!(client.verify_master_key().await.is_err());
+    }
+}
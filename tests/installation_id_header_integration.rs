@@ -0,0 +1,128 @@
+use parse_rs::user::LoginRequest;
+use parse_rs::{Parse, ParseQuery, RetrievedParseObject};
+use serde_json::json;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod installation_id_header_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    fn respond(req: &RecordedRequest) -> MockResponse {
+        match req.path_only() {
+            "/parse/login" => MockResponse::json(
+                200,
+                json!({
+                    "objectId": "user123456",
+                    "username": "alice",
+                    "sessionToken": "r:session",
+                    "createdAt": "2024-01-01T00:00:00.000Z",
+                    "updatedAt": "2024-01-01T00:00:00.000Z"
+                }),
+            ),
+            _ => MockResponse::json(200, json!({ "results": [] })),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_installation_id_sent_on_login_and_query() {
+        let server = MockServer::start(respond).await;
+        let mut client = Parse::builder(&server.url, "appId")
+            .installation_id("device-1234")
+            .build()
+            .unwrap();
+        assert_eq!(client.installation_id(), Some("device-1234"));
+
+        client
+            .user()
+            .login(&LoginRequest {
+                username: "alice",
+                password: "secret",
+            })
+            .await
+            .unwrap();
+        let _: Vec<RetrievedParseObject> =
+            ParseQuery::new("GameScore").find(&client).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        for request in &requests {
+            assert_eq!(
+                request.header("X-Parse-Installation-Id"),
+                Some("device-1234"),
+                "Missing installation id on {} {}",
+                request.method,
+                request.path
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_explicit_installation_id_overrides_configured_one() {
+        let server = MockServer::start(respond).await;
+        let mut client = Parse::builder(&server.url, "appId")
+            .installation_id("device-1234")
+            .build()
+            .unwrap();
+
+        client
+            .user()
+            .login_with_installation_id(
+                &LoginRequest {
+                    username: "alice",
+                    password: "secret",
+                },
+                "device-5678",
+            )
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].header("X-Parse-Installation-Id"),
+            Some("device-5678")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_login_anonymous_with_installation_id() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(
+                201,
+                json!({
+                    "objectId": "anonUser01",
+                    "sessionToken": "r:anonymous",
+                    "createdAt": "2024-01-01T00:00:00.000Z"
+                }),
+            )
+        })
+        .await;
+        let mut client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        client
+            .user()
+            .login_anonymous_with_installation_id("device-5678")
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path_only(), "/parse/users");
+        assert_eq!(
+            requests[0].header("X-Parse-Installation-Id"),
+            Some("device-5678")
+        );
+        assert_eq!(client.session_token(), Some("r:anonymous"));
+    }
+
+    #[tokio::test]
+    async fn test_no_installation_id_by_default() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+        assert_eq!(client.installation_id(), None);
+
+        let _: Vec<RetrievedParseObject> =
+            ParseQuery::new("GameScore").find(&client).await.unwrap();
+        assert_eq!(server.requests()[0].header("X-Parse-Installation-Id"), None);
+    }
+}
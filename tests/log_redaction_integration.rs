@@ -0,0 +1,128 @@
+use parse_rs::{Parse, UserUpdate};
+use serde_json::{json, Value};
+use std::sync::{Mutex, Once};
+
+mod mock_server_utils;
+
+// Collects every log message emitted by the crate so tests can inspect them.
+struct CapturingLogger {
+    messages: Mutex<Vec<String>>,
+}
+
+impl log::Log for CapturingLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        self.messages
+            .lock()
+            .unwrap()
+            .push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger {
+    messages: Mutex::new(Vec::new()),
+};
+static INIT: Once = Once::new();
+
+fn captured_logs() -> Vec<String> {
+    INIT.call_once(|| {
+        log::set_logger(&LOGGER).expect("Failed to install test logger");
+        log::set_max_level(log::LevelFilter::Debug);
+    });
+    LOGGER.messages.lock().unwrap().clone()
+}
+
+#[cfg(test)]
+mod log_redaction_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_master_key_is_masked_in_logs() {
+        captured_logs();
+        let server = MockServer::start(|_| MockResponse::json(200, json!({ "results": [] }))).await;
+        let client =
+            Parse::new(&server.url, "appId", None, None, Some("redactedMasterKey")).unwrap();
+
+        let _: Value = client.get("classes/Item").await.expect("get failed");
+
+        let logs = captured_logs();
+        assert!(
+            logs.iter()
+                .any(|line| line.contains("x-parse-master-key: reda***")),
+            "Masked master key header not logged"
+        );
+        assert!(!logs.iter().any(|line| line.contains("redactedMasterKey")));
+    }
+
+    #[tokio::test]
+    async fn test_log_redaction_can_be_disabled() {
+        captured_logs();
+        let server = MockServer::start(|_| MockResponse::json(200, json!({ "results": [] }))).await;
+        let client = Parse::builder(&server.url, "appId")
+            .master_key("plainMasterKey")
+            .log_redaction(false)
+            .build()
+            .unwrap();
+
+        let _: Value = client.get("classes/Item").await.expect("get failed");
+
+        let logs = captured_logs();
+        assert!(logs
+            .iter()
+            .any(|line| line.contains("x-parse-master-key: plainMasterKey")));
+    }
+
+    #[tokio::test]
+    async fn test_user_update_password_is_not_returned_or_logged() {
+        captured_logs();
+        let server = MockServer::start(|req| match req.method.as_str() {
+            "GET" => MockResponse::json(
+                200,
+                json!({
+                    "objectId": "user000001",
+                    "username": "sean",
+                    "createdAt": "2024-01-01T00:00:00.000Z",
+                    "updatedAt": "2024-01-01T00:00:00.000Z"
+                }),
+            ),
+            _ => MockResponse::json(200, json!({ "updatedAt": "2024-02-01T00:00:00.000Z" })),
+        })
+        .await;
+        let mut client = Parse::new(&server.url, "appId", None, Some("restKey"), None)
+            .unwrap()
+            .clone_with_session(Some("r:session0001".to_string()));
+
+        let update = UserUpdate::new()
+            .email("sean@example.com")
+            .password("writeOnlyPassword");
+        assert!(!format!("{:?}", update).contains("writeOnlyPassword"));
+        let user = client.user().update(&update).await.expect("update failed");
+
+        assert_eq!(user.email.as_deref(), Some("sean@example.com"));
+        assert_eq!(
+            user.updated_at.as_ref().unwrap().iso,
+            "2024-02-01T00:00:00.000Z"
+        );
+        let returned = serde_json::to_value(&user).unwrap();
+        assert!(returned.get("password").is_none());
+        assert!(!returned.to_string().contains("writeOnlyPassword"));
+
+        let requests = server.requests();
+        assert_eq!(requests[1].method, "PUT");
+        assert_eq!(requests[1].path_only(), "/parse/users/user000001");
+        let body: Value = serde_json::from_str(&requests[1].body).unwrap();
+        assert_eq!(body["password"], "writeOnlyPassword");
+
+        let logs = captured_logs();
+        assert!(logs
+            .iter()
+            .any(|line| line.contains(r#""password": "***""#)));
+        assert!(!logs.iter().any(|line| line.contains("writeOnlyPassword")));
+    }
+}
@@ -0,0 +1,156 @@
+use parse_rs::{Parse, ParseError};
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod logout_all_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    // (objectId, sessionToken, user objectId)
+    type Sessions = Arc<Mutex<Vec<(String, String, String)>>>;
+
+    fn start_sessions() -> Sessions {
+        Arc::new(Mutex::new(
+            [
+                ("session001", "r:laptop", "seanUser01"),
+                ("session002", "r:phone", "seanUser01"),
+                ("session003", "r:tablet", "seanUser01"),
+                ("session004", "r:other", "otherUser1"),
+            ]
+            .iter()
+            .map(|(id, token, user)| (id.to_string(), token.to_string(), user.to_string()))
+            .collect(),
+        ))
+    }
+
+    fn session_json(id: &str, token: &str, user: &str) -> Value {
+        json!({
+            "objectId": id,
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "sessionToken": token,
+            "user": { "__type": "Pointer", "className": "_User", "objectId": user }
+        })
+    }
+
+    // Serves `/users/me`, `/sessions/me`, session listing by user and session deletion, like
+    // Parse Server does for `_Session`. Listings return at most `max_limit` sessions per page.
+    fn respond(sessions: &Sessions, max_limit: usize, req: &RecordedRequest) -> MockResponse {
+        let mut sessions = sessions.lock().unwrap();
+        let token = req.header("x-parse-session-token").unwrap_or_default();
+        let current = sessions.iter().find(|(_, t, _)| t == token).cloned();
+        match (req.method.as_str(), req.path_only()) {
+            ("GET", "/parse/users/me") | ("GET", "/parse/sessions/me") => match current {
+                Some((id, token, user)) if req.path_only().contains("sessions") => {
+                    MockResponse::json(200, session_json(&id, &token, &user))
+                }
+                Some((_, token, user)) => MockResponse::json(
+                    200,
+                    json!({
+                        "objectId": user,
+                        "username": "sean",
+                        "sessionToken": token,
+                        "createdAt": "2024-01-01T00:00:00.000Z",
+                        "updatedAt": "2024-01-01T00:00:00.000Z"
+                    }),
+                ),
+                None => MockResponse::json(
+                    400,
+                    json!({ "code": 209, "error": "Invalid session token" }),
+                ),
+            },
+            ("GET", "/parse/sessions") => {
+                let params = req.query_params();
+                let where_clause: Value = serde_json::from_str(&params["where"]).unwrap();
+                let skip: usize = params["skip"].parse().unwrap();
+                let limit: usize = params["limit"].parse().unwrap();
+                let results: Vec<Value> = sessions
+                    .iter()
+                    .filter(|(_, _, user)| where_clause["user"]["objectId"] == json!(user))
+                    .skip(skip)
+                    .take(limit.min(max_limit))
+                    .map(|(id, token, user)| session_json(id, token, user))
+                    .collect();
+                MockResponse::json(200, json!({ "results": results }))
+            }
+            ("DELETE", path) if path.starts_with("/parse/sessions/") => {
+                assert_eq!(req.header("x-parse-master-key"), Some("masterKey"));
+                let id = path.rsplit('/').next().unwrap();
+                sessions.retain(|(session_id, _, _)| session_id != id);
+                MockResponse::json(200, json!({}))
+            }
+            _ => MockResponse::json(404, json!({ "code": 1, "error": "unexpected request" })),
+        }
+    }
+
+    async fn logged_in_client(sessions: &Sessions, max_limit: usize) -> (MockServer, Parse) {
+        let state = sessions.clone();
+        let server = MockServer::start(move |req| respond(&state, max_limit, req)).await;
+        let mut client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+        client.user().become_user("r:laptop").await.unwrap();
+        (server, client)
+    }
+
+    fn remaining_ids(sessions: &Sessions) -> Vec<String> {
+        sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(id, _, _)| id.clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_logout_all_deletes_every_session_of_the_user() {
+        let sessions = start_sessions();
+        let (_server, mut client) = logged_in_client(&sessions, 100).await;
+
+        let revoked = client.user().logout_all().await.unwrap();
+
+        assert_eq!(revoked, 3);
+        assert_eq!(remaining_ids(&sessions), vec!["session004"]);
+        assert!(!client.is_authenticated());
+        assert!(client.current_user().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_other_sessions_keeps_the_current_one() {
+        let sessions = start_sessions();
+        let (_server, mut client) = logged_in_client(&sessions, 100).await;
+
+        let revoked = client.user().revoke_other_sessions().await.unwrap();
+
+        assert_eq!(revoked, 2);
+        assert_eq!(remaining_ids(&sessions), vec!["session001", "session004"]);
+        assert_eq!(client.session_token(), Some("r:laptop"));
+    }
+
+    #[tokio::test]
+    async fn test_logout_all_follows_pages_shortened_by_max_limit() {
+        let sessions = start_sessions();
+        let (_server, mut client) = logged_in_client(&sessions, 1).await;
+
+        let revoked = client.user().logout_all().await.unwrap();
+
+        assert_eq!(revoked, 3);
+        assert_eq!(remaining_ids(&sessions), vec!["session004"]);
+    }
+
+    #[tokio::test]
+    async fn test_logout_all_requires_the_master_key() {
+        let sessions = start_sessions();
+        let state = sessions.clone();
+        let server = MockServer::start(move |req| respond(&state, 100, req)).await;
+        let mut client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+        client.user().become_user("r:laptop").await.unwrap();
+
+        assert!(matches!(
+            client.user().logout_all().await,
+            Err(ParseError::MasterKeyRequired(_))
+        ));
+        assert_eq!(remaining_ids(&sessions).len(), 4);
+        assert!(client.is_authenticated());
+    }
+}
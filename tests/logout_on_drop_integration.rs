@@ -0,0 +1,110 @@
+use parse_rs::Parse;
+use serde_json::json;
+use std::time::Duration;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod logout_on_drop_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    fn respond(req: &RecordedRequest) -> MockResponse {
+        match (req.method.as_str(), req.path_only()) {
+            ("GET", "/parse/users/me") => MockResponse::json(
+                200,
+                json!({
+                    "objectId": "seanUser01",
+                    "username": "sean",
+                    "sessionToken": "r:cli-session",
+                    "createdAt": "2024-01-01T00:00:00.000Z",
+                    "updatedAt": "2024-01-01T00:00:00.000Z"
+                }),
+            ),
+            ("POST", "/parse/logout") => MockResponse::json(200, json!({})),
+            _ => MockResponse::json(404, json!({ "code": 1, "error": "unexpected request" })),
+        }
+    }
+
+    async fn logged_in_client(server: &MockServer, logout_on_drop: bool) -> Parse {
+        let mut client = Parse::builder(&server.url, "appId")
+            .logout_on_drop(logout_on_drop)
+            .build()
+            .unwrap();
+        client.user().become_user("r:cli-session").await.unwrap();
+        client
+    }
+
+    fn logout_requests(server: &MockServer) -> Vec<RecordedRequest> {
+        server
+            .requests()
+            .into_iter()
+            .filter(|req| req.path_only() == "/parse/logout")
+            .collect()
+    }
+
+    // Gives the task spawned on drop time to send its request.
+    async fn wait_for_logout(server: &MockServer) -> Vec<RecordedRequest> {
+        for _ in 0..50 {
+            let requests = logout_requests(server);
+            if !requests.is_empty() {
+                return requests;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        Vec::new()
+    }
+
+    #[tokio::test]
+    async fn test_drop_logs_out_when_enabled() {
+        let server = MockServer::start(respond).await;
+        let client = logged_in_client(&server, true).await;
+
+        drop(client);
+
+        let requests = wait_for_logout(&server).await;
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(
+            requests[0].header("x-parse-session-token"),
+            Some("r:cli-session")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drop_does_not_log_out_by_default() {
+        let server = MockServer::start(respond).await;
+        let client = logged_in_client(&server, false).await;
+
+        drop(client);
+
+        assert!(wait_for_logout(&server).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dropping_a_clone_keeps_the_session() {
+        let server = MockServer::start(respond).await;
+        let client = logged_in_client(&server, true).await;
+
+        drop(client.clone());
+        drop(client.clone_with_session(Some("r:other-session".to_string())));
+        assert!(wait_for_logout(&server).await.is_empty());
+
+        drop(client);
+        assert_eq!(wait_for_logout(&server).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_drop_without_session_sends_nothing() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::builder(&server.url, "appId")
+            .logout_on_drop(true)
+            .build()
+            .unwrap();
+
+        drop(client);
+
+        assert!(wait_for_logout(&server).await.is_empty());
+        assert!(server.requests().is_empty());
+    }
+}
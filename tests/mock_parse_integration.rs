@@ -0,0 +1,329 @@
+#![cfg(feature = "mock")]
+
+use parse_rs::mock::MockParse;
+use parse_rs::user::LoginRequest;
+use parse_rs::{Parse, ParseDate, ParseError, ParseObject, ParseQuery, RetrievedParseObject};
+use serde_json::{json, Value};
+
+#[cfg(test)]
+mod mock_parse_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_query_and_delete() {
+        let mock = MockParse::start().await.unwrap();
+        let client = mock.client().unwrap();
+
+        for (player, score) in [("Sean", 10), ("Alice", 30), ("Sean", 20)] {
+            let object = ParseObject::new("GameScore")
+                .with("playerName", player)
+                .with("score", score);
+            client.create_object("GameScore", &object).await.unwrap();
+        }
+        assert_eq!(mock.objects("GameScore").len(), 3);
+
+        let mut query = ParseQuery::new("GameScore");
+        query.equal_to("playerName", "Sean").order("-score");
+        let results: Vec<RetrievedParseObject> = query.find(&client).await.unwrap();
+        let scores: Vec<&Value> = results.iter().map(|o| &o.fields["score"]).collect();
+        assert_eq!(scores, vec![&json!(20), &json!(10)]);
+        assert_eq!(query.count(&client).await.unwrap(), 2);
+
+        let id = results[0].object_id.clone();
+        let fetched = client.retrieve_object("GameScore", &id).await.unwrap();
+        assert_eq!(fetched.fields["playerName"], json!("Sean"));
+
+        client.delete_object("GameScore", &id).await.unwrap();
+        assert_eq!(query.count(&client).await.unwrap(), 1);
+        assert!(matches!(
+            client.retrieve_object("GameScore", &id).await,
+            Err(ParseError::ObjectNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_date_field_round_trips_as_parse_date() {
+        let mock = MockParse::start().await.unwrap();
+        let client = mock.client().unwrap();
+
+        let due_at = ParseDate::new("2024-03-01T12:30:00.000Z");
+        let task = ParseObject::new("Task").with("dueAt", &due_at);
+        let created = client.create_object("Task", &task).await.unwrap();
+        assert_eq!(
+            mock.objects("Task")[0]["dueAt"],
+            json!({ "__type": "Date", "iso": "2024-03-01T12:30:00.000Z" })
+        );
+
+        let fetched = client
+            .retrieve_object("Task", &created.object_id)
+            .await
+            .unwrap();
+        let fetched_due_at: ParseDate =
+            serde_json::from_value(fetched.fields["dueAt"].clone()).unwrap();
+        assert_eq!(fetched_due_at, due_at);
+    }
+
+    #[tokio::test]
+    async fn test_update_with_field_operations() {
+        let mock = MockParse::start().await.unwrap();
+        let client = mock.client().unwrap();
+
+        let object = ParseObject::new("GameScore")
+            .with("score", 10)
+            .with("tags", vec!["a"]);
+        let created = client.create_object("GameScore", &object).await.unwrap();
+
+        let mut update = ParseObject::new("GameScore");
+        update.increment("score", 5);
+        update.add_to_array("tags", &["b"]);
+        client
+            .update_object("GameScore", &created.object_id, &update)
+            .await
+            .unwrap();
+
+        let fetched = client
+            .retrieve_object("GameScore", &created.object_id)
+            .await
+            .unwrap();
+        assert_eq!(fetched.fields["score"], json!(15));
+        assert_eq!(fetched.fields["tags"], json!(["a", "b"]));
+    }
+
+    #[tokio::test]
+    async fn test_increment_past_2_pow_53_keeps_precision() {
+        let mock = MockParse::start().await.unwrap();
+        let client = mock.client().unwrap();
+
+        let start: i64 = 1 << 53;
+        let object = ParseObject::new("Counter").with("hits", start);
+        let created = client.create_object("Counter", &object).await.unwrap();
+
+        let mut update = ParseObject::new("Counter");
+        update.increment("hits", 3);
+        client
+            .update_object("Counter", &created.object_id, &update)
+            .await
+            .unwrap();
+
+        let fetched = client
+            .retrieve_object("Counter", &created.object_id)
+            .await
+            .unwrap();
+        assert_eq!(fetched.fields["hits"].as_i64(), Some(9_007_199_254_740_995));
+
+        // Numeric constraints send the exact integer as well.
+        let mut query = ParseQuery::new("Counter");
+        query.equal_to("hits", start + 3);
+        assert_eq!(query.count(&client).await.unwrap(), 1);
+        query.equal_to("hits", start + 2);
+        assert_eq!(query.count(&client).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_pointer_exists_and_does_not_exist() {
+        let mock = MockParse::start().await.unwrap();
+        let client = mock.client().unwrap();
+
+        let moderator =
+            json!({ "__type": "Pointer", "className": "_User", "objectId": "mod0000001" });
+        let comments = [
+            ("moderated", Some(moderator)),
+            ("cleared", Some(Value::Null)),
+            ("unmoderated", None),
+        ];
+        for (text, pointer) in comments {
+            let mut comment = ParseObject::new("Comment").with("text", text);
+            if let Some(pointer) = pointer {
+                comment = comment.with("moderator", pointer);
+            }
+            client.create_object("Comment", &comment).await.unwrap();
+        }
+
+        let texts = |results: Vec<RetrievedParseObject>| {
+            let mut texts: Vec<String> = results
+                .iter()
+                .map(|o| o.fields["text"].as_str().unwrap().to_string())
+                .collect();
+            texts.sort();
+            texts
+        };
+
+        let mut with_moderator = ParseQuery::new("Comment");
+        with_moderator.pointer_exists("moderator");
+        assert_eq!(
+            texts(with_moderator.find(&client).await.unwrap()),
+            vec!["moderated"]
+        );
+
+        let mut without_moderator = ParseQuery::new("Comment");
+        without_moderator.pointer_does_not_exist("moderator");
+        assert_eq!(
+            texts(without_moderator.find(&client).await.unwrap()),
+            vec!["cleared", "unmoderated"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_query_operator_is_rejected() {
+        let mock = MockParse::start().await.unwrap();
+        let client = mock.client().unwrap();
+
+        let mut query = ParseQuery::new("GameScore");
+        query.greater_than("score", 10);
+        let result: Result<Vec<RetrievedParseObject>, _> = query.find(&client).await;
+        assert!(matches!(result, Err(ParseError::InvalidQuery(_))));
+    }
+
+    #[tokio::test]
+    async fn test_wrong_application_id_is_unauthorized() {
+        let mock = MockParse::start().await.unwrap();
+        let client = Parse::new(mock.server_url(), "otherAppId", None, None, None).unwrap();
+
+        let result = client
+            .create_object("GameScore", &json!({ "score": 1 }))
+            .await;
+        assert!(
+            matches!(result, Err(ParseError::AuthenticationError(_))),
+            "Unexpected result: {:?}",
+            result
+        );
+        assert!(mock.objects("GameScore").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_signup_login_and_logout() {
+        let mock = MockParse::start().await.unwrap();
+        let mut client = mock.client().unwrap();
+
+        client
+            .user()
+            .signup(&json!({ "username": "alice", "password": "secret", "email": "a@example.com" }))
+            .await
+            .unwrap();
+        assert!(client.session_token().is_some());
+        let me = client.user().me().await.unwrap();
+        assert_eq!(me.username, "alice");
+        assert!(mock.objects("_User")[0].get("password").is_none());
+
+        client.user().logout().await.unwrap();
+        assert!(client.session_token().is_none());
+
+        let wrong_password = client
+            .user()
+            .login(&LoginRequest {
+                username: "alice",
+                password: "wrong",
+            })
+            .await;
+        assert!(wrong_password.is_err());
+
+        let user = client
+            .user()
+            .login(&LoginRequest {
+                username: "alice",
+                password: "secret",
+            })
+            .await
+            .unwrap();
+        assert_eq!(user.email.as_deref(), Some("a@example.com"));
+        assert_eq!(client.session_token(), user.session_token.as_deref());
+
+        let duplicate = client
+            .user()
+            .signup(&json!({ "username": "alice", "password": "other" }))
+            .await;
+        assert!(matches!(duplicate, Err(ParseError::UsernameTaken(_))));
+    }
+
+    #[tokio::test]
+    async fn test_current_user_is_cached_until_logout() {
+        let mock = MockParse::start().await.unwrap();
+        let mut client = mock.client().unwrap();
+        assert!(client.current_user().is_none());
+
+        let signup = client
+            .user()
+            .signup(&json!({ "username": "alice", "password": "secret", "level": 3 }))
+            .await
+            .unwrap();
+        let cached = client.current_user().unwrap();
+        assert_eq!(cached.object_id.as_deref(), Some(signup.object_id.as_str()));
+        assert_eq!(cached.username, "alice");
+        assert_eq!(cached.other_fields["level"], json!(3));
+        assert!(!cached.other_fields.contains_key("password"));
+
+        client.user().logout().await.unwrap();
+        assert!(client.current_user().is_none());
+
+        client
+            .user()
+            .login(&LoginRequest {
+                username: "alice",
+                password: "secret",
+            })
+            .await
+            .unwrap();
+        let cached = client.current_user().unwrap();
+        assert_eq!(cached.username, "alice");
+        assert_eq!(cached.session_token.as_deref(), client.session_token());
+
+        // Changes made elsewhere only show up after a refresh.
+        let admin = mock.client().unwrap();
+        admin
+            .update_object("_User", &signup.object_id, &json!({ "level": 4 }))
+            .await
+            .unwrap();
+        assert_eq!(
+            client.current_user().unwrap().other_fields["level"],
+            json!(3)
+        );
+        let refreshed = client.refresh_current_user().await.unwrap();
+        assert_eq!(refreshed.other_fields["level"], json!(4));
+        assert_eq!(
+            client.current_user().unwrap().other_fields["level"],
+            json!(4)
+        );
+
+        client.user().logout().await.unwrap();
+        assert!(client.current_user().is_none());
+        assert!(matches!(
+            client.refresh_current_user().await,
+            Err(ParseError::SessionTokenMissing)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_user_upgrade_keeps_object_id() {
+        let mock = MockParse::start().await.unwrap();
+        let mut client = mock.client().unwrap();
+
+        let guest = client.user().login_anonymous().await.unwrap();
+        assert_eq!(client.session_token(), Some(guest.session_token.as_str()));
+        let stored = &mock.objects("_User")[0];
+        assert!(stored["authData"]["anonymous"]["id"].is_string());
+
+        let upgraded = client
+            .user()
+            .upgrade_anonymous("guest_to_sean", "s3cret")
+            .await
+            .unwrap();
+        assert_eq!(
+            upgraded.object_id.as_deref(),
+            Some(guest.object_id.as_str())
+        );
+        assert_eq!(upgraded.username, "guest_to_sean");
+        assert!(mock.objects("_User")[0].get("authData").is_none());
+
+        client.user().logout().await.unwrap();
+        let user = client
+            .user()
+            .login(&LoginRequest {
+                username: "guest_to_sean",
+                password: "s3cret",
+            })
+            .await
+            .unwrap();
+        assert_eq!(user.object_id.as_deref(), Some(guest.object_id.as_str()));
+        assert_eq!(mock.objects("_User").len(), 1);
+    }
+}
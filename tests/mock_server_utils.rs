@@ -0,0 +1,224 @@
+// tests/mock_server_utils.rs
+//
+// A minimal in-process HTTP server for tests that need to inspect the exact requests the SDK
+// sends (headers, paths, bodies) or to simulate responses a real Parse Server rarely produces.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// A request received by the [`MockServer`].
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// Path including the query string, e.g. `/parse/classes/Foo?limit=1`.
+    pub path: String,
+    /// Header names are lowercased.
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+#[allow(dead_code)]
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// The path without its query string.
+    pub fn path_only(&self) -> &str {
+        self.path.split('?').next().unwrap_or(&self.path)
+    }
+
+    /// Decoded query parameters.
+    pub fn query_params(&self) -> HashMap<String, String> {
+        match self.path.split_once('?') {
+            Some((_, query)) => url::form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect(),
+            None => HashMap::new(),
+        }
+    }
+}
+
+/// The response the [`MockServer`] sends for a request.
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+    /// How long to wait before sending the response.
+    pub delay: Option<Duration>,
+}
+
+#[allow(dead_code)]
+impl MockResponse {
+    pub fn json(status: u16, body: serde_json::Value) -> Self {
+        Self {
+            status,
+            headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+            body: body.to_string(),
+            delay: None,
+        }
+    }
+
+    pub fn empty(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: String::new(),
+            delay: None,
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+}
+
+type Responder = dyn Fn(&RecordedRequest) -> MockResponse + Send + Sync;
+
+/// A mock HTTP/1.1 server listening on a random local port.
+#[allow(dead_code)]
+pub struct MockServer {
+    /// Base URL including the `/parse` mount path, suitable for `Parse::new`.
+    pub url: String,
+    /// Base URL without the mount path.
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+#[allow(dead_code)]
+impl MockServer {
+    /// Starts a server that answers every request with the result of `responder`.
+    pub async fn start<F>(responder: F) -> Self
+    where
+        F: Fn(&RecordedRequest) -> MockResponse + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind mock server");
+        let addr = listener.local_addr().expect("Mock server has no address");
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let responder: Arc<Responder> = Arc::new(responder);
+
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let Ok((stream, _)) = listener.accept().await else {
+                    break;
+                };
+                let recorded = recorded.clone();
+                let responder = responder.clone();
+                tokio::spawn(async move {
+                    handle_connection(stream, recorded, responder).await;
+                });
+            }
+        });
+
+        let base_url = format!("http://{}", addr);
+        Self {
+            url: format!("{}/parse", base_url),
+            base_url,
+            requests,
+        }
+    }
+
+    /// All requests received so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    recorded: Arc<Mutex<Vec<RecordedRequest>>>,
+    responder: Arc<Responder>,
+) {
+    loop {
+        let Some(request) = read_request(&mut stream).await else {
+            return;
+        };
+        recorded.lock().unwrap().push(request.clone());
+        let response = responder(&request);
+        if let Some(delay) = response.delay {
+            tokio::time::sleep(delay).await;
+        }
+
+        let mut raw = format!("HTTP/1.1 {} Mock\r\n", response.status);
+        for (name, value) in &response.headers {
+            raw.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        if request.method != "HEAD"
+            && !response
+                .headers
+                .iter()
+                .any(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        {
+            raw.push_str(&format!("Content-Length: {}\r\n", response.body.len()));
+        }
+        raw.push_str("\r\n");
+        if request.method != "HEAD" {
+            raw.push_str(&response.body);
+        }
+        if stream.write_all(raw.as_bytes()).await.is_err() {
+            return;
+        }
+    }
+}
+
+async fn read_request(stream: &mut TcpStream) -> Option<RecordedRequest> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        if let Some(pos) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos;
+        }
+        let read = stream.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            return None;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split_whitespace();
+    let method = request_line.next()?.to_string();
+    let path = request_line.next()?.to_string();
+    let headers: HashMap<String, String> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .collect();
+
+    let content_length = headers
+        .get("content-length")
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = buffer[header_end + 4..].to_vec();
+    while body.len() < content_length {
+        let read = stream.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..read]);
+    }
+
+    Some(RecordedRequest {
+        method,
+        path,
+        headers,
+        body: String::from_utf8_lossy(&body).to_string(),
+    })
+}
@@ -0,0 +1,85 @@
+use parse_rs::{ParseError, ParseRole, ParseSession, ParseUser, RetrievedParseObject};
+use serde_json::json;
+
+#[cfg(test)]
+mod model_conversion_tests {
+    use super::*;
+
+    #[test]
+    fn test_user_from_value() {
+        let user = ParseUser::try_from(json!({
+            "__type": "Object",
+            "className": "_User",
+            "objectId": "user000001",
+            "username": "alice",
+            "email": "alice@example.com",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "updatedAt": "2024-01-02T00:00:00.000Z",
+            "level": 7
+        }))
+        .unwrap();
+        assert_eq!(user.object_id.as_deref(), Some("user000001"));
+        assert_eq!(user.username, "alice");
+        assert_eq!(user.other_fields["level"], json!(7));
+    }
+
+    #[test]
+    fn test_session_from_value() {
+        let session = ParseSession::try_from(json!({
+            "objectId": "sess000001",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "sessionToken": "r:abc",
+            "user": { "__type": "Pointer", "className": "_User", "objectId": "user000001" },
+            "createdWith": { "action": "login", "authProvider": "password" }
+        }))
+        .unwrap();
+        assert_eq!(session.session_token, "r:abc");
+        assert_eq!(session.user_pointer().unwrap().object_id, "user000001");
+    }
+
+    #[test]
+    fn test_role_from_value() {
+        let role = ParseRole::try_from(json!({
+            "objectId": "role000001",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "updatedAt": "2024-01-01T00:00:00.000Z",
+            "name": "Admin",
+            "ACL": { "*": { "read": true } }
+        }))
+        .unwrap();
+        assert_eq!(role.name, "Admin");
+        assert!(role.acl.get_public_read_access());
+        assert!(!role.acl.get_public_write_access());
+    }
+
+    #[test]
+    fn test_object_from_value() {
+        let object = RetrievedParseObject::try_from(json!({
+            "objectId": "obj0000001",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "updatedAt": "2024-01-02T00:00:00.000Z",
+            "score": 1337,
+            "playerName": "Sean Plott"
+        }))
+        .unwrap();
+        assert_eq!(object.object_id, "obj0000001");
+        assert_eq!(object.fields["score"], json!(1337));
+        assert_eq!(object.fields["playerName"], json!("Sean Plott"));
+    }
+
+    #[test]
+    fn test_mismatched_value_is_rejected() {
+        assert!(matches!(
+            ParseRole::try_from(json!({ "objectId": "role000001" })),
+            Err(ParseError::JsonDeserializationFailed(..))
+        ));
+        assert!(matches!(
+            ParseUser::try_from(json!("alice")),
+            Err(ParseError::JsonDeserializationFailed(..))
+        ));
+        assert!(matches!(
+            RetrievedParseObject::try_from(json!({ "score": 1 })),
+            Err(ParseError::JsonDeserializationFailed(..))
+        ));
+    }
+}
@@ -0,0 +1,54 @@
+use parse_rs::Parse;
+use serde_json::json;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod network_options_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    fn port_of(server: &MockServer) -> u16 {
+        server.base_url.rsplit(':').next().unwrap().parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_resolve_override_reaches_the_mock_server() {
+        let server =
+            MockServer::start(|_| MockResponse::json(200, json!({ "status": "ok" }))).await;
+        let port = port_of(&server);
+
+        let client = Parse::builder(
+            &format!("http://parse.backend-a.invalid:{}/parse", port),
+            "appId",
+        )
+        .resolve(
+            "parse.backend-a.invalid",
+            SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+        )
+        .build()
+        .unwrap();
+        client.ping().await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            requests[0].header("host"),
+            Some(format!("parse.backend-a.invalid:{}", port).as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_local_address_binding() {
+        let server =
+            MockServer::start(|_| MockResponse::json(200, json!({ "status": "ok" }))).await;
+
+        let client = Parse::builder(&server.url, "appId")
+            .local_address(IpAddr::V4(Ipv4Addr::LOCALHOST))
+            .build()
+            .unwrap();
+        client.ping().await.unwrap();
+        assert_eq!(server.requests().len(), 1);
+    }
+}
@@ -231,3 +231,51 @@ async fn test_remove_from_array_operation() {
         .await
         .expect("Failed to delete object");
 }
+
+#[tokio::test]
+async fn test_unset_field_operation() {
+    let client = setup_client_with_master_key();
+    let class_name = unique_class_name("TestItemUnset");
+    let mut object_to_create = ParseObject::new(&class_name);
+    object_to_create.set("keep", "kept");
+    object_to_create.set("remove_me", "gone soon");
+
+    let create_response = client
+        .create_object(&class_name, &object_to_create.fields)
+        .await
+        .expect("Failed to create object");
+    let object_id = create_response.object_id;
+
+    client
+        .unset_field(&class_name, &object_id, "remove_me")
+        .await
+        .expect("Failed to unset field");
+
+    let retrieved_object: RetrievedParseObject = client
+        .retrieve_object(&class_name, &object_id)
+        .await
+        .expect("Failed to retrieve object");
+    assert!(
+        !retrieved_object.fields.contains_key("remove_me"),
+        "Unset field should be absent"
+    );
+    assert_eq!(retrieved_object.fields.get("keep"), Some(&json!("kept")));
+
+    // Staging via ParseObject::unset works the same way.
+    let mut staged = ParseObject::new(&class_name);
+    staged.unset("keep");
+    client
+        .update_object(&class_name, &object_id, &staged.fields)
+        .await
+        .expect("Failed to save staged unset");
+    let retrieved_object: RetrievedParseObject = client
+        .retrieve_object(&class_name, &object_id)
+        .await
+        .expect("Failed to retrieve object");
+    assert!(!retrieved_object.fields.contains_key("keep"));
+
+    client
+        .delete_object(&class_name, &object_id)
+        .await
+        .expect("Failed to delete object");
+}
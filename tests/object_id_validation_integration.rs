@@ -0,0 +1,80 @@
+use parse_rs::{Parse, ParseError};
+use serde_json::json;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod object_id_validation_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    fn object_response() -> MockResponse {
+        MockResponse::json(
+            200,
+            json!({
+                "objectId": "custom-id-1",
+                "createdAt": "2024-01-01T00:00:00.000Z",
+                "updatedAt": "2024-01-01T00:00:00.000Z"
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_malformed_object_id_is_rejected_without_request() {
+        let server = MockServer::start(|_| object_response()).await;
+        let client = Parse::builder(&server.url, "appId")
+            .master_key("masterKey")
+            .validate_object_ids(true)
+            .build()
+            .unwrap();
+
+        for malformed in ["short", "abcdefghijk", "abc/def/gh", "abcde fghi"] {
+            let result = client.retrieve_object("Item", malformed).await;
+            assert!(
+                matches!(result, Err(ParseError::InvalidInput(ref msg)) if msg.contains("Malformed object ID")),
+                "Expected InvalidInput for {:?}, got {:?}",
+                malformed,
+                result
+            );
+        }
+        let result = client.delete_object("Item", "bad/id").await;
+        assert!(matches!(result, Err(ParseError::InvalidInput(_))));
+        let result = client.session().get_by_object_id("bad/id").await;
+        assert!(matches!(result, Err(ParseError::InvalidInput(_))));
+        assert!(server.requests().is_empty());
+
+        client
+            .retrieve_object("Item", "aB3dE6gH9j")
+            .await
+            .expect("Well-formed id should be sent");
+        assert_eq!(
+            server.requests()[0].path_only(),
+            "/parse/classes/Item/aB3dE6gH9j"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_object_id_validation_is_configurable() {
+        let server = MockServer::start(|_| object_response()).await;
+
+        let custom_ids = Parse::builder(&server.url, "appId").build().unwrap();
+        custom_ids
+            .retrieve_object("Item", "custom-id-1")
+            .await
+            .expect("Validation should be disabled by default");
+
+        let long_ids = Parse::builder(&server.url, "appId")
+            .validate_object_ids(true)
+            .object_id_length(16)
+            .build()
+            .unwrap();
+        let result = long_ids.retrieve_object("Item", "aB3dE6gH9j").await;
+        assert!(matches!(result, Err(ParseError::InvalidInput(_))));
+        long_ids
+            .retrieve_object("Item", "aB3dE6gH9jK2mN5p")
+            .await
+            .expect("16-character id should be accepted");
+
+        assert_eq!(server.requests().len(), 2);
+    }
+}
@@ -1,8 +1,8 @@
 use crate::query_test_utils::shared::{
-    cleanup_test_class, generate_unique_classname, setup_client,
+    cleanup_test_class, generate_unique_classname, setup_client, setup_client_with_master_key,
 };
 use parse_rs::object::{CreateObjectResponse, RetrievedParseObject};
-use parse_rs::ParseError;
+use parse_rs::{ParseACL, ParseError};
 use serde_json::json;
 
 mod query_test_utils;
@@ -282,4 +282,322 @@ mod object_tests {
             Err(ParseError::InvalidInput(msg)) if msg.contains("can only contain letters, numbers, or underscores")
         ));
     }
+
+    #[tokio::test]
+    async fn test_object_ops_with_master_key_on_session_client() {
+        let mut client = setup_client_with_master_key();
+        let class_name = &generate_unique_classname("TestMasterKeyOverride");
+
+        let username = format!("mk_override_{}", uuid::Uuid::new_v4().simple());
+        let signup_data = json!({ "username": username, "password": "password123" });
+        let signup_response = client
+            .user()
+            .signup(&signup_data)
+            .await
+            .expect("Failed to sign up test user");
+        assert!(client.is_authenticated());
+
+        // An empty ACL grants no access to anyone, so only the master key can read or write it.
+        let data = json!({ "secret": "initial", "ACL": ParseACL::new() });
+        let create_response = client
+            .create_object_with_master_key(class_name, &data)
+            .await
+            .expect("Failed to create object with master key");
+        let object_id = create_response.object_id;
+
+        client
+            .update_object_with_master_key(class_name, &object_id, &json!({ "secret": "updated" }))
+            .await
+            .expect("Failed to update object with master key");
+
+        let retrieved = client
+            .retrieve_object_with_master_key(class_name, &object_id)
+            .await
+            .expect("Failed to retrieve object with master key");
+        assert_eq!(
+            retrieved.fields.get("secret").and_then(|v| v.as_str()),
+            Some("updated")
+        );
+
+        // The client itself was not reconfigured by the elevated calls.
+        assert_eq!(
+            client.session_token(),
+            Some(signup_response.session_token.as_str())
+        );
+
+        let endpoint = format!("classes/{}/{}", class_name, object_id);
+        client
+            .delete_object_with_master_key(&endpoint)
+            .await
+            .expect("Failed to clean up object");
+        client
+            .delete_user(&signup_response.object_id)
+            .await
+            .expect("Failed to clean up user");
+    }
+
+    #[tokio::test]
+    async fn test_with_master_key_requires_configured_key() {
+        let client = setup_client();
+        let result = client
+            .create_object_with_master_key("MasterKeyRequiredTest", &json!({ "a": 1 }))
+            .await;
+        assert!(matches!(result, Err(ParseError::MasterKeyRequired(_))));
+    }
+
+    #[tokio::test]
+    async fn test_request_with_master_key_override() {
+        // A client without a configured master key can still make master-key calls
+        // by supplying the key per request.
+        let client = setup_client();
+        let master_key = std::env::var("PARSE_SERVER_MASTER_KEY")
+            .expect("PARSE_SERVER_MASTER_KEY not set for master key override test");
+
+        let valid: Result<serde_json::Value, ParseError> = client
+            .request_with_master_key_override(
+                reqwest::Method::GET,
+                "schemas",
+                None::<&serde_json::Value>,
+                &master_key,
+            )
+            .await;
+        assert!(
+            valid.is_ok(),
+            "Expected schemas to be readable with the correct master key, got {:?}",
+            valid.err()
+        );
+
+        let invalid: Result<serde_json::Value, ParseError> = client
+            .request_with_master_key_override(
+                reqwest::Method::GET,
+                "schemas",
+                None::<&serde_json::Value>,
+                "definitely-not-the-master-key",
+            )
+            .await;
+        assert!(
+            matches!(
+                invalid,
+                Err(ParseError::AuthenticationError(_)) | Err(ParseError::OperationForbidden(_))
+            ),
+            "Expected an auth error with the wrong master key, got {:?}",
+            invalid
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_and_update_responses_include_timestamps() {
+        let client = setup_client();
+        let class_name = &generate_unique_classname("TestTimestamps");
+
+        let create_response = create_test_object_with_fields(&client, class_name, "ts", 1).await;
+        assert!(!create_response.created_at.iso.is_empty());
+        let created_at = create_response
+            .created_at
+            .to_datetime()
+            .expect("createdAt should be a valid ISO 8601 date");
+
+        let update_response = client
+            .update_object(
+                class_name,
+                &create_response.object_id,
+                &json!({ "score": 2 }),
+            )
+            .await
+            .expect("Failed to update object");
+        let updated_at = update_response
+            .updated_at
+            .to_datetime()
+            .expect("updatedAt should be a valid ISO 8601 date");
+        assert!(updated_at >= created_at);
+
+        cleanup_test_class(&client, class_name).await;
+    }
+
+    #[tokio::test]
+    async fn test_fetch_objects_by_ids() {
+        let client = setup_client();
+        let class_name = &generate_unique_classname("TestFetchByIds");
+
+        let mut object_ids = Vec::new();
+        for score in 0..5 {
+            let created = create_test_object_with_fields(&client, class_name, "fetch", score).await;
+            object_ids.push(created.object_id);
+        }
+
+        let wanted: Vec<&str> = vec![&object_ids[0], &object_ids[2], &object_ids[4]];
+        let fetched: Vec<RetrievedParseObject> = client
+            .fetch_objects_by_ids(class_name, &wanted)
+            .await
+            .expect("Failed to fetch objects by ids");
+
+        let mut fetched_ids: Vec<&str> = fetched.iter().map(|o| o.object_id.as_str()).collect();
+        fetched_ids.sort();
+        let mut expected_ids = wanted.clone();
+        expected_ids.sort();
+        assert_eq!(fetched_ids, expected_ids);
+
+        cleanup_test_class(&client, class_name).await;
+    }
+
+    #[tokio::test]
+    async fn test_update_object_if_unchanged_rejects_stale_update() {
+        let client = setup_client();
+        let class_name = &generate_unique_classname("TestConditionalUpdate");
+        cleanup_test_class(&client, class_name).await;
+
+        let object_id = create_test_object_with_fields(&client, class_name, "v1", 1)
+            .await
+            .object_id;
+        let snapshot = client
+            .retrieve_object(class_name, &object_id)
+            .await
+            .expect("Failed to retrieve object");
+
+        // Another writer modifies the object after the snapshot was taken.
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        client
+            .update_object(
+                class_name,
+                &object_id,
+                &json!({ "some_field": "concurrent" }),
+            )
+            .await
+            .expect("Concurrent update failed");
+
+        let stale = client
+            .update_object_if_unchanged(
+                class_name,
+                &object_id,
+                &json!({ "some_field": "stale" }),
+                &snapshot.updated_at,
+            )
+            .await;
+        assert!(
+            matches!(stale, Err(ParseError::Conflict(_))),
+            "Expected Conflict, got {:?}",
+            stale
+        );
+        let current = client
+            .retrieve_object(class_name, &object_id)
+            .await
+            .expect("Failed to retrieve object");
+        assert_eq!(current.fields.get("some_field"), Some(&json!("concurrent")));
+
+        // Based on the latest version, the update goes through.
+        client
+            .update_object_if_unchanged(
+                class_name,
+                &object_id,
+                &json!({ "some_field": "fresh" }),
+                &current.updated_at,
+            )
+            .await
+            .expect("Update based on the current version should succeed");
+        let updated = client
+            .retrieve_object(class_name, &object_id)
+            .await
+            .expect("Failed to retrieve object");
+        assert_eq!(updated.fields.get("some_field"), Some(&json!("fresh")));
+
+        cleanup_test_class(&client, class_name).await;
+    }
+
+    #[tokio::test]
+    async fn test_geo_point_field_round_trip() {
+        use parse_rs::geopoint::ParseGeoPoint;
+        use parse_rs::ParseObject;
+
+        let client = setup_client();
+        let class_name = &generate_unique_classname("TestGeoPoint");
+        cleanup_test_class(&client, class_name).await;
+
+        let point = ParseGeoPoint::new(37.7749, -122.4194).expect("Valid coordinates");
+        let mut place = ParseObject::new(class_name);
+        place.set_geo_point("location", &point);
+        let created = client
+            .create_object(class_name, &place.fields)
+            .await
+            .expect("Failed to create object with a GeoPoint");
+
+        let retrieved = client
+            .retrieve_object(class_name, &created.object_id)
+            .await
+            .expect("Failed to retrieve object");
+        assert_eq!(retrieved.get_geo_point("location"), Some(point));
+
+        cleanup_test_class(&client, class_name).await;
+    }
+
+    #[tokio::test]
+    async fn test_fetch_object_fields_selects_keys() {
+        let client = setup_client();
+        let class_name = &generate_unique_classname("TestFetchFields");
+        cleanup_test_class(&client, class_name).await;
+
+        let created = create_test_object_with_fields(&client, class_name, "large", 7).await;
+
+        let partial: RetrievedParseObject = client
+            .fetch_object_fields(class_name, &created.object_id, &["score"])
+            .await
+            .expect("Failed to fetch selected fields");
+        assert_eq!(partial.object_id, created.object_id);
+        assert_eq!(partial.fields.get("score"), Some(&json!(7)));
+        assert!(
+            !partial.fields.contains_key("some_field"),
+            "Unselected field was returned: {:?}",
+            partial.fields
+        );
+
+        cleanup_test_class(&client, class_name).await;
+    }
+
+    #[tokio::test]
+    async fn test_merge_object_updates_only_given_fields() {
+        let client = setup_client();
+        let class_name = &generate_unique_classname("TestMerge");
+        cleanup_test_class(&client, class_name).await;
+
+        let created = client
+            .create_object(
+                class_name,
+                &json!({ "some_field": "keep", "score": 1, "level": 1, "name": "old" }),
+            )
+            .await
+            .expect("Failed to create object");
+
+        client
+            .merge_object(
+                class_name,
+                &created.object_id,
+                json!({ "score": 2, "name": "new" }),
+            )
+            .await
+            .expect("Failed to merge object");
+
+        let merged = client
+            .retrieve_object(class_name, &created.object_id)
+            .await
+            .expect("Failed to retrieve object");
+        assert_eq!(merged.fields.get("score"), Some(&json!(2)));
+        assert_eq!(merged.fields.get("name"), Some(&json!("new")));
+        assert_eq!(merged.fields.get("some_field"), Some(&json!("keep")));
+        assert_eq!(merged.fields.get("level"), Some(&json!(1)));
+
+        let injected = client
+            .merge_object(
+                class_name,
+                &created.object_id,
+                json!({ "score": 3, "objectId": "hijacked00" }),
+            )
+            .await;
+        assert!(matches!(injected, Err(ParseError::InvalidInput(_))));
+        let unchanged = client
+            .retrieve_object(class_name, &created.object_id)
+            .await
+            .expect("Failed to retrieve object");
+        assert_eq!(unchanged.fields.get("score"), Some(&json!(2)));
+
+        cleanup_test_class(&client, class_name).await;
+    }
 }
@@ -0,0 +1,85 @@
+use parse_rs::{Parse, ParseObject};
+use serde_json::{json, Value};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod object_save_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    fn respond(req: &RecordedRequest) -> MockResponse {
+        match req.method.as_str() {
+            "POST" => MockResponse::json(
+                201,
+                json!({ "objectId": "score00001", "createdAt": "2024-01-01T00:00:00.000Z" }),
+            ),
+            _ => MockResponse::json(200, json!({ "updatedAt": "2024-01-02T00:00:00.000Z" })),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dirty_state_across_set_and_save() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let mut score = ParseObject::new("GameScore");
+        assert!(!score.is_dirty());
+        score.set("score", 1337);
+        score.set("playerName", "Sean Plott");
+        assert_eq!(score.dirty_keys(), vec!["playerName", "score"]);
+
+        client.save_object(&mut score).await.unwrap();
+        assert!(!score.is_dirty());
+        assert_eq!(score.object_id.as_deref(), Some("score00001"));
+
+        // Saving a clean object sends nothing.
+        client.save_object(&mut score).await.unwrap();
+        assert_eq!(server.requests().len(), 1);
+
+        score.set("score", 1400);
+        assert_eq!(score.dirty_keys(), vec!["score"]);
+        client.save_object(&mut score).await.unwrap();
+        assert!(!score.is_dirty());
+        assert_eq!(
+            score.updated_at.as_ref().map(|date| date.iso.as_str()),
+            Some("2024-01-02T00:00:00.000Z")
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "POST");
+        let created: Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(
+            created,
+            json!({ "score": 1337, "playerName": "Sean Plott" })
+        );
+        assert_eq!(requests[1].method, "PUT");
+        assert_eq!(
+            requests[1].path_only(),
+            "/parse/classes/GameScore/score00001"
+        );
+        let updated: Value = serde_json::from_str(&requests[1].body).unwrap();
+        assert_eq!(updated, json!({ "score": 1400 }));
+    }
+
+    #[tokio::test]
+    async fn test_failed_save_keeps_dirty_keys() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(
+                400,
+                json!({ "code": 111, "error": "invalid type for key score" }),
+            )
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let mut score: ParseObject =
+            serde_json::from_value(json!({ "objectId": "score00001", "score": 1 })).unwrap();
+        score.class_name = "GameScore".to_string();
+        score.set("score", "not a number");
+
+        assert!(client.save_object(&mut score).await.is_err());
+        assert_eq!(score.dirty_keys(), vec!["score"]);
+    }
+}
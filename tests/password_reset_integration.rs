@@ -0,0 +1,72 @@
+use parse_rs::{Parse, ParseError};
+use serde_json::json;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod password_reset_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reset_password_with_token_request_shape() {
+        let server = MockServer::start(|_| MockResponse {
+            status: 200,
+            headers: vec![("Content-Type".to_string(), "text/plain".to_string())],
+            body: "Password successfully reset".to_string(),
+            delay: None,
+        })
+        .await;
+        let mut client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        client
+            .user()
+            .reset_password_with_token("alice", "n3w p@ss&word", "resetToken123")
+            .await
+            .expect("reset_password_with_token failed");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        let req = &requests[0];
+        assert_eq!(req.method, "POST");
+        assert_eq!(req.path, "/parse/apps/appId/request_password_reset");
+        assert_eq!(
+            req.header("Content-Type"),
+            Some("application/x-www-form-urlencoded")
+        );
+        assert_eq!(req.header("X-Requested-With"), Some("XMLHttpRequest"));
+        assert_eq!(req.header("X-Parse-REST-API-Key"), None);
+
+        let form: Vec<(String, String)> = url::form_urlencoded::parse(req.body.as_bytes())
+            .into_owned()
+            .collect();
+        assert!(form.contains(&("username".to_string(), "alice".to_string())));
+        assert!(form.contains(&("token".to_string(), "resetToken123".to_string())));
+        assert!(form.contains(&("new_password".to_string(), "n3w p@ss&word".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_with_invalid_token() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(
+                400,
+                json!({ "code": 1, "error": "Failed to reset password" }),
+            )
+        })
+        .await;
+        let mut client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let result = client
+            .user()
+            .reset_password_with_token("alice", "newPassword", "expiredToken")
+            .await;
+        assert!(result.is_err(), "Expected an error, got {:?}", result);
+
+        let result = client
+            .user()
+            .reset_password_with_token("alice", "newPassword", "")
+            .await;
+        assert!(matches!(result, Err(ParseError::InvalidInput(_))));
+        assert_eq!(server.requests().len(), 1);
+    }
+}
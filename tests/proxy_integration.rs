@@ -0,0 +1,78 @@
+use parse_rs::{Parse, ProxyConfig};
+use serde_json::json;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod proxy_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    async fn ok_server() -> MockServer {
+        MockServer::start(|_| MockResponse::json(200, json!({ "status": "ok" }))).await
+    }
+
+    #[tokio::test]
+    async fn test_requests_traverse_the_proxy() {
+        // The mock server plays the proxy: a proxied plain-HTTP request arrives with the
+        // absolute target URL in its request line.
+        let proxy = ok_server().await;
+
+        let client = Parse::builder("http://parse.example.invalid/parse", "appId")
+            .proxy(ProxyConfig::http(&proxy.base_url).basic_auth("svc-parse", "s3cret"))
+            .build()
+            .unwrap();
+        client.ping().await.unwrap();
+
+        let requests = proxy.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(
+            requests[0]
+                .path
+                .starts_with("http://parse.example.invalid/parse/"),
+            "unexpected request target {}",
+            requests[0].path
+        );
+        assert_eq!(
+            requests[0].header("proxy-authorization"),
+            Some("Basic c3ZjLXBhcnNlOnMzY3JldA==")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_no_proxy_hosts_bypass_the_proxy() {
+        let proxy = ok_server().await;
+        let server = ok_server().await;
+
+        let client = Parse::builder(&server.url, "appId")
+            .proxy(ProxyConfig::all(&proxy.base_url).no_proxy("localhost,127.0.0.1"))
+            .build()
+            .unwrap();
+        client.ping().await.unwrap();
+
+        assert!(proxy.requests().is_empty());
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_client_builds_with_proxy_config() {
+        let proxy = ProxyConfig::https("http://proxy.corp.example:3128")
+            .basic_auth("svc-parse", "s3cret")
+            .no_proxy(".corp.example");
+        assert!(!format!("{:?}", proxy).contains("s3cret"));
+
+        let client = Parse::builder("https://parse.example.com/parse", "appId")
+            .proxy(ProxyConfig::http("http://proxy.corp.example:3128"))
+            .proxy(proxy)
+            .build();
+        assert!(client.is_ok());
+    }
+
+    #[test]
+    fn test_invalid_proxy_url_fails_to_build() {
+        let client = Parse::builder("https://parse.example.com/parse", "appId")
+            .proxy(ProxyConfig::all("not a url"))
+            .build();
+        assert!(client.is_err());
+    }
+}
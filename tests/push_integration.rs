@@ -0,0 +1,107 @@
+use parse_rs::{Parse, ParseError, ParseQuery, PushPayload};
+use serde_json::{json, Value};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod push_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    async fn start_push_server() -> MockServer {
+        MockServer::start(|_| {
+            MockResponse::json(200, json!({ "result": true }))
+                .with_header("X-Parse-Push-Status-Id", "pushStat01")
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_send_push_to_channels() {
+        let server = start_push_server().await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+
+        let push = PushPayload::new(json!({ "alert": "The Giants won!", "badge": "Increment" }))
+            .channels(&["Giants", "Mets"]);
+        let status_id = client.send_push(&push).await.unwrap();
+        assert_eq!(status_id, "pushStat01");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path_only(), "/parse/push");
+        assert_eq!(requests[0].header("X-Parse-Master-Key"), Some("masterKey"));
+        let body: Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "channels": ["Giants", "Mets"],
+                "data": { "alert": "The Giants won!", "badge": "Increment" }
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_push_to_query() {
+        let server = start_push_server().await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+
+        let mut query = ParseQuery::new("_Installation");
+        query
+            .equal_to("deviceType", "ios")
+            .equal_to("injuryReports", true);
+        let push = PushPayload::new(json!({ "alert": "Willie Hayes injured" })).where_query(&query);
+        client.send_push(&push).await.unwrap();
+
+        let body: Value = serde_json::from_str(&server.requests()[0].body).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "where": { "deviceType": "ios", "injuryReports": true },
+                "data": { "alert": "Willie Hayes injured" }
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_push_requires_a_target_and_master_key() {
+        let server = start_push_server().await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+        let untargeted = PushPayload::new(json!({ "alert": "Hello" }));
+        assert!(matches!(
+            client.send_push(&untargeted).await,
+            Err(ParseError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            client.send_push(&untargeted.clone().channels(&[])).await,
+            Err(ParseError::InvalidInput(_))
+        ));
+
+        let without_master_key =
+            Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+        assert!(matches!(
+            without_master_key
+                .send_push(&untargeted.channels(&["Giants"]))
+                .await,
+            Err(ParseError::MasterKeyRequired(_))
+        ));
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_push_reports_server_errors() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(
+                400,
+                json!({ "code": 115, "error": "Missing push configuration" }),
+            )
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+
+        let push = PushPayload::new(json!({ "alert": "Hello" })).channels(&["Giants"]);
+        assert!(matches!(
+            client.send_push(&push).await,
+            Err(ParseError::OtherParseError { code: 115, .. })
+        ));
+    }
+}
@@ -144,6 +144,61 @@ mod advanced_query_tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_query_equal_to_null_vs_does_not_exist() -> Result<(), ParseError> {
+        let (client, master_key_client, class_name_str) =
+            setup_clients_and_class_name("TestNullQuery").await;
+        let class_name = class_name_str.as_str();
+
+        cleanup_test_class(&client, class_name).await;
+
+        let present = create_test_object(
+            &master_key_client,
+            class_name,
+            json!({ "name": "Present", "optional_field": "value" }),
+        )
+        .await
+        .unwrap();
+        let explicit_null = create_test_object(
+            &master_key_client,
+            class_name,
+            json!({ "name": "ExplicitNull", "optional_field": Value::Null }),
+        )
+        .await
+        .unwrap();
+        let absent =
+            create_test_object(&master_key_client, class_name, json!({ "name": "Absent" }))
+                .await
+                .unwrap();
+
+        let mut query_null = ParseQuery::new(class_name);
+        query_null.equal_to_null("optional_field");
+        let results_null: Vec<TestObject> = query_null
+            .find(&master_key_client)
+            .await
+            .expect("Query equal_to_null failed");
+        assert_eq!(results_null, vec![explicit_null.clone()]);
+
+        let mut query_missing = ParseQuery::new(class_name);
+        query_missing.does_not_exist("optional_field");
+        let results_missing: Vec<TestObject> = query_missing
+            .find(&master_key_client)
+            .await
+            .expect("Query does_not_exist failed");
+        assert_eq!(results_missing, vec![absent.clone()]);
+
+        let mut query_exists = ParseQuery::new(class_name);
+        query_exists.exists("optional_field").order("name");
+        let results_exists: Vec<TestObject> = query_exists
+            .find(&master_key_client)
+            .await
+            .expect("Query exists failed");
+        assert_eq!(results_exists, vec![explicit_null, present]);
+
+        cleanup_test_class(&client, class_name).await;
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_query_string_matching_ops() -> Result<(), ParseError> {
         let (client, _master_key_client, class_name_str) =
@@ -209,7 +264,7 @@ mod advanced_query_tests {
         assert!(results_starts.contains(&obj3));
 
         let mut query_ends = ParseQuery::new(class_name);
-        query_ends.matches_regex("description", "World$", None);
+        query_ends.matches_regex("description", "World$", None)?;
         let results_ends: Vec<TestObject> = query_ends
             .find(&client)
             .await
@@ -222,7 +277,7 @@ mod advanced_query_tests {
         assert!(results_ends.contains(&obj1));
 
         let mut query_contains = ParseQuery::new(class_name);
-        query_contains.matches_regex("description", ".*Wor.*", None);
+        query_contains.matches_regex("description", ".*Wor.*", None)?;
         let results_contains: Vec<TestObject> = query_contains
             .find(&client)
             .await
@@ -286,7 +341,7 @@ mod advanced_query_tests {
             .unwrap();
 
         let mut query_matches_status_exact = ParseQuery::new(class_name);
-        query_matches_status_exact.matches_regex("status", "^active$", None);
+        query_matches_status_exact.matches_regex("status", "^active$", None)?;
         let results_matches_status_exact: Vec<TestObject> =
             query_matches_status_exact.find(&client).await?;
         assert_eq!(
@@ -300,7 +355,7 @@ mod advanced_query_tests {
         );
 
         let mut query_regex_name_starts = ParseQuery::new(class_name);
-        query_regex_name_starts.matches_regex("name", "^object", Some("i"));
+        query_regex_name_starts.matches_regex("name", "^object", Some("i"))?;
         let results_regex_name_starts: Vec<TestObject> = query_regex_name_starts
             .find(&client)
             .await
@@ -312,7 +367,7 @@ mod advanced_query_tests {
         );
 
         let mut query_regex_status_ends = ParseQuery::new(class_name);
-        query_regex_status_ends.matches_regex("status", "^active$", None);
+        query_regex_status_ends.matches_regex("status", "^active$", None)?;
         let results_regex_status_ends: Vec<TestObject> = query_regex_status_ends
             .find(&client)
             .await
@@ -599,4 +654,41 @@ mod advanced_query_tests {
         cleanup_test_class(&client, class_name).await;
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_query_count_distinct() -> Result<(), ParseError> {
+        let (_client, master_key_client, class_name_str) =
+            setup_clients_and_class_name("TestCountDistinct").await;
+        let class_name = class_name_str.as_str();
+
+        for (name, category) in [
+            ("Item A", "electronics"),
+            ("Item B", "fashion"),
+            ("Item C", "electronics"),
+            ("Item D", "garden"),
+            ("Item E", "fashion"),
+        ] {
+            create_test_object(
+                &master_key_client,
+                class_name,
+                json!({ "name": name, "category": category }),
+            )
+            .await?;
+        }
+
+        let mut query = ParseQuery::new(class_name);
+        query.exists("category");
+        let count = query.count_distinct(&master_key_client, "category").await?;
+        assert_eq!(count, 3, "Expected electronics, fashion and garden");
+
+        let mut query_none = ParseQuery::new(class_name);
+        query_none.equal_to("category", "toys");
+        let count_none = query_none
+            .count_distinct(&master_key_client, "category")
+            .await?;
+        assert_eq!(count_none, 0);
+
+        cleanup_test_class(&master_key_client, class_name).await;
+        Ok(())
+    }
 }
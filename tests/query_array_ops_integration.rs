@@ -380,5 +380,73 @@ mod array_ops_tests {
         cleanup_test_class(&client, &class_name).await;
     }
 
+    #[tokio::test]
+    async fn test_query_contained_in_huge_list() {
+        let client = setup_client();
+        let class_name = format!("TestArrayOps_{}", Uuid::new_v4().simple());
+        cleanup_test_class(&client, &class_name).await;
+
+        let score1 = create_test_score(&client, &class_name, 10, "PlayerHuge1", None, None)
+            .await
+            .unwrap();
+        let _score2 = create_test_score(&client, &class_name, 20, "PlayerHuge2", None, None)
+            .await
+            .unwrap();
+
+        // Far more than fits in a URL; the query is sent as a POST with `_method=GET`.
+        let mut player_names: Vec<String> = (0..5000)
+            .map(|i| format!("MissingPlayer_{:05}", i))
+            .collect();
+        player_names.push("PlayerHuge1".to_string());
+        let mut query = ParseQuery::new(&class_name);
+        query.contained_in("player_name", player_names);
+        let results: Vec<GameScore> = query
+            .find(&client)
+            .await
+            .expect("Query contained_in with a huge list failed");
+        assert_eq!(results, vec![score1]);
+
+        cleanup_test_class(&client, &class_name).await;
+    }
+
     // Tests for contains_all will go here
+
+    #[tokio::test]
+    async fn test_query_contained_by() {
+        let client = setup_client();
+        let class_name = format!("TestArrayOps_{}", Uuid::new_v4().simple());
+        cleanup_test_class(&client, &class_name).await;
+
+        let tag_sets: [&[&str]; 4] = [
+            &["news"],
+            &["news", "sports"],
+            &["news", "politics"],
+            &["weather"],
+        ];
+        for (i, tags) in tag_sets.iter().enumerate() {
+            create_test_score(
+                &client,
+                &class_name,
+                i as i32,
+                &format!("PlayerTags{}", i),
+                None,
+                Some(tags.iter().map(|t| t.to_string()).collect()),
+            )
+            .await
+            .unwrap();
+        }
+
+        // Only objects whose tags are all within the allowed set match.
+        let mut query = ParseQuery::new(&class_name);
+        query.contained_by("skills", vec!["news", "sports"]);
+        let results: Vec<GameScore> = query
+            .find(&client)
+            .await
+            .expect("Query contained_by failed");
+        let mut names: Vec<&str> = results.iter().map(|s| s.player_name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["PlayerTags0", "PlayerTags1"]);
+
+        cleanup_test_class(&client, &class_name).await;
+    }
 }
@@ -124,4 +124,172 @@ mod basic_ops_tests {
 
         cleanup_test_class(&client, &class_name).await;
     }
+
+    #[tokio::test]
+    async fn test_query_find_ids() {
+        let client = setup_client();
+        let class_name = format!("TestBasicOps_{}", Uuid::new_v4().simple());
+        cleanup_test_class(&client, &class_name).await;
+
+        let mut expected_ids = Vec::new();
+        for (score, player) in [(10, "IdsOne"), (20, "IdsTwo"), (30, "IdsThree")] {
+            let created = create_test_score(&client, &class_name, score, player, None, None)
+                .await
+                .expect("Failed to create test score");
+            expected_ids.push(created.object_id.expect("Created score missing objectId"));
+        }
+
+        let mut query = ParseQuery::new(&class_name);
+        query.greater_than("score", 10);
+        let mut ids = query.find_ids(&client).await.expect("find_ids failed");
+        ids.sort();
+        let mut expected: Vec<String> = expected_ids[1..].to_vec();
+        expected.sort();
+        assert_eq!(ids, expected);
+
+        // The same keys restriction returns objects without any user fields.
+        query.select(&["objectId"]);
+        let raw: Vec<serde_json::Value> = query.find(&client).await.expect("find failed");
+        assert_eq!(raw.len(), 2);
+        for item in raw {
+            assert!(item.get("objectId").is_some());
+            assert!(item.get("score").is_none());
+            assert!(item.get("player_name").is_none());
+        }
+
+        cleanup_test_class(&client, &class_name).await;
+    }
+
+    fn scores_above(class_name: &str, minimum: i32) -> ParseQuery {
+        ParseQuery::new(class_name)
+            .with_greater_than("score", minimum)
+            .with_order("-score")
+            .with_limit(10)
+    }
+
+    #[tokio::test]
+    async fn test_query_owned_builder() {
+        let client = setup_client();
+        let class_name = format!("TestBasicOps_{}", Uuid::new_v4().simple());
+        cleanup_test_class(&client, &class_name).await;
+
+        for (score, player) in [(10, "OwnedOne"), (20, "OwnedTwo"), (30, "OwnedThree")] {
+            create_test_score(&client, &class_name, score, player, None, None)
+                .await
+                .expect("Failed to create test score");
+        }
+
+        let query = scores_above(&class_name, 10);
+        let results: Vec<GameScore> = query.find(&client).await.expect("Query find failed");
+        let scores: Vec<i32> = results.iter().map(|r| r.score).collect();
+        assert_eq!(scores, vec![30, 20]);
+
+        // The stored query can be refined further without affecting the original.
+        let narrowed = query.clone().with_equal_to("player_name", "OwnedTwo");
+        let results: Vec<GameScore> = narrowed.find(&client).await.expect("Query find failed");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].player_name, "OwnedTwo");
+        assert_eq!(query.count(&client).await.expect("Query count failed"), 2);
+
+        cleanup_test_class(&client, &class_name).await;
+    }
+
+    #[tokio::test]
+    async fn test_query_find_all_past_page_limit() {
+        let client = setup_client();
+        let class_name = format!("TestBasicOps_{}", Uuid::new_v4().simple());
+        cleanup_test_class(&client, &class_name).await;
+
+        for i in 0..150 {
+            create_test_score(
+                &client,
+                &class_name,
+                i,
+                &format!("FindAll{}", i),
+                None,
+                None,
+            )
+            .await
+            .expect("Failed to create test score");
+        }
+
+        // A plain find stops at the server's default page size of 100.
+        let query = ParseQuery::new(&class_name);
+        let first_page: Vec<GameScore> = query.find(&client).await.expect("Query find failed");
+        assert_eq!(first_page.len(), 100);
+
+        let all: Vec<GameScore> = query.find_all(&client).await.expect("find_all failed");
+        assert_eq!(all.len(), 150);
+        let mut scores: Vec<i32> = all.iter().map(|score| score.score).collect();
+        scores.sort();
+        assert_eq!(scores, (0..150).collect::<Vec<i32>>());
+
+        cleanup_test_class(&client, &class_name).await;
+    }
+
+    #[tokio::test]
+    async fn test_query_find_with_count_pages() {
+        let client = setup_client();
+        let class_name = format!("TestBasicOps_{}", Uuid::new_v4().simple());
+        cleanup_test_class(&client, &class_name).await;
+
+        for i in 0..5 {
+            create_test_score(&client, &class_name, i, &format!("Page{}", i), None, None)
+                .await
+                .expect("Failed to create test score");
+        }
+
+        let mut query = ParseQuery::new(&class_name);
+        query.order("score").limit(2);
+        let mut page = query
+            .find_with_count::<GameScore>(&client)
+            .await
+            .expect("find_with_count failed");
+        let mut scores: Vec<i32> = page.iter().map(|s| s.score).collect();
+        assert_eq!(page.count, Some(5));
+        while let Some(next) = page.next_query() {
+            page = next
+                .find_with_count(&client)
+                .await
+                .expect("find_with_count failed");
+            scores.extend(page.iter().map(|s| s.score));
+        }
+        assert_eq!(scores, vec![0, 1, 2, 3, 4]);
+        assert_eq!(page.skip, 4);
+
+        cleanup_test_class(&client, &class_name).await;
+    }
+
+    #[tokio::test]
+    async fn test_query_count_and_estimated_count() {
+        let client = setup_client();
+        let class_name = format!("TestBasicOps_{}", Uuid::new_v4().simple());
+        cleanup_test_class(&client, &class_name).await;
+
+        for i in 0..20 {
+            create_test_score(&client, &class_name, i, &format!("Count{}", i), None, None)
+                .await
+                .expect("Failed to create test score");
+        }
+
+        let mut query = ParseQuery::new(&class_name);
+        query.set_master_key(true);
+        let exact = query.count(&client).await.expect("Query count failed");
+        let estimated = query
+            .estimated_count(&client)
+            .await
+            .expect("Query estimated_count failed");
+        assert_eq!(exact, 20);
+        // Nothing is being written, so the estimate should match the exact count.
+        assert_eq!(estimated, exact);
+
+        query.greater_than("score", 9);
+        assert_eq!(query.count(&client).await.expect("Query count failed"), 10);
+        assert!(matches!(
+            query.estimated_count(&client).await,
+            Err(ParseError::InvalidQuery(_))
+        ));
+
+        cleanup_test_class(&client, &class_name).await;
+    }
 }
@@ -0,0 +1,160 @@
+use parse_rs::{Parse, ParseError, ParseQuery};
+use serde_json::{json, Value};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod bulk_update_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    // Answers a find with the page of `ids` selected by `skip` and `limit`, returning at most
+    // `max_limit` objects like a server configured with that `maxLimit`.
+    fn id_page(req: &RecordedRequest, ids: &[&str], max_limit: usize) -> MockResponse {
+        let params = req.query_params();
+        let skip: usize = params.get("skip").map_or(0, |s| s.parse().unwrap());
+        let limit: usize = params.get("limit").map_or(100, |s| s.parse().unwrap());
+        let results: Vec<Value> = ids
+            .iter()
+            .skip(skip)
+            .take(limit.min(max_limit))
+            .map(|id| json!({ "objectId": id }))
+            .collect();
+        MockResponse::json(200, json!({ "results": results }))
+    }
+
+    // Serves `total` matching objects on find, and answers batch requests with one
+    // success entry per sub-request (or an error for the object id "bad").
+    fn respond(total: usize, req: &RecordedRequest) -> MockResponse {
+        match (req.method.as_str(), req.path_only()) {
+            ("GET", "/parse/classes/Item") => {
+                let ids: Vec<String> = (0..total).map(|i| format!("id{:04}", i)).collect();
+                let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+                id_page(req, &ids, 1000)
+            }
+            ("POST", "/parse/batch") => {
+                let body: Value = serde_json::from_str(&req.body).unwrap();
+                let results: Vec<Value> = body["requests"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|r| {
+                        if r["path"].as_str().unwrap().ends_with("/bad") {
+                            json!({ "error": { "code": 101, "error": "Object not found." } })
+                        } else {
+                            json!({ "success": { "updatedAt": "2024-01-01T00:00:00.000Z" } })
+                        }
+                    })
+                    .collect();
+                MockResponse::json(200, json!(results))
+            }
+            _ => MockResponse::json(404, json!({ "error": "unexpected request" })),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_all_with_progress_reports_increasing_counts() {
+        let server = MockServer::start(|req| respond(120, req)).await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+
+        let mut query = ParseQuery::new("Item");
+        query.equal_to("status", "pending").set_master_key(true);
+
+        let mut calls = Vec::new();
+        let updated = query
+            .update_all_with_progress(&client, &json!({ "status": "done" }), |done, total| {
+                calls.push((done, total))
+            })
+            .await
+            .expect("update_all_with_progress failed");
+
+        assert_eq!(updated, 120);
+        assert_eq!(calls, vec![(50, 120), (100, 120), (120, 120)]);
+
+        let requests = server.requests();
+        let find = &requests[0];
+        assert_eq!(
+            find.query_params().get("keys").map(String::as_str),
+            Some("objectId")
+        );
+        let batches: Vec<_> = requests.iter().filter(|r| r.method == "POST").collect();
+        assert_eq!(batches.len(), 3);
+        let first_batch: Value = serde_json::from_str(&batches[0].body).unwrap();
+        assert_eq!(
+            first_batch["requests"][0],
+            json!({
+                "method": "PUT",
+                "path": "/parse/classes/Item/id0000",
+                "body": { "status": "done" }
+            })
+        );
+        assert_eq!(batches[0].header("X-Parse-Master-Key"), Some("masterKey"));
+    }
+
+    #[tokio::test]
+    async fn test_update_all_stops_on_batch_error() {
+        let server = MockServer::start(|req| match (req.method.as_str(), req.path_only()) {
+            ("GET", _) => id_page(req, &["bad"], 1000),
+            _ => respond(0, req),
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let result = ParseQuery::new("Item")
+            .update_all(&client, &json!({ "status": "done" }))
+            .await;
+        assert!(
+            matches!(result, Err(ParseError::ObjectNotFound(_))),
+            "Unexpected result: {:?}",
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_all_counts_successes_of_a_failed_chunk() {
+        let server = MockServer::start(|req| match (req.method.as_str(), req.path_only()) {
+            ("GET", _) => id_page(req, &["id0000", "bad", "id0002"], 1000),
+            _ => respond(0, req),
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let mut calls = Vec::new();
+        let result = ParseQuery::new("Item")
+            .update_all_with_progress(&client, &json!({ "status": "done" }), |done, total| {
+                calls.push((done, total))
+            })
+            .await;
+
+        assert!(matches!(result, Err(ParseError::ObjectNotFound(_))));
+        assert_eq!(calls, vec![(2, 3)]);
+    }
+
+    #[tokio::test]
+    async fn test_update_all_follows_pages_shortened_by_max_limit() {
+        let server = MockServer::start(|req| match (req.method.as_str(), req.path_only()) {
+            ("GET", _) => {
+                let ids: Vec<String> = (0..120).map(|i| format!("id{:04}", i)).collect();
+                let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+                id_page(req, &ids, 50)
+            }
+            _ => respond(0, req),
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let updated = ParseQuery::new("Item")
+            .update_all(&client, &json!({ "status": "done" }))
+            .await
+            .expect("update_all failed");
+        assert_eq!(updated, 120);
+
+        let skips: Vec<String> = server
+            .requests()
+            .iter()
+            .filter(|req| req.method == "GET")
+            .map(|req| req.query_params()["skip"].clone())
+            .collect();
+        assert_eq!(skips, vec!["0", "50", "100", "120"]);
+    }
+}
@@ -232,4 +232,32 @@ mod comparison_ops_tests {
 
         cleanup_test_class(&client, &class_name).await;
     }
+
+    #[tokio::test]
+    async fn test_query_bounded_range() {
+        let client = setup_client();
+        let class_name = format!("TestComparisonOps_{}", Uuid::new_v4().simple());
+        cleanup_test_class(&client, &class_name).await;
+
+        create_test_score(&client, &class_name, 50, "PlayerRange1", None, None)
+            .await
+            .unwrap();
+        let score2 = create_test_score(&client, &class_name, 100, "PlayerRange2", None, None)
+            .await
+            .unwrap();
+        create_test_score(&client, &class_name, 150, "PlayerRange3", None, None)
+            .await
+            .unwrap();
+
+        // Both bounds must survive: only the middle score is strictly between them.
+        let mut query = ParseQuery::new(&class_name);
+        query.greater_than("score", 50).less_than("score", 150);
+        let results: Vec<GameScore> = query
+            .find(&client)
+            .await
+            .expect("Query bounded range failed");
+        assert_eq!(results, vec![score2]);
+
+        cleanup_test_class(&client, &class_name).await;
+    }
 }
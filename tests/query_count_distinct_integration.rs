@@ -0,0 +1,48 @@
+use parse_rs::{Parse, ParseQuery};
+use serde_json::{json, Value};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod query_count_distinct_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_count_distinct_groups_then_counts() {
+        let server =
+            MockServer::start(|_| MockResponse::json(200, json!({ "results": [{ "count": 3 }] })))
+                .await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+
+        let mut query = ParseQuery::new("Product");
+        query.equal_to("inStock", true);
+        let count = query.count_distinct(&client, "category").await.unwrap();
+        assert_eq!(count, 3);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path_only(), "/parse/aggregate/Product");
+        let pipeline: Value =
+            serde_json::from_str(&requests[0].query_params()["pipeline"]).unwrap();
+        assert_eq!(
+            pipeline,
+            json!([
+                { "$match": { "inStock": true } },
+                { "$group": { "_id": "$category" } },
+                { "$count": "count" }
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_count_distinct_without_matches_is_zero() {
+        let server = MockServer::start(|_| MockResponse::json(200, json!({ "results": [] }))).await;
+        let client = Parse::new(&server.url, "appId", None, None, Some("masterKey")).unwrap();
+
+        let count = ParseQuery::new("Product")
+            .count_distinct(&client, "category")
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+}
@@ -131,4 +131,39 @@ mod equality_ops_tests {
 
         cleanup_test_class(&client, &class_name).await;
     }
+
+    #[tokio::test]
+    async fn test_query_equals_ignore_case_anchored() {
+        let client = setup_client();
+        let class_name = format!("TestEqualityOps_{}", Uuid::new_v4().simple());
+        cleanup_test_class(&client, &class_name).await;
+
+        for name in ["Alice", "alice", "ALICE", "Alicex", "xalice", "Al.ce"] {
+            create_test_score(&client, &class_name, 1, name, None, None)
+                .await
+                .unwrap();
+        }
+
+        let mut query = ParseQuery::new(&class_name);
+        query.equals_ignore_case_anchored("player_name", "alice");
+        let results: Vec<GameScore> = query
+            .find(&client)
+            .await
+            .expect("Query equals_ignore_case_anchored failed");
+        let mut names: Vec<&str> = results.iter().map(|s| s.player_name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["ALICE", "Alice", "alice"]);
+
+        // Regex metacharacters in the value are matched literally.
+        let mut query_dot = ParseQuery::new(&class_name);
+        query_dot.equals_ignore_case_anchored("player_name", "al.ce");
+        let results_dot: Vec<GameScore> = query_dot
+            .find(&client)
+            .await
+            .expect("Query equals_ignore_case_anchored with dot failed");
+        assert_eq!(results_dot.len(), 1);
+        assert_eq!(results_dot[0].player_name, "Al.ce");
+
+        cleanup_test_class(&client, &class_name).await;
+    }
 }
@@ -0,0 +1,147 @@
+use parse_rs::{Parse, ParseError, ParseQuery};
+use serde_json::{json, Value};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod query_find_all_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    const TOTAL_OBJECTS: usize = 2500;
+
+    // Serves `TOTAL_OBJECTS` objects, honoring the `limit` and `skip` parameters.
+    fn paged_results(req: &RecordedRequest) -> MockResponse {
+        let params = req.query_params();
+        let skip: usize = params.get("skip").map_or(0, |s| s.parse().unwrap());
+        let limit: usize = params.get("limit").map_or(100, |s| s.parse().unwrap());
+        let results: Vec<Value> = (skip..TOTAL_OBJECTS.min(skip + limit))
+            .map(|i| json!({ "objectId": format!("obj{:07}", i), "index": i }))
+            .collect();
+        MockResponse::json(200, json!({ "results": results }))
+    }
+
+    #[tokio::test]
+    async fn test_find_all_pages_until_exhausted() {
+        let server = MockServer::start(paged_results).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let results: Vec<Value> = ParseQuery::new("Item")
+            .find_all(&client)
+            .await
+            .expect("find_all failed");
+        assert_eq!(results.len(), TOTAL_OBJECTS);
+        assert_eq!(
+            results[TOTAL_OBJECTS - 1]["index"],
+            json!(TOTAL_OBJECTS - 1)
+        );
+
+        let pages: Vec<(String, String, String)> = server
+            .requests()
+            .iter()
+            .map(|req| {
+                let params = req.query_params();
+                (
+                    params["limit"].clone(),
+                    params["skip"].clone(),
+                    params["order"].clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            pages,
+            vec![
+                ("1000".to_string(), "0".to_string(), "objectId".to_string()),
+                (
+                    "1000".to_string(),
+                    "1000".to_string(),
+                    "objectId".to_string()
+                ),
+                (
+                    "1000".to_string(),
+                    "2000".to_string(),
+                    "objectId".to_string()
+                ),
+                (
+                    "1000".to_string(),
+                    "2500".to_string(),
+                    "objectId".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_all_follows_pages_shortened_by_max_limit() {
+        // A server with `maxLimit: 300` returns at most 300 objects whatever the limit asked for.
+        let server = MockServer::start(|req| {
+            let params = req.query_params();
+            let skip: usize = params["skip"].parse().unwrap();
+            let limit: usize = params["limit"].parse::<usize>().unwrap().min(300);
+            let results: Vec<Value> = (skip..TOTAL_OBJECTS.min(skip + limit))
+                .map(|i| json!({ "objectId": format!("obj{:07}", i), "index": i }))
+                .collect();
+            MockResponse::json(200, json!({ "results": results }))
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let results: Vec<Value> = ParseQuery::new("Item")
+            .find_all(&client)
+            .await
+            .expect("find_all failed");
+        assert_eq!(results.len(), TOTAL_OBJECTS);
+        assert_eq!(results[300]["index"], json!(300));
+
+        let skips: Vec<String> = server
+            .requests()
+            .iter()
+            .map(|req| req.query_params()["skip"].clone())
+            .collect();
+        assert_eq!(skips[..3], ["0", "300", "600"]);
+    }
+
+    #[tokio::test]
+    async fn test_find_all_keeps_order_limit_and_skip() {
+        let server = MockServer::start(paged_results).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let mut query = ParseQuery::new("Item");
+        query.order("-index").skip(100).limit(1200);
+        let results: Vec<Value> = query.find_all(&client).await.expect("find_all failed");
+        assert_eq!(results.len(), 1200);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        let second = requests[1].query_params();
+        assert_eq!(second["order"], "-index,objectId");
+        assert_eq!(second["limit"], "200");
+        assert_eq!(second["skip"], "1100");
+    }
+
+    #[tokio::test]
+    async fn test_find_all_fails_past_cap() {
+        let server = MockServer::start(paged_results).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let result = ParseQuery::new("Item")
+            .find_all_with_cap::<Value>(&client, 1500)
+            .await;
+        assert!(
+            matches!(result, Err(ParseError::TooManyResults(1500))),
+            "Unexpected result: {:?}",
+            result.map(|r| r.len())
+        );
+        // The second page only asks for enough objects to detect the overflow.
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].query_params()["limit"], "501");
+
+        // Exactly `cap` matches is not an error.
+        let results: Vec<Value> = ParseQuery::new("Item")
+            .find_all_with_cap(&client, TOTAL_OBJECTS)
+            .await
+            .expect("find_all_with_cap failed");
+        assert_eq!(results.len(), TOTAL_OBJECTS);
+    }
+}
@@ -0,0 +1,100 @@
+mod query_test_utils;
+
+#[cfg(test)]
+mod geo_ops_tests {
+    use super::query_test_utils::shared::*;
+    use parse_rs::geopoint::ParseGeoPoint;
+    use parse_rs::query::ParseQuery;
+    use serde_json::json;
+
+    fn geo_point(latitude: f64, longitude: f64) -> ParseGeoPoint {
+        ParseGeoPoint::new(latitude, longitude).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_query_within_polygon() {
+        let client = setup_client();
+        let class_name = generate_unique_classname("TestGeoOps");
+        cleanup_test_class(&client, &class_name).await;
+
+        let inside = create_test_object(
+            &client,
+            &class_name,
+            json!({ "name": "Inside", "location": geo_point(2.0, 2.0) }),
+        )
+        .await
+        .expect("Failed to create the inside object");
+        create_test_object(
+            &client,
+            &class_name,
+            json!({ "name": "Outside", "location": geo_point(8.0, 8.0) }),
+        )
+        .await
+        .expect("Failed to create the outside object");
+
+        // The triangle (0,0), (0,10), (10,0) contains (2,2) but not (8,8).
+        let triangle = vec![
+            geo_point(0.0, 0.0),
+            geo_point(0.0, 10.0),
+            geo_point(10.0, 0.0),
+        ];
+        let mut query = ParseQuery::new(&class_name);
+        query.within_polygon("location", triangle).unwrap();
+        let results: Vec<TestObject> = query
+            .find(&client)
+            .await
+            .expect("Query within_polygon failed");
+
+        assert_eq!(results.len(), 1, "Expected only the point inside to match");
+        assert_eq!(results[0].object_id, inside.object_id);
+        assert_eq!(results[0].fields["name"], json!("Inside"));
+
+        cleanup_test_class(&client, &class_name).await;
+    }
+
+    #[tokio::test]
+    async fn test_query_polygon_contains() {
+        let client = setup_client();
+        let class_name = generate_unique_classname("TestGeoOps");
+        cleanup_test_class(&client, &class_name).await;
+
+        let north = create_test_object(
+            &client,
+            &class_name,
+            json!({
+                "name": "North",
+                "area": {
+                    "__type": "Polygon",
+                    "coordinates": [[10.0, 0.0], [10.0, 10.0], [20.0, 10.0], [20.0, 0.0]]
+                }
+            }),
+        )
+        .await
+        .expect("Failed to create the north zone");
+        create_test_object(
+            &client,
+            &class_name,
+            json!({
+                "name": "South",
+                "area": {
+                    "__type": "Polygon",
+                    "coordinates": [[-20.0, 0.0], [-20.0, 10.0], [-10.0, 10.0], [-10.0, 0.0]]
+                }
+            }),
+        )
+        .await
+        .expect("Failed to create the south zone");
+
+        let mut query = ParseQuery::new(&class_name);
+        query.polygon_contains("area", geo_point(15.0, 5.0));
+        let results: Vec<TestObject> = query
+            .find(&client)
+            .await
+            .expect("Query polygon_contains failed");
+
+        assert_eq!(results.len(), 1, "Expected only the north zone to match");
+        assert_eq!(results[0].object_id, north.object_id);
+
+        cleanup_test_class(&client, &class_name).await;
+    }
+}
@@ -0,0 +1,54 @@
+use parse_rs::{Parse, ParseQuery};
+use serde_json::json;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod query_pluck_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_pluck_returns_every_value_of_the_field() {
+        let server = MockServer::start(|_| {
+            let results: Vec<_> = [(1, 30), (2, 10), (3, 30), (4, 20), (5, 50)]
+                .iter()
+                .map(|(i, score)| json!({ "objectId": format!("score{:05}", i), "score": score }))
+                .collect();
+            MockResponse::json(200, json!({ "results": results }))
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let mut query = ParseQuery::new("GameScore");
+        query
+            .equal_to("playerName", "Sean")
+            .select(&["playerName", "createdAt"]);
+        let scores: Vec<i64> = query.pluck(&client, "score").await.unwrap();
+        assert_eq!(scores, vec![30, 10, 30, 20, 50]);
+
+        let params = server.requests()[0].query_params();
+        assert_eq!(params["keys"], "score");
+        assert_eq!(params["where"], r#"{"playerName":"Sean"}"#);
+    }
+
+    #[tokio::test]
+    async fn test_pluck_nested_and_missing_values() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(
+                200,
+                json!({ "results": [
+                    { "objectId": "stats00001", "stats": { "score": 7 } },
+                    { "objectId": "stats00002" }
+                ] }),
+            )
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let query = ParseQuery::new("Player");
+        let scores: Vec<Option<i64>> = query.pluck(&client, "stats.score").await.unwrap();
+        assert_eq!(scores, vec![Some(7), None]);
+        assert!(query.pluck::<i64>(&client, "stats.score").await.is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use parse_rs::Parse;
+use parse_rs::ParseQuery;
+use serde_json::{json, Value};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod query_post_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    // Behaves like a server behind a proxy with a URL length limit.
+    fn respond(req: &RecordedRequest) -> MockResponse {
+        if req.path.len() > 8192 {
+            return MockResponse::json(414, json!({ "error": "URI Too Long" }));
+        }
+        MockResponse::json(
+            200,
+            json!({ "results": [{ "objectId": "match" }], "count": 1 }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_huge_contained_in_is_sent_via_post() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let names: Vec<String> = (0..2000).map(|i| format!("player_{:05}", i)).collect();
+        let mut query = ParseQuery::new("Item");
+        query.contained_in("name", names).limit(10);
+        let results: Vec<Value> = query.find(&client).await.expect("find failed");
+        assert_eq!(results.len(), 1);
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/parse/classes/Item");
+        let body: Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(body["_method"], "GET");
+        assert_eq!(body["limit"], 10);
+        assert_eq!(body["where"]["name"]["$in"].as_array().unwrap().len(), 2000);
+    }
+
+    #[tokio::test]
+    async fn test_small_queries_use_get_unless_forced() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let mut query = ParseQuery::new("Item");
+        query.equal_to("name", "a").select(&["name"]);
+        let _: Vec<Value> = query.find(&client).await.expect("find failed");
+        query.force_post(true);
+        assert_eq!(query.count(&client).await.expect("count failed"), 1);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[1].method, "POST");
+        let body: Value = serde_json::from_str(&requests[1].body).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "_method": "GET",
+                "where": { "name": "a" },
+                "limit": 0,
+                "count": 1
+            })
+        );
+    }
+}
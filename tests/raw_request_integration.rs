@@ -0,0 +1,73 @@
+use parse_rs::client::AuthType;
+use parse_rs::{Parse, ParseError};
+use reqwest::{Method, StatusCode};
+use serde_json::{json, Value};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod raw_request_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_raw_request_returns_unprocessed_response() {
+        let server = MockServer::start(|req| match req.path_only() {
+            "/parse/health" => MockResponse::json(200, json!({ "status": "ok" })),
+            _ => MockResponse::json(418, json!({ "error": "teapot" })),
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let response = client
+            .raw_request(Method::GET, "health", None, AuthType::NoAuth)
+            .await
+            .expect("raw_request failed");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Value = response.json().await.unwrap();
+        assert_eq!(body, json!({ "status": "ok" }));
+
+        // Error statuses are handed back untouched rather than mapped to a ParseError.
+        let response = client
+            .raw_request(
+                Method::POST,
+                "custom/route",
+                Some(json!({ "a": 1 })),
+                AuthType::NoAuth,
+            )
+            .await
+            .expect("raw_request failed");
+        assert_eq!(response.status().as_u16(), 418);
+
+        let requests = server.requests();
+        assert_eq!(requests[1].method, "POST");
+        assert_eq!(requests[1].header("Content-Type"), Some("application/json"));
+        assert_eq!(requests[1].body, r#"{"a":1}"#);
+    }
+
+    #[tokio::test]
+    async fn test_raw_request_applies_selected_auth() {
+        let server = MockServer::start(|_| MockResponse::json(200, json!({}))).await;
+        let client = Parse::new(
+            &server.url,
+            "appId",
+            None,
+            Some("restKey"),
+            Some("masterKey"),
+        )
+        .unwrap();
+
+        client
+            .raw_request(Method::GET, "schemas", None, AuthType::MasterKey)
+            .await
+            .unwrap();
+        let request = &server.requests()[0];
+        assert_eq!(request.header("X-Parse-Application-Id"), Some("appId"));
+        assert_eq!(request.header("X-Parse-Master-Key"), Some("masterKey"));
+
+        let result = client
+            .raw_request(Method::GET, "users/me", None, AuthType::SessionToken)
+            .await;
+        assert!(matches!(result, Err(ParseError::SessionTokenMissing)));
+    }
+}
@@ -0,0 +1,72 @@
+use parse_rs::{Parse, ParseError, RedirectPolicy};
+use serde_json::{json, Value};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod redirect_policy_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    // Starts the server the client should end up at, and an old one that permanently redirects
+    // every request to it.
+    async fn start_servers() -> (MockServer, MockServer) {
+        let target = MockServer::start(|_| {
+            MockResponse::json(200, json!({ "results": [{ "objectId": "item000001" }] }))
+        })
+        .await;
+        let target_base = target.base_url.clone();
+        let old = MockServer::start(move |req: &RecordedRequest| {
+            MockResponse::empty(301)
+                .with_header("Location", &format!("{}{}", target_base, req.path_only()))
+        })
+        .await;
+        (old, target)
+    }
+
+    #[tokio::test]
+    async fn test_redirects_are_followed_by_default() {
+        let (old, target) = start_servers().await;
+        let client = Parse::builder(&old.url, "appId").build().unwrap();
+
+        let response: Value = client.get("classes/Item").await.unwrap();
+        assert_eq!(response["results"][0]["objectId"], "item000001");
+
+        assert_eq!(old.requests().len(), 1);
+        let requests = target.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path_only(), "/parse/classes/Item");
+    }
+
+    #[tokio::test]
+    async fn test_redirects_can_be_disabled() {
+        let (old, target) = start_servers().await;
+        let client = Parse::builder(&old.url, "appId")
+            .redirect_policy(RedirectPolicy::None)
+            .build()
+            .unwrap();
+
+        let result: Result<Value, ParseError> = client.get("classes/Item").await;
+        assert!(
+            matches!(result, Err(ParseError::OtherParseError { code: 301, .. })),
+            "Unexpected result: {:?}",
+            result
+        );
+        assert_eq!(old.requests().len(), 1);
+        assert!(target.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_redirect_hops_can_be_limited() {
+        let (old, target) = start_servers().await;
+        let client = Parse::builder(&old.url, "appId")
+            .redirect_policy(RedirectPolicy::Limited(0))
+            .build()
+            .unwrap();
+
+        let result: Result<Value, ParseError> = client.get("classes/Item").await;
+        assert!(matches!(result, Err(ParseError::ReqwestError(_))));
+        assert_eq!(old.requests().len(), 1);
+        assert!(target.requests().is_empty());
+    }
+}
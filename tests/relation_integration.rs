@@ -132,3 +132,52 @@ async fn test_add_and_remove_relation() {
     cleanup_test_class(&client, &parent_class_name).await;
     cleanup_test_class(&client, &child_class_name).await;
 }
+
+#[tokio::test]
+async fn test_add_relation_and_remove_relation_on_custom_class() {
+    let client = setup_client_with_master_key();
+    let playlist_class = generate_unique_classname("Playlist");
+    let song_class = generate_unique_classname("Song");
+
+    let playlist_id = client
+        .create_object(&playlist_class, &json!({ "name": "Road trip" }))
+        .await
+        .expect("Failed to create playlist")
+        .object_id;
+    let playlist_pointer = Pointer::new(&playlist_class, &playlist_id);
+
+    let mut songs = Vec::new();
+    for title in ["First", "Second"] {
+        let song_id = client
+            .create_object(&song_class, &json!({ "title": title }))
+            .await
+            .expect("Failed to create song")
+            .object_id;
+        songs.push(Pointer::new(&song_class, &song_id));
+    }
+
+    let added = client
+        .add_relation(&playlist_class, &playlist_id, "songs", &songs)
+        .await
+        .expect("Failed to add relation");
+    assert!(added.updated_at.to_datetime().is_ok());
+
+    let mut query = ParseQuery::new(&song_class);
+    query.related_to(&playlist_pointer, "songs");
+    assert_eq!(query.count(&client).await.unwrap(), 2);
+
+    let removed = client
+        .remove_relation(&playlist_class, &playlist_id, "songs", &songs)
+        .await
+        .expect("Failed to remove relation");
+    assert!(removed.updated_at.to_datetime().unwrap() >= added.updated_at.to_datetime().unwrap());
+    assert_eq!(query.count(&client).await.unwrap(), 0);
+
+    let empty = client
+        .add_relation(&playlist_class, &playlist_id, "songs", &[])
+        .await;
+    assert!(matches!(empty, Err(parse_rs::ParseError::InvalidInput(_))));
+
+    cleanup_test_class(&client, &playlist_class).await;
+    cleanup_test_class(&client, &song_class).await;
+}
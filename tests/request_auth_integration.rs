@@ -0,0 +1,153 @@
+use parse_rs::client::AuthType;
+use parse_rs::{Parse, ParseError};
+use reqwest::Method;
+use serde_json::{json, Value};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod request_auth_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    const KEY_HEADERS: [&str; 4] = [
+        "X-Parse-Master-Key",
+        "X-Parse-REST-API-Key",
+        "X-Parse-Javascript-Key",
+        "X-Parse-Session-Token",
+    ];
+
+    fn respond(req: &RecordedRequest) -> MockResponse {
+        match req.path_only() {
+            "/parse/users/me" => MockResponse::json(
+                200,
+                json!({
+                    "objectId": "user1",
+                    "username": "alice",
+                    "sessionToken": "r:session",
+                    "createdAt": "2024-01-01T00:00:00.000Z",
+                    "updatedAt": "2024-01-01T00:00:00.000Z"
+                }),
+            ),
+            _ => MockResponse::json(200, json!({ "results": [] })),
+        }
+    }
+
+    // The credential headers present on a request, in `KEY_HEADERS` order.
+    fn key_headers(req: &RecordedRequest) -> Vec<(&'static str, String)> {
+        KEY_HEADERS
+            .iter()
+            .filter_map(|name| req.header(name).map(|value| (*name, value.to_string())))
+            .collect()
+    }
+
+    async fn client_with_all_credentials(server: &MockServer) -> Parse {
+        let mut client = Parse::new(
+            &server.url,
+            "appId",
+            Some("jsKey"),
+            Some("restKey"),
+            Some("masterKey"),
+        )
+        .unwrap();
+        client
+            .user()
+            .become_user("r:session")
+            .await
+            .expect("become_user failed");
+        client
+    }
+
+    #[tokio::test]
+    async fn test_request_with_auth_sends_only_selected_credentials() {
+        let server = MockServer::start(respond).await;
+        let client = client_with_all_credentials(&server).await;
+
+        let cases = [
+            (
+                AuthType::MasterKey,
+                vec![("X-Parse-Master-Key", "masterKey".to_string())],
+            ),
+            (
+                AuthType::RestApiKey,
+                vec![("X-Parse-REST-API-Key", "restKey".to_string())],
+            ),
+            (
+                AuthType::SessionToken,
+                vec![("X-Parse-Session-Token", "r:session".to_string())],
+            ),
+            (AuthType::NoAuth, vec![]),
+        ];
+        for (auth, expected) in cases {
+            let _: Value = client
+                .request_with_auth(Method::GET, "classes/Item", None::<&Value>, auth)
+                .await
+                .expect("request_with_auth failed");
+            let request = server.requests().pop().unwrap();
+            assert_eq!(request.header("X-Parse-Application-Id"), Some("appId"));
+            assert_eq!(key_headers(&request), expected, "{:?}", auth);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_with_auth_rest_key_falls_back_to_javascript_key() {
+        let server = MockServer::start(respond).await;
+        let client =
+            Parse::new(&server.url, "appId", Some("jsKey"), None, Some("masterKey")).unwrap();
+
+        let _: Value = client
+            .request_with_auth(
+                Method::POST,
+                "classes/Item",
+                Some(&json!({ "a": 1 })),
+                AuthType::RestApiKey,
+            )
+            .await
+            .expect("request_with_auth failed");
+        let request = &server.requests()[0];
+        assert_eq!(
+            key_headers(request),
+            vec![("X-Parse-Javascript-Key", "jsKey".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_with_auth_missing_credentials() {
+        let client = Parse::new(
+            "http://127.0.0.1:1/parse",
+            "appId",
+            None,
+            Some("restKey"),
+            None,
+        )
+        .unwrap();
+
+        let result: Result<Value, _> = client
+            .request_with_auth(Method::GET, "schemas", None::<&Value>, AuthType::MasterKey)
+            .await;
+        assert!(matches!(result, Err(ParseError::MasterKeyRequired(_))));
+
+        let result: Result<Value, _> = client
+            .request_with_auth(
+                Method::GET,
+                "users/me",
+                None::<&Value>,
+                AuthType::SessionToken,
+            )
+            .await;
+        assert!(matches!(result, Err(ParseError::SessionTokenMissing)));
+    }
+
+    #[tokio::test]
+    async fn test_implicit_requests_keep_default_key() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+
+        let _: Value = client.get("classes/Item").await.expect("get failed");
+        let request = &server.requests()[0];
+        assert_eq!(
+            key_headers(request),
+            vec![("X-Parse-REST-API-Key", "restKey".to_string())]
+        );
+    }
+}
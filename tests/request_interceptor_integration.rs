@@ -0,0 +1,99 @@
+use parse_rs::interceptor::{async_trait, RequestInterceptor, RequestParts, ResponseParts};
+use parse_rs::{Parse, ParseObject, ParseQuery, RetrievedParseObject};
+use reqwest::header::HeaderValue;
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod request_interceptor_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    // Counts requests, tags each one with a header and records the observed response statuses.
+    #[derive(Default)]
+    struct CountingInterceptor {
+        requests: AtomicUsize,
+        statuses: Mutex<Vec<(String, u16)>>,
+    }
+
+    #[async_trait]
+    impl RequestInterceptor for CountingInterceptor {
+        async fn before_request(&self, request: &mut RequestParts) {
+            let count = self.requests.fetch_add(1, Ordering::SeqCst) + 1;
+            request.headers.insert(
+                "X-Request-Count",
+                HeaderValue::from_str(&count.to_string()).unwrap(),
+            );
+        }
+
+        async fn after_response(&self, response: &ResponseParts) {
+            self.statuses
+                .lock()
+                .unwrap()
+                .push((response.method.to_string(), response.status.as_u16()));
+        }
+    }
+
+    fn respond(req: &RecordedRequest) -> MockResponse {
+        match req.method.as_str() {
+            "POST" => MockResponse::json(
+                201,
+                json!({ "objectId": "abc123XYZ0", "createdAt": "2024-01-01T00:00:00.000Z" }),
+            ),
+            _ => MockResponse::json(200, json!({ "results": [] })),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_interceptor_invoked_for_get_and_post() {
+        let server = MockServer::start(respond).await;
+        let interceptor = Arc::new(CountingInterceptor::default());
+        let client = Parse::builder(&server.url, "appId")
+            .rest_api_key("restKey")
+            .interceptor(interceptor.clone())
+            .build()
+            .unwrap();
+
+        let mut object = ParseObject::new("GameScore");
+        object.set("score", 10);
+        client.create_object("GameScore", &object).await.unwrap();
+
+        let results: Vec<RetrievedParseObject> =
+            ParseQuery::new("GameScore").find(&client).await.unwrap();
+        assert!(results.is_empty());
+
+        assert_eq!(interceptor.requests.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            *interceptor.statuses.lock().unwrap(),
+            vec![("POST".to_string(), 201), ("GET".to_string(), 200)]
+        );
+
+        // Headers added in `before_request` are sent, alongside the client's own.
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].header("X-Request-Count"), Some("1"));
+        assert_eq!(requests[1].header("X-Request-Count"), Some("2"));
+        assert_eq!(requests[1].header("X-Parse-Application-Id"), Some("appId"));
+        assert_eq!(requests[1].header("X-Parse-REST-API-Key"), Some("restKey"));
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_interceptor() {
+        let server = MockServer::start(respond).await;
+        let interceptor = Arc::new(CountingInterceptor::default());
+        let client = Parse::builder(&server.url, "appId")
+            .interceptor(interceptor.clone())
+            .build()
+            .unwrap();
+        let clone = client.clone();
+
+        let _: Vec<RetrievedParseObject> =
+            ParseQuery::new("GameScore").find(&client).await.unwrap();
+        let _: Vec<RetrievedParseObject> = ParseQuery::new("GameScore").find(&clone).await.unwrap();
+
+        assert_eq!(interceptor.requests.load(Ordering::SeqCst), 2);
+    }
+}
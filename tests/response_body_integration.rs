@@ -0,0 +1,76 @@
+use parse_rs::{Parse, ParseError, ParseQuery};
+use serde::Deserialize;
+use serde_json::Value;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod response_body_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct RequiresField {
+        #[allow(dead_code)]
+        field: String,
+    }
+
+    async fn client_for(response: MockResponse) -> (MockServer, Parse) {
+        let server = MockServer::start(move |_| response.clone()).await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+        (server, client)
+    }
+
+    fn empty_200() -> MockResponse {
+        MockResponse::empty(200)
+    }
+
+    fn whitespace_200() -> MockResponse {
+        MockResponse {
+            status: 200,
+            headers: Vec::new(),
+            body: "  \n".to_string(),
+            delay: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_path_empty_bodies() {
+        for response in [MockResponse::empty(204), empty_200(), whitespace_200()] {
+            let (_server, client) = client_for(response).await;
+            let value: Value = client.get("classes/Foo/abc").await.unwrap();
+            assert_eq!(value, serde_json::json!({}));
+            let _: () = client.delete("classes/Foo/abc").await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_url_params_path_empty_bodies() {
+        for response in [MockResponse::empty(204), empty_200(), whitespace_200()] {
+            let (server, client) = client_for(response).await;
+            let value: Value = ParseQuery::new("Foo").get("abc", &client).await.unwrap();
+            assert_eq!(value, serde_json::json!({}));
+            assert_eq!(server.requests()[0].path_only(), "/parse/classes/Foo/abc");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_empty_body_into_incompatible_type_is_clear_error() {
+        let (_server, client) = client_for(MockResponse::empty(204)).await;
+        let result: Result<RequiresField, ParseError> = client.get("classes/Foo/abc").await;
+        assert!(
+            matches!(result, Err(ParseError::JsonDeserializationFailed(ref msg, _)) if msg.contains("Empty response body")),
+            "Unexpected result: {:?}",
+            result
+        );
+
+        let (_server, client) = client_for(empty_200()).await;
+        let result: Result<RequiresField, ParseError> =
+            ParseQuery::new("Foo").get("abc", &client).await;
+        assert!(
+            matches!(result, Err(ParseError::JsonDeserializationFailed(ref msg, _)) if msg.contains("Empty response body")),
+            "Unexpected result: {:?}",
+            result
+        );
+    }
+}
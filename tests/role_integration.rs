@@ -1,4 +1,4 @@
-use parse_rs::role::NewParseRole;
+use parse_rs::role::{NewParseRole, ParseRole};
 use parse_rs::Parse;
 use parse_rs::ParseACL;
 use uuid::Uuid;
@@ -53,6 +53,47 @@ async fn test_create_and_get_role() {
     cleanup_role(&client, &role_object_id).await;
 }
 
+#[tokio::test]
+async fn test_query_roles_by_name() {
+    let client = setup_client_with_master_key();
+    let role_name = format!("QueryRole_{}", Uuid::new_v4().simple());
+
+    let mut acl = ParseACL::new();
+    acl.set_public_read_access(true);
+    acl.set_role_write_access(&role_name, true);
+
+    let created_role = client
+        .create_role(&NewParseRole {
+            name: role_name.clone(),
+            acl: acl.clone(),
+        })
+        .await
+        .expect("Failed to create role");
+    let role_object_id = created_role.object_id.unwrap();
+
+    let found: Option<ParseRole> = client
+        .query_roles()
+        .equal_to("name", &role_name)
+        .first(&client)
+        .await
+        .expect("Failed to query roles");
+    let found = found.expect("Role should be found by name");
+    assert_eq!(found.object_id, Some(role_object_id.clone()));
+    assert_eq!(found.name, role_name);
+    assert_eq!(found.acl, acl);
+    assert!(found.created_at.is_some());
+
+    let missing: Option<ParseRole> = client
+        .query_roles()
+        .equal_to("name", "NoSuchRole_query_roles")
+        .first(&client)
+        .await
+        .expect("Failed to query roles");
+    assert!(missing.is_none());
+
+    cleanup_role(&client, &role_object_id).await;
+}
+
 #[tokio::test]
 async fn test_add_remove_users_in_role() {
     let mut client = setup_client_with_master_key(); // Mutable for user().signup()
@@ -175,3 +216,66 @@ async fn test_add_remove_child_roles_in_role() {
     cleanup_role(&client, &child_role_object_id).await;
     cleanup_role(&client, &parent_role_object_id).await;
 }
+
+#[tokio::test]
+async fn test_effective_roles_for_nested_roles() {
+    let mut client = setup_client_with_master_key();
+
+    // Admin contains Editor, which contains Viewer.
+    let mut role_ids = Vec::new();
+    for prefix in ["Admin", "Editor", "Viewer"] {
+        let mut acl = ParseACL::new();
+        acl.set_public_read_access(true);
+        let role = client
+            .create_role(&NewParseRole {
+                name: format!("{}_{}", prefix, Uuid::new_v4().simple()),
+                acl,
+            })
+            .await
+            .expect("Failed to create role");
+        role_ids.push(role.object_id.unwrap());
+    }
+    let (admin_id, editor_id, viewer_id) = (&role_ids[0], &role_ids[1], &role_ids[2]);
+    client
+        .add_child_roles_to_role(admin_id, &[editor_id])
+        .await
+        .expect("Failed to add Editor to Admin");
+    client
+        .add_child_roles_to_role(editor_id, &[viewer_id])
+        .await
+        .expect("Failed to add Viewer to Editor");
+
+    let username = format!("ViewerUser_{}", Uuid::new_v4().simple());
+    let user_id = client
+        .user()
+        .signup(&SignupRequest {
+            username: &username,
+            password: "password123",
+            email: None,
+        })
+        .await
+        .expect("Failed to create user")
+        .object_id;
+    client
+        .add_users_to_role(viewer_id, &[&user_id])
+        .await
+        .expect("Failed to add user to Viewer");
+
+    let roles = client
+        .get_effective_roles_for_user(&user_id)
+        .await
+        .expect("Failed to resolve effective roles");
+    let mut effective_ids: Vec<String> = roles.into_iter().filter_map(|r| r.object_id).collect();
+    effective_ids.sort();
+    let mut expected = role_ids.clone();
+    expected.sort();
+    assert_eq!(effective_ids, expected);
+
+    for role_id in &role_ids {
+        cleanup_role(&client, role_id).await;
+    }
+    client
+        .delete_user(&user_id)
+        .await
+        .expect("Failed to delete test user");
+}
@@ -142,8 +142,10 @@ async fn test_create_get_and_delete_class_schema() {
 
     // 5. Verify deletion by trying to fetch it again (should fail)
     match client.get_class_schema(&class_name).await {
-        Err(ParseError::OtherParseError { code, message }) => {
-            assert_eq!(code, 103);
+        Err(ParseError::InvalidClass {
+            class_name: _,
+            message,
+        }) => {
             assert!(
                 message.to_lowercase().contains("does not exist")
                     || message.to_lowercase().contains("class not found")
@@ -387,11 +389,12 @@ async fn test_delete_non_empty_class_schema_fails() {
 
     // 3. Attempt to delete the class schema (should fail as it's not empty)
     match client.delete_class_schema(&class_name, true).await {
-        Err(ParseError::OtherParseError { code, message }) => {
-            assert_eq!(
-                code, 255,
-                "Expected error code 255 for non-empty class deletion"
-            );
+        Err(ParseError::InvalidSchemaOperation {
+            class_name: failed_class,
+            message,
+            ..
+        }) => {
+            assert_eq!(failed_class.as_deref(), Some(class_name.as_str()));
             assert!(message.to_lowercase().contains("not empty"));
         }
         Ok(_) => panic!("Deleting non-empty schema should have failed but succeeded."),
@@ -414,9 +417,7 @@ async fn test_delete_non_empty_class_schema_fails() {
 
     // Verify schema is actually gone
     match client.get_class_schema(&class_name).await {
-        Err(ParseError::OtherParseError { code, message: _ }) => {
-            assert_eq!(code, 103);
-        }
+        Err(ParseError::InvalidClass { .. }) => {}
         Ok(_) => panic!("Schema should not exist after cleanup but was found."),
         Err(e) => panic!(
             "Fetching schema after cleanup failed with an unexpected error: {}",
@@ -1,4 +1,5 @@
 use chrono::Utc;
+use parse_rs::user::LoginRequest;
 use parse_rs::ParseError;
 use parse_rs::ParseSession;
 use serde_json::json;
@@ -141,18 +142,11 @@ async fn test_session_get_by_object_id_success() {
         retrieved_session.session_token, original_session_token,
         "Retrieved session token does not match"
     );
-    let user_ptr_val = retrieved_session
-        .user
-        .as_object()
-        .expect("User pointer should be an object");
-    assert_eq!(
-        user_ptr_val.get("className").unwrap().as_str().unwrap(),
-        "_User"
-    );
-    assert_eq!(
-        user_ptr_val.get("objectId").unwrap().as_str().unwrap(),
-        signup_response.object_id
-    );
+    let user_ptr = retrieved_session
+        .user_pointer()
+        .expect("Session should point to its user");
+    assert_eq!(user_ptr.class_name, "_User");
+    assert_eq!(user_ptr.object_id, signup_response.object_id);
 
     // TODO: Add cleanup - delete the created user and session if necessary
 }
@@ -406,16 +400,15 @@ async fn test_session_get_all_sessions_success() {
             "Session token should not be empty"
         );
         // When user is not included, it should be a pointer
-        let user_ptr = session
-            .user
-            .as_object()
-            .expect("User should be a pointer object when not included");
-        assert_eq!(user_ptr.get("__type").unwrap().as_str().unwrap(), "Pointer");
-        assert_eq!(
-            user_ptr.get("className").unwrap().as_str().unwrap(),
-            "_User"
+        assert!(
+            session.user_object().is_none(),
+            "User should be a pointer when not included"
         );
-        assert!(user_ptr.contains_key("objectId"));
+        let user_ptr = session
+            .user_pointer()
+            .expect("Session should point to its user");
+        assert_eq!(user_ptr.class_name, "_User");
+        assert!(!user_ptr.object_id.is_empty());
     }
 
     // Now try with include=user for our specific users
@@ -458,23 +451,16 @@ async fn test_session_get_all_sessions_success() {
         .iter()
         .find(|s| s.session_token == session_token1)
     {
-        let user1_obj = found_session1
-            .user
-            .as_object()
-            .expect("User1 should be an object");
+        let user1 = found_session1
+            .user_object()
+            .expect("User1 should be included");
         assert_eq!(
-            user1_obj.get("objectId").unwrap().as_str().unwrap(),
-            user1_object_id,
+            user1.object_id.as_deref(),
+            Some(user1_object_id.as_str()),
             "User objectId mismatch for first session"
         );
         assert_eq!(
-            user1_obj.get("className").unwrap().as_str().unwrap(),
-            "_User",
-            "User className mismatch for first session"
-        );
-        assert_eq!(
-            user1_obj.get("username").unwrap().as_str().unwrap(),
-            username1,
+            user1.username, username1,
             "Username mismatch for first session"
         );
     } else {
@@ -486,23 +472,16 @@ async fn test_session_get_all_sessions_success() {
         .iter()
         .find(|s| s.session_token == session_token2)
     {
-        let user2_obj = found_session2
-            .user
-            .as_object()
-            .expect("User2 should be an object");
+        let user2 = found_session2
+            .user_object()
+            .expect("User2 should be included");
         assert_eq!(
-            user2_obj.get("objectId").unwrap().as_str().unwrap(),
-            user2_object_id,
+            user2.object_id.as_deref(),
+            Some(user2_object_id.as_str()),
             "User objectId mismatch for second session"
         );
         assert_eq!(
-            user2_obj.get("className").unwrap().as_str().unwrap(),
-            "_User",
-            "User className mismatch for second session"
-        );
-        assert_eq!(
-            user2_obj.get("username").unwrap().as_str().unwrap(),
-            username2,
+            user2.username, username2,
             "Username mismatch for second session"
         );
     } else {
@@ -510,13 +489,57 @@ async fn test_session_get_all_sessions_success() {
     }
 
     // Cleanup: Delete the created users (sessions are implicitly deleted with users)
-    // User deletion requires master key and is a DELETE to /users/:objectId
     client
-        .delete_object_with_master_key(&format!("users/{}", user1_object_id))
+        .delete_user(&user1_object_id)
         .await
         .expect("Failed to delete user1");
     client
-        .delete_object_with_master_key(&format!("users/{}", user2_object_id))
+        .delete_user(&user2_object_id)
         .await
         .expect("Failed to delete user2");
 }
+
+#[tokio::test]
+async fn test_logout_all_invalidates_every_session() {
+    let mut client = setup_client_with_master_key();
+    let username = format!("testuser_logout_all_{}", Uuid::new_v4().simple());
+    let password = "testpassword123";
+    let signup_response = client
+        .user()
+        .signup(&json!({ "username": username, "password": password }))
+        .await
+        .expect("Signup failed");
+
+    // Two more devices log in as the same user, each getting its own session.
+    let mut other_tokens = Vec::new();
+    for _ in 0..2 {
+        let mut device = setup_client();
+        let login = LoginRequest {
+            username: &username,
+            password,
+        };
+        let user = device.user().login(&login).await.expect("Login failed");
+        other_tokens.push(user.session_token.expect("Login should return a token"));
+    }
+
+    let revoked = client.user().logout_all().await.expect("logout_all failed");
+    assert_eq!(revoked, 3, "Expected the signup and both login sessions");
+    assert!(!client.is_authenticated());
+
+    for token in other_tokens
+        .iter()
+        .chain(std::iter::once(&signup_response.session_token))
+    {
+        let mut device = setup_client();
+        assert!(
+            device.user().become_user(token).await.is_err(),
+            "Session {} should have been invalidated",
+            token
+        );
+    }
+
+    client
+        .delete_user(&signup_response.object_id)
+        .await
+        .expect("Failed to delete user");
+}
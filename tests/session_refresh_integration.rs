@@ -0,0 +1,146 @@
+use parse_rs::{Parse, ParseError};
+use serde_json::{json, Value};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod session_refresh_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    fn user() -> Value {
+        json!({
+            "objectId": "user1",
+            "username": "alice",
+            "sessionToken": "r:session",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "updatedAt": "2024-01-01T00:00:00.000Z"
+        })
+    }
+
+    fn session(expires_at: Option<&str>) -> Value {
+        let mut session = json!({
+            "objectId": "sess1",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "user": { "__type": "Pointer", "className": "_User", "objectId": "user1" },
+            "sessionToken": "r:session"
+        });
+        if let Some(expires_at) = expires_at {
+            session["expiresAt"] = json!({ "__type": "Date", "iso": expires_at });
+        }
+        session
+    }
+
+    async fn logged_in_client(
+        responder: impl Fn(&RecordedRequest) -> MockResponse + Send + Sync + 'static,
+    ) -> (MockServer, Parse) {
+        let server = MockServer::start(responder).await;
+        let mut client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+        client
+            .user()
+            .become_user("r:session")
+            .await
+            .expect("become_user failed");
+        (server, client)
+    }
+
+    #[tokio::test]
+    async fn test_refresh_returns_extended_expiry() {
+        let (server, client) = logged_in_client(|req| match req.path_only() {
+            "/parse/users/me" => MockResponse::json(200, user()),
+            _ => MockResponse::json(200, session(Some("2030-06-01T00:00:00.000Z"))),
+        })
+        .await;
+
+        let refreshed = client.session().refresh().await.expect("refresh failed");
+        assert_eq!(
+            refreshed.expires_at.map(|date| date.iso),
+            Some("2030-06-01T00:00:00.000Z".to_string())
+        );
+
+        let request = server.requests().pop().unwrap();
+        assert_eq!(request.path_only(), "/parse/sessions/me");
+        assert_eq!(request.header("X-Parse-Session-Token"), Some("r:session"));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_without_expiry_is_unsupported() {
+        let (_server, client) = logged_in_client(|req| match req.path_only() {
+            "/parse/users/me" => MockResponse::json(200, user()),
+            _ => MockResponse::json(200, session(None)),
+        })
+        .await;
+
+        let result = client.session().refresh().await;
+        assert!(
+            matches!(result, Err(ParseError::Unsupported(_))),
+            "Unexpected result: {:?}",
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn test_refresh_requires_session_token() {
+        let client = Parse::new("http://127.0.0.1:1/parse", "appId", None, None, None).unwrap();
+        let result = client.session().refresh().await;
+        assert!(matches!(result, Err(ParseError::SessionTokenMissing)));
+    }
+
+    #[tokio::test]
+    async fn test_is_valid_for_accepted_session() {
+        let (server, client) = logged_in_client(|req| match req.path_only() {
+            "/parse/users/me" => MockResponse::json(200, user()),
+            _ => MockResponse::json(200, session(None)),
+        })
+        .await;
+
+        assert!(client.session().is_valid().await.expect("is_valid failed"));
+        let request = server.requests().pop().unwrap();
+        assert_eq!(request.path_only(), "/parse/sessions/me");
+    }
+
+    #[tokio::test]
+    async fn test_is_valid_for_expired_session() {
+        let (_server, client) = logged_in_client(|req| match req.path_only() {
+            "/parse/users/me" => MockResponse::json(200, user()),
+            _ => MockResponse::json(
+                400,
+                json!({ "code": 209, "error": "Invalid session token" }),
+            ),
+        })
+        .await;
+
+        assert!(!client.session().is_valid().await.expect("is_valid failed"));
+    }
+
+    #[tokio::test]
+    async fn test_is_valid_propagates_other_errors() {
+        let (_server, client) = logged_in_client(|req| match req.path_only() {
+            "/parse/users/me" => MockResponse::json(200, user()),
+            _ => MockResponse::json(500, json!({ "code": 1, "error": "Internal server error" })),
+        })
+        .await;
+        let result = client.session().is_valid().await;
+        assert!(
+            matches!(result, Err(ParseError::InternalServerError(_))),
+            "Unexpected result: {:?}",
+            result
+        );
+
+        // Nothing listens on port 1, so the request fails before reaching a server.
+        let mut unreachable = client.clone();
+        unreachable.server_url = "http://127.0.0.1:1/parse".to_string();
+        let result = unreachable.session().is_valid().await;
+        assert!(
+            matches!(result, Err(ParseError::Network(_))),
+            "Unexpected result: {:?}",
+            result
+        );
+
+        let no_session = Parse::new("http://127.0.0.1:1/parse", "appId", None, None, None).unwrap();
+        assert!(matches!(
+            no_session.session().is_valid().await,
+            Err(ParseError::SessionTokenMissing)
+        ));
+    }
+}
@@ -0,0 +1,62 @@
+use parse_rs::{Parse, ParseError, ParseStats};
+use serde_json::json;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod stats_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_counters_track_requests_outcomes_and_bytes() {
+        let server = MockServer::start(|req| {
+            if req.path_only().ends_with("/missing001") {
+                MockResponse::json(404, json!({ "code": 101, "error": "Object not found." }))
+            } else {
+                MockResponse::json(
+                    201,
+                    json!({ "objectId": "score00001", "createdAt": "2024-01-01T00:00:00.000Z" }),
+                )
+            }
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, Some("restKey"), None).unwrap();
+        assert_eq!(client.stats(), ParseStats::default());
+
+        let body = json!({ "score": 1337 });
+        client.create_object("GameScore", &body).await.unwrap();
+        client.create_object("GameScore", &body).await.unwrap();
+        let missing = client.retrieve_object("GameScore", "missing001").await;
+        assert!(matches!(missing, Err(ParseError::ObjectNotFound(_))));
+
+        let requests = server.requests();
+        let stats = client.stats();
+        assert_eq!(stats.requests, 3);
+        assert_eq!(stats.successes, 2);
+        assert_eq!(stats.failures, 1);
+        assert_eq!(
+            stats.bytes_sent,
+            requests.iter().map(|r| r.body.len() as u64).sum::<u64>()
+        );
+        assert!(stats.bytes_received > 0);
+
+        // Clones share the counters.
+        let user_client = client.clone_with_session(Some("r:abc".to_string()));
+        user_client.create_object("GameScore", &body).await.unwrap();
+        assert_eq!(client.stats().requests, 4);
+    }
+
+    #[tokio::test]
+    async fn test_transport_errors_count_as_failures() {
+        let client = Parse::new("http://127.0.0.1:1/parse", "appId", None, None, None).unwrap();
+        assert!(client
+            .retrieve_object("GameScore", "score00001")
+            .await
+            .is_err());
+
+        let stats = client.stats();
+        assert_eq!((stats.requests, stats.successes, stats.failures), (1, 0, 1));
+        assert_eq!(stats.bytes_received, 0);
+    }
+}
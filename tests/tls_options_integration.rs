@@ -0,0 +1,37 @@
+use parse_rs::{Certificate, Identity, Parse};
+
+// A self-signed test CA, and a client certificate it issued with the matching PKCS #8 key.
+const CA_PEM: &[u8] = include_bytes!("fixtures/ca.pem");
+const CLIENT_CERT_PEM: &[u8] = include_bytes!("fixtures/client.pem");
+const CLIENT_KEY_PEM: &[u8] = include_bytes!("fixtures/client.key");
+
+#[cfg(test)]
+mod tls_options_tests {
+    use super::*;
+
+    #[test]
+    fn test_client_builds_with_custom_root_certificate() {
+        let ca = Certificate::from_pem(CA_PEM).unwrap();
+
+        let client = Parse::builder("https://parse.internal.example/parse", "appId")
+            .add_root_certificate(ca)
+            .build();
+        assert!(client.is_ok());
+    }
+
+    #[test]
+    fn test_client_builds_with_client_identity() {
+        let identity = Identity::from_pkcs8_pem(CLIENT_CERT_PEM, CLIENT_KEY_PEM).unwrap();
+
+        let client = Parse::builder("https://parse.internal.example/parse", "appId")
+            .add_root_certificate(Certificate::from_pem(CA_PEM).unwrap())
+            .identity(identity)
+            .build();
+        assert!(client.is_ok());
+    }
+
+    #[test]
+    fn test_invalid_certificate_pem_is_rejected() {
+        assert!(Certificate::from_pem(b"not a certificate").is_err());
+    }
+}
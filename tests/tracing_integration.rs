@@ -0,0 +1,107 @@
+#![cfg(feature = "tracing")]
+
+use parse_rs::{Parse, ParseObject, ParseQuery, RetrievedParseObject};
+use serde_json::json;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod tracing_tests {
+    use super::mock_server_utils::{MockResponse, MockServer, RecordedRequest};
+    use super::*;
+
+    // Span ids, names and fields, in creation order.
+    type RecordedSpans = Arc<Mutex<Vec<(u64, String, HashMap<String, String>)>>>;
+
+    // A layer that records every span's name and fields.
+    #[derive(Default)]
+    struct SpanRecorder {
+        spans: RecordedSpans,
+    }
+
+    struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for SpanRecorder {
+        fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _ctx: Context<'_, S>) {
+            let mut fields = HashMap::new();
+            attrs.record(&mut FieldVisitor(&mut fields));
+            self.spans.lock().unwrap().push((
+                id.into_u64(),
+                attrs.metadata().name().to_string(),
+                fields,
+            ));
+        }
+
+        fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+            let mut spans = self.spans.lock().unwrap();
+            if let Some((_, _, fields)) = spans
+                .iter_mut()
+                .rev()
+                .find(|(span_id, _, _)| *span_id == id.into_u64())
+            {
+                values.record(&mut FieldVisitor(fields));
+            }
+        }
+    }
+
+    fn respond(req: &RecordedRequest) -> MockResponse {
+        match req.method.as_str() {
+            "POST" => MockResponse::json(
+                201,
+                json!({ "objectId": "abc123XYZ0", "createdAt": "2024-01-01T00:00:00.000Z" }),
+            ),
+            _ => MockResponse::json(200, json!({ "results": [] })),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_spans_carry_endpoint_and_status() {
+        let server = MockServer::start(respond).await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let recorder = SpanRecorder::default();
+        let spans = recorder.spans.clone();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder));
+
+        let object = ParseObject::new("GameScore").with("score", 10);
+        client.create_object("GameScore", &object).await.unwrap();
+        let _: Vec<RetrievedParseObject> =
+            ParseQuery::new("GameScore").find(&client).await.unwrap();
+
+        let spans = spans.lock().unwrap();
+        let requests: Vec<&HashMap<String, String>> = spans
+            .iter()
+            .filter(|(_, name, _)| name == "parse_request")
+            .map(|(_, _, fields)| fields)
+            .collect();
+        assert_eq!(requests.len(), 2, "Unexpected spans: {:?}", spans);
+
+        assert_eq!(requests[0]["method"], "POST");
+        assert_eq!(requests[0]["endpoint"], "classes/GameScore");
+        assert_eq!(requests[0]["status"], "201");
+        assert_eq!(requests[1]["method"], "GET");
+        assert_eq!(requests[1]["endpoint"], "classes/GameScore");
+        assert_eq!(requests[1]["status"], "200");
+    }
+}
@@ -0,0 +1,64 @@
+use parse_rs::{Parse, ParseError, ParseQuery, RetrievedParseObject};
+use serde_json::json;
+use std::error::Error;
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod transport_errors_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_connection_refused_is_a_network_error() {
+        // Nothing listens on port 1, so the connection is refused.
+        let client = Parse::new("http://127.0.0.1:1/parse", "appId", None, None, None).unwrap();
+
+        let result: Result<Vec<RetrievedParseObject>, _> =
+            ParseQuery::new("GameScore").find(&client).await;
+        let error = result.unwrap_err();
+        assert!(
+            matches!(error, ParseError::Network(_)),
+            "Unexpected error: {:?}",
+            error
+        );
+        assert!(error.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_forbidden_response_is_a_server_error() {
+        let server =
+            MockServer::start(|_| MockResponse::json(403, json!({ "error": "unauthorized" })))
+                .await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let result: Result<Vec<RetrievedParseObject>, _> =
+            ParseQuery::new("GameScore").find(&client).await;
+        let error = result.unwrap_err();
+        assert!(
+            matches!(error, ParseError::AuthenticationError(_)),
+            "Unexpected error: {:?}",
+            error
+        );
+        assert!(!error.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_wrapped_errors_expose_their_source() {
+        let client = Parse::new("http://127.0.0.1:1/parse", "appId", None, None, None).unwrap();
+        let result: Result<Vec<RetrievedParseObject>, _> =
+            ParseQuery::new("GameScore").find(&client).await;
+        let error = result.unwrap_err();
+        let source = error.source().expect("network error has no source");
+        assert!(source.downcast_ref::<reqwest::Error>().is_some());
+
+        let server = MockServer::start(|_| MockResponse::json(200, json!({ "results": 42 }))).await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+        let result: Result<Vec<RetrievedParseObject>, _> =
+            ParseQuery::new("GameScore").find(&client).await;
+        let error = result.unwrap_err();
+        assert!(matches!(error, ParseError::JsonDeserializationFailed(..)));
+        let source = error.source().expect("deserialization error has no source");
+        assert!(source.downcast_ref::<serde_json::Error>().is_some());
+    }
+}
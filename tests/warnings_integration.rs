@@ -0,0 +1,50 @@
+use parse_rs::{Parse, ParseQuery};
+use serde_json::{json, Value};
+
+mod mock_server_utils;
+
+#[cfg(test)]
+mod warnings_tests {
+    use super::mock_server_utils::{MockResponse, MockServer};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_warning_header_is_surfaced() {
+        let server = MockServer::start(|req| match req.path_only() {
+            "/parse/classes/Legacy/abc" => MockResponse::json(200, json!({ "objectId": "abc" }))
+                .with_header("Warning", "299 - \"This endpoint is deprecated\""),
+            _ => MockResponse::json(200, json!({ "results": [] })),
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+        assert!(client.last_warnings().is_empty());
+
+        let _: Value = client.get("classes/Legacy/abc").await.unwrap();
+        assert_eq!(
+            client.last_warnings(),
+            vec!["299 - \"This endpoint is deprecated\"".to_string()]
+        );
+
+        // Warnings are replaced by the next response.
+        let _: Vec<Value> = ParseQuery::new("Other").find(&client).await.unwrap();
+        assert!(client.last_warnings().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_warning_body_field_is_surfaced_on_query_path() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(
+                200,
+                json!({ "results": [], "warning": "The 'foo' option is deprecated" }),
+            )
+        })
+        .await;
+        let client = Parse::new(&server.url, "appId", None, None, None).unwrap();
+
+        let _: Vec<Value> = ParseQuery::new("Legacy").find(&client).await.unwrap();
+        assert_eq!(
+            client.last_warnings(),
+            vec!["The 'foo' option is deprecated".to_string()]
+        );
+    }
+}